@@ -1,11 +1,21 @@
-#![feature(test)]
+/*! Benchmarks for `BitSlice` construction, access, and bulk operations.
 
-extern crate test;
+The bulk-operation benchmarks exercise the routines that the `simd` feature
+vectorizes. Run them with and without `--features simd` to compare the vector
+kernels against the element-wise implementations.
+!*/
 
-use bitvec::prelude::*;
-use test::{
-	bench::black_box,
-	Bencher,
+use bitvec::{
+	mem::bits_of,
+	prelude::*,
+};
+use criterion::{
+	black_box,
+	criterion_group,
+	criterion_main,
+	BenchmarkId,
+	Criterion,
+	Throughput,
 };
 
 /* `BitSlice::empty` is not benched, because the compiler const-folds it. It
@@ -15,125 +25,296 @@ the return value is a `const` value, and insert the value at all
 `BitSlice::empty` call sites. It takes 0ns.
 */
 
-#[bench]
-fn element(b: &mut Bencher) {
-	b.iter(|| BitSlice::<u8, Msb0>::from_element(&!0));
-	b.iter(|| BitSlice::<u8, Lsb0>::from_element(&!0));
-	b.iter(|| BitSlice::<u16, Msb0>::from_element(&!0));
-	b.iter(|| BitSlice::<u16, Lsb0>::from_element(&!0));
-	b.iter(|| BitSlice::<u32, Msb0>::from_element(&!0));
-	b.iter(|| BitSlice::<u32, Lsb0>::from_element(&!0));
+fn element(c: &mut Criterion) {
+	let mut group = c.benchmark_group("element");
+	group.bench_function("u8/Msb0", |b| {
+		b.iter(|| BitSlice::<u8, Msb0>::from_element(&!0))
+	});
+	group.bench_function("u8/Lsb0", |b| {
+		b.iter(|| BitSlice::<u8, Lsb0>::from_element(&!0))
+	});
+	group.bench_function("u16/Msb0", |b| {
+		b.iter(|| BitSlice::<u16, Msb0>::from_element(&!0))
+	});
+	group.bench_function("u16/Lsb0", |b| {
+		b.iter(|| BitSlice::<u16, Lsb0>::from_element(&!0))
+	});
+	group.bench_function("u32/Msb0", |b| {
+		b.iter(|| BitSlice::<u32, Msb0>::from_element(&!0))
+	});
+	group.bench_function("u32/Lsb0", |b| {
+		b.iter(|| BitSlice::<u32, Lsb0>::from_element(&!0))
+	});
 
 	#[cfg(target_pointer_width = "64")]
 	{
-		b.iter(|| BitSlice::<u64, Msb0>::from_element(&!0));
-		b.iter(|| BitSlice::<u64, Lsb0>::from_element(&!0));
+		group.bench_function("u64/Msb0", |b| {
+			b.iter(|| BitSlice::<u64, Msb0>::from_element(&!0))
+		});
+		group.bench_function("u64/Lsb0", |b| {
+			b.iter(|| BitSlice::<u64, Lsb0>::from_element(&!0))
+		});
 	}
+	group.finish();
 }
 
-#[bench]
-fn slice(b: &mut Bencher) {
-	b.iter(|| BitSlice::<u8, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-	b.iter(|| BitSlice::<u8, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-	b.iter(|| BitSlice::<u16, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-	b.iter(|| BitSlice::<u16, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-	b.iter(|| BitSlice::<u32, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-	b.iter(|| BitSlice::<u32, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
+fn slice(c: &mut Criterion) {
+	let mut group = c.benchmark_group("slice");
+	group.bench_function("u8/Msb0", |b| {
+		b.iter(|| BitSlice::<u8, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
+	group.bench_function("u8/Lsb0", |b| {
+		b.iter(|| BitSlice::<u8, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
+	group.bench_function("u16/Msb0", |b| {
+		b.iter(|| BitSlice::<u16, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
+	group.bench_function("u16/Lsb0", |b| {
+		b.iter(|| BitSlice::<u16, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
+	group.bench_function("u32/Msb0", |b| {
+		b.iter(|| BitSlice::<u32, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
+	group.bench_function("u32/Lsb0", |b| {
+		b.iter(|| BitSlice::<u32, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+	});
 
 	#[cfg(target_pointer_width = "64")]
 	{
-		b.iter(|| BitSlice::<u64, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
-		b.iter(|| BitSlice::<u64, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]));
+		group.bench_function("u64/Msb0", |b| {
+			b.iter(|| BitSlice::<u64, Msb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+		});
+		group.bench_function("u64/Lsb0", |b| {
+			b.iter(|| BitSlice::<u64, Lsb0>::from_slice(&[0, 1, !0 - 1, !0][..]))
+		});
 	}
+	group.finish();
 }
 
-#[bench]
-fn len(b: &mut Bencher) {
+fn len(c: &mut Criterion) {
+	let mut group = c.benchmark_group("len");
 	let bsb08 = [0u8; 16].view_bits::<Msb0>();
 	let bsl08 = [0u8; 16].view_bits::<Lsb0>();
-	b.iter(|| bsb08.len());
-	b.iter(|| bsl08.len());
+	group.bench_function("u8/Msb0", |b| b.iter(|| black_box(bsb08).len()));
+	group.bench_function("u8/Lsb0", |b| b.iter(|| black_box(bsl08).len()));
 
 	let bsb16 = [0u16; 8].view_bits::<Msb0>();
 	let bsl16 = [0u16; 8].view_bits::<Lsb0>();
-	b.iter(|| bsb16.len());
-	b.iter(|| bsl16.len());
+	group.bench_function("u16/Msb0", |b| b.iter(|| black_box(bsb16).len()));
+	group.bench_function("u16/Lsb0", |b| b.iter(|| black_box(bsl16).len()));
 
 	let bsb32 = [0u32; 4].view_bits::<Msb0>();
 	let bsl32 = [0u32; 4].view_bits::<Lsb0>();
-	b.iter(|| bsb32.len());
-	b.iter(|| bsl32.len());
+	group.bench_function("u32/Msb0", |b| b.iter(|| black_box(bsb32).len()));
+	group.bench_function("u32/Lsb0", |b| b.iter(|| black_box(bsl32).len()));
 
 	#[cfg(target_pointer_width = "64")]
 	{
 		let bsb64 = [0u64; 2].view_bits::<Msb0>();
 		let bsl64 = [0u64; 2].view_bits::<Lsb0>();
-		b.iter(|| bsb64.len());
-		b.iter(|| bsl64.len());
+		group.bench_function("u64/Msb0", |b| b.iter(|| black_box(bsb64).len()));
+		group.bench_function("u64/Lsb0", |b| b.iter(|| black_box(bsl64).len()));
 	}
+	group.finish();
 }
 
 //  This index value is not only "nice", it also ensures that the hard path is
 //  hit in `BitIdx::offset`.
-#[bench]
-fn index(b: &mut Bencher) {
+fn index(c: &mut Criterion) {
+	let mut group = c.benchmark_group("index");
 	let bsb08 = [0u8; 16].view_bits::<Msb0>();
 	let bsl08 = [0u8; 16].view_bits::<Lsb0>();
-	b.iter(|| assert!(!black_box(bsb08)[black_box(69)]));
-	b.iter(|| assert!(!black_box(bsl08)[black_box(69)]));
+	group.bench_function("u8/Msb0", |b| {
+		b.iter(|| assert!(!black_box(bsb08)[black_box(69)]))
+	});
+	group.bench_function("u8/Lsb0", |b| {
+		b.iter(|| assert!(!black_box(bsl08)[black_box(69)]))
+	});
 
 	let bsb16 = [0u16; 8].view_bits::<Msb0>();
 	let bsl16 = [0u16; 8].view_bits::<Lsb0>();
-	b.iter(|| assert!(!black_box(bsb16)[black_box(69)]));
-	b.iter(|| assert!(!black_box(bsl16)[black_box(69)]));
+	group.bench_function("u16/Msb0", |b| {
+		b.iter(|| assert!(!black_box(bsb16)[black_box(69)]))
+	});
+	group.bench_function("u16/Lsb0", |b| {
+		b.iter(|| assert!(!black_box(bsl16)[black_box(69)]))
+	});
 
 	let bsb32 = [0u32; 4].view_bits::<Msb0>();
 	let bsl32 = [0u32; 4].view_bits::<Lsb0>();
-	b.iter(|| assert!(!black_box(bsb32)[black_box(69)]));
-	b.iter(|| assert!(!black_box(bsl32)[black_box(69)]));
+	group.bench_function("u32/Msb0", |b| {
+		b.iter(|| assert!(!black_box(bsb32)[black_box(69)]))
+	});
+	group.bench_function("u32/Lsb0", |b| {
+		b.iter(|| assert!(!black_box(bsl32)[black_box(69)]))
+	});
 
 	#[cfg(target_pointer_width = "64")]
 	{
 		let bsb64 = [0u64; 2].view_bits::<Msb0>();
 		let bsl64 = [0u64; 2].view_bits::<Lsb0>();
-		b.iter(|| assert!(!black_box(bsb64)[black_box(69)]));
-		b.iter(|| assert!(!black_box(bsl64)[black_box(69)]));
+		group.bench_function("u64/Msb0", |b| {
+			b.iter(|| assert!(!black_box(bsb64)[black_box(69)]))
+		});
+		group.bench_function("u64/Lsb0", |b| {
+			b.iter(|| assert!(!black_box(bsl64)[black_box(69)]))
+		});
 	}
+	group.finish();
 }
 
 /* This routine has more work to do: index, create a reference struct, and drop
 it. The compiler *should* be able to properly arrange immediate drops, though.
 */
-#[bench]
-fn get_mut(b: &mut Bencher) {
+fn get_mut(c: &mut Criterion) {
+	let mut group = c.benchmark_group("get_mut");
 	let mut src = [0u8; 16];
 	let bsb08 = src.view_bits_mut::<Msb0>();
-	b.iter(|| *bsb08.get_mut(69).unwrap() = true);
+	group.bench_function("u8/Msb0", |b| {
+		b.iter(|| *bsb08.get_mut(69).unwrap() = true)
+	});
 	let mut src = [0u8; 16];
 	let bsl08 = src.view_bits_mut::<Lsb0>();
-	b.iter(|| *bsl08.get_mut(69).unwrap() = true);
+	group.bench_function("u8/Lsb0", |b| {
+		b.iter(|| *bsl08.get_mut(69).unwrap() = true)
+	});
 
 	let mut src = [0u16; 8];
 	let bsb16 = src.view_bits_mut::<Msb0>();
-	b.iter(|| *bsb16.get_mut(69).unwrap() = true);
+	group.bench_function("u16/Msb0", |b| {
+		b.iter(|| *bsb16.get_mut(69).unwrap() = true)
+	});
 	let mut src = [0u16; 8];
 	let bsl16 = src.view_bits_mut::<Lsb0>();
-	b.iter(|| *bsl16.get_mut(69).unwrap() = true);
+	group.bench_function("u16/Lsb0", |b| {
+		b.iter(|| *bsl16.get_mut(69).unwrap() = true)
+	});
 
 	let mut src = [0u32; 4];
 	let bsb32 = src.view_bits_mut::<Msb0>();
-	b.iter(|| *bsb32.get_mut(69).unwrap() = true);
+	group.bench_function("u32/Msb0", |b| {
+		b.iter(|| *bsb32.get_mut(69).unwrap() = true)
+	});
 	let mut src = [0u32; 4];
 	let bsl32 = src.view_bits_mut::<Lsb0>();
-	b.iter(|| *bsl32.get_mut(69).unwrap() = true);
+	group.bench_function("u32/Lsb0", |b| {
+		b.iter(|| *bsl32.get_mut(69).unwrap() = true)
+	});
 
 	#[cfg(target_pointer_width = "64")]
 	{
 		let mut src = [0u64; 2];
 		let bsb64 = src.view_bits_mut::<Msb0>();
-		b.iter(|| *bsb64.get_mut(69).unwrap() = true);
+		group.bench_function("u64/Msb0", |b| {
+			b.iter(|| *bsb64.get_mut(69).unwrap() = true)
+		});
 		let mut src = [0u64; 2];
 		let bsl64 = src.view_bits_mut::<Lsb0>();
-		b.iter(|| *bsl64.get_mut(69).unwrap() = true);
+		group.bench_function("u64/Lsb0", |b| {
+			b.iter(|| *bsl64.get_mut(69).unwrap() = true)
+		});
+	}
+	group.finish();
+}
+
+//  Sizes, in bytes, of the memory regions used by the bulk benchmarks.
+const BULK_BYTES: &[usize] = &[256, 4096, 65536];
+
+//  Each bulk benchmark starts its bit-slices three bits into their first
+//  element, so that the partial head and tail elements are always present.
+const OFFSET: usize = 3;
+
+fn count_ones(c: &mut Criterion) {
+	let mut group = c.benchmark_group("count_ones");
+	for &bytes in BULK_BYTES {
+		group.throughput(Throughput::Bytes(bytes as u64));
+		let data = (0 .. bytes / 8)
+			.map(|n| (n as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+			.collect::<Vec<_>>();
+		let bits = &data.view_bits::<Lsb0>()[OFFSET ..];
+		group.bench_with_input(
+			BenchmarkId::new("u64", bytes),
+			bits,
+			|b, bits| b.iter(|| black_box(bits).count_ones()),
+		);
+		let data = (0 .. bytes)
+			.map(|n| (n as u8).wrapping_mul(0x9D))
+			.collect::<Vec<_>>();
+		let bits = &data.view_bits::<Msb0>()[OFFSET ..];
+		group.bench_with_input(
+			BenchmarkId::new("u8", bytes),
+			bits,
+			|b, bits| b.iter(|| black_box(bits).count_ones()),
+		);
+	}
+	group.finish();
+}
+
+fn seek(c: &mut Criterion) {
+	let mut group = c.benchmark_group("seek");
+	for &bytes in BULK_BYTES {
+		group.throughput(Throughput::Bytes(bytes as u64));
+		//  Only the middle bit is set, so that both searches cross half of the
+		//  bit-slice before finding it.
+		let mut data = vec![0u64; bytes / 8];
+		let bits = data.view_bits_mut::<Lsb0>();
+		let mid = bits.len() / 2;
+		bits.set(mid, true);
+		let bits = &data.view_bits::<Lsb0>()[OFFSET ..];
+		group.bench_with_input(
+			BenchmarkId::new("first_one", bytes),
+			bits,
+			|b, bits| b.iter(|| black_box(bits).first_one()),
+		);
+		group.bench_with_input(
+			BenchmarkId::new("last_one", bytes),
+			bits,
+			|b, bits| b.iter(|| black_box(bits).last_one()),
+		);
+	}
+	group.finish();
+}
+
+fn eq(c: &mut Criterion) {
+	let mut group = c.benchmark_group("eq");
+	for &bytes in BULK_BYTES {
+		group.throughput(Throughput::Bytes(bytes as u64));
+		let a = vec![0x5Au8; bytes];
+		let b = a.clone();
+		let (a, b) = (
+			&a.view_bits::<Msb0>()[OFFSET ..],
+			&b.view_bits::<Msb0>()[OFFSET ..],
+		);
+		group.bench_with_input(
+			BenchmarkId::new("u8", bytes),
+			&(a, b),
+			|bench, &(a, b)| bench.iter(|| black_box(a) == black_box(b)),
+		);
 	}
+	group.finish();
 }
+
+fn bitop_assign(c: &mut Criterion) {
+	let mut group = c.benchmark_group("bitop_assign");
+	for &bytes in BULK_BYTES {
+		group.throughput(Throughput::Bytes(bytes as u64));
+		let rhs = vec![0x0F0F_0F0F_0F0F_0F0Fu64; bytes / 8];
+		let rhs = &rhs.view_bits::<Lsb0>()[OFFSET ..];
+		let mut lhs = vec![!0u64; bytes / 8];
+		let width = bits_of::<u64>() * lhs.len();
+		group.bench_function(BenchmarkId::new("and", bytes), |b| {
+			b.iter(|| lhs.view_bits_mut::<Lsb0>()[OFFSET .. width] &= rhs)
+		});
+		group.bench_function(BenchmarkId::new("or", bytes), |b| {
+			b.iter(|| lhs.view_bits_mut::<Lsb0>()[OFFSET .. width] |= rhs)
+		});
+		group.bench_function(BenchmarkId::new("xor", bytes), |b| {
+			b.iter(|| lhs.view_bits_mut::<Lsb0>()[OFFSET .. width] ^= rhs)
+		});
+	}
+	group.finish();
+}
+
+criterion_group!(access, element, slice, len, index, get_mut);
+criterion_group!(bulk, count_ones, seek, eq, bitop_assign);
+criterion_main!(access, bulk);
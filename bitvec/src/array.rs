@@ -8,7 +8,12 @@ use crate::{
 		BitOrder,
 		Lsb0,
 	},
-	slice::BitSlice,
+	slice::{
+		parse_bits,
+		BitSlice,
+		ParseBitsError,
+	},
+	store::BitStore,
 	view::BitViewSized,
 };
 
@@ -17,8 +22,15 @@ mod iter;
 mod ops;
 mod tests;
 mod traits;
+mod vec;
 
-pub use self::iter::IntoIter;
+pub use self::{
+	iter::IntoIter,
+	vec::{
+		BitArrayVec,
+		CapacityError,
+	},
+};
 
 #[repr(transparent)]
 #[doc = include_str!("../doc/array/BitArray.md")]
@@ -115,4 +127,68 @@ where
 	pub fn is_empty(&self) -> bool {
 		mem::bits_of::<A>() == 0
 	}
+
+	/// Parses text into a bit-array, reading numerals in a chosen radix.
+	///
+	/// The parsed bits fill the bit-array from the front; any bits after the
+	/// end of the text are zeroed. Text that produces more bits than the
+	/// bit-array holds is rejected with [`ParseBitsError::TooLong`]. See
+	/// [`ParseBitsError`] for a full description of the accepted text.
+	///
+	/// ## Parameters
+	///
+	/// - `text`: the text to parse.
+	/// - `radix_bits`: the bit width of each digit (log2 of its radix): `1` for
+	///   binary, `3` for octal, `4` for hexadecimal.
+	///
+	/// ## Panics
+	///
+	/// This panics if `radix_bits` is not in `1 ..= 5`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slice::ParseBitsError;
+	///
+	/// let arr = BitArray::<u8, Msb0>::parse_radix("0xa", 4).unwrap();
+	/// assert_eq!(arr, bits![1, 0, 1, 0, 0, 0, 0, 0]);
+	///
+	/// let err = BitArray::<u8, Msb0>::parse_radix("a_b_c", 4).unwrap_err();
+	/// assert_eq!(err, ParseBitsError::TooLong {
+	///   position: 4,
+	///   capacity: 8,
+	/// });
+	/// ```
+	#[inline]
+	pub fn parse_radix(
+		text: &str,
+		radix_bits: usize,
+	) -> Result<Self, ParseBitsError> {
+		Self::parse_text(text, Some(radix_bits))
+	}
+
+	/// Parses text into a bit-array.
+	///
+	/// This drives both [`.parse_radix()`] and the [`FromStr`]
+	/// implementation, which differ only in how they select a radix.
+	///
+	/// [`.parse_radix()`]: Self::parse_radix
+	/// [`FromStr`]: core::str::FromStr
+	#[inline]
+	fn parse_text(
+		text: &str,
+		radix_bits: Option<usize>,
+	) -> Result<Self, ParseBitsError> {
+		let mut out = Self::ZERO;
+		let mut bits = out.as_mut_bitslice().iter_mut();
+		let capacity = mem::bits_of::<A>();
+		parse_bits(
+			text,
+			radix_bits,
+			mem::bits_of::<<A::Store as BitStore>::Mem>(),
+			|bit| bits.next().ok_or(capacity).map(|mut slot| *slot = bit),
+		)?;
+		Ok(out)
+	}
 }
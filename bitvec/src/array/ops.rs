@@ -12,6 +12,10 @@ use core::ops::{
 	Index,
 	IndexMut,
 	Not,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
 };
 
 use super::BitArray;
@@ -240,3 +244,57 @@ where
 		self
 	}
 }
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Shl<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, by: usize) -> Self::Output {
+		self <<= by;
+		self
+	}
+}
+
+impl<A, O> ShlAssign<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_register.md")]
+	fn shl_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_register_up(by);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Shr<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, by: usize) -> Self::Output {
+		self >>= by;
+		self
+	}
+}
+
+impl<A, O> ShrAssign<usize> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_register.md")]
+	fn shr_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_register_down(by);
+	}
+}
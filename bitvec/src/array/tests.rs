@@ -21,6 +21,7 @@ use core::{
 	},
 };
 
+use rand::random;
 use static_assertions::*;
 
 use crate::prelude::*;
@@ -95,6 +96,34 @@ fn ops() {
 	let _: &mut BitSlice = &mut f;
 }
 
+#[test]
+fn shifts() {
+	let val = random::<u64>();
+
+	let mut le = BitArray::<[u16; 4], Lsb0>::ZERO;
+	le.store_le(val);
+	let mut be = BitArray::<[u32; 2], Msb0>::ZERO;
+	be.store_be(val);
+	for by in [0, 1, 13, 31, 32, 33, 63] {
+		assert_eq!((le << by).load_le::<u64>(), val << by);
+		assert_eq!((le >> by).load_le::<u64>(), val >> by);
+		assert_eq!((be << by).load_be::<u64>(), val << by);
+		assert_eq!((be >> by).load_be::<u64>(), val >> by);
+	}
+
+	let mut a = le;
+	a <<= 64;
+	assert!(a.not_any());
+	let mut b = be;
+	b >>= 100;
+	assert!(b.not_any());
+
+	le.shift_start_signed(20);
+	assert_eq!(le.load_le::<i64>(), (val as i64) >> 20);
+	be.shift_end_signed(20);
+	assert_eq!(be.load_be::<i64>(), (val as i64) >> 20);
+}
+
 #[test]
 fn traits() {
 	let a = BitArray::<[Cell<u16>; 3], Msb0>::default();
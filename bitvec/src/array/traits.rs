@@ -18,6 +18,7 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
 use tap::TryConv;
@@ -27,7 +28,10 @@ use crate::{
 	index::BitIdx,
 	mem,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	view::BitViewSized,
 };
@@ -234,6 +238,38 @@ where
 	}
 }
 
+/// Parses a bit-array from text.
+///
+/// Each numeral may select binary, octal, or hexadecimal with a `0b`, `0o`, or
+/// `0x` prefix, and is binary if it has none. The parsed bits fill the
+/// bit-array from the front, and text that produces more bits than the
+/// bit-array holds is rejected. See [`ParseBitsError`] for a full description
+/// of the accepted text.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// let arr = bitarr![u8, Lsb0; 1, 0, 0, 1, 1, 0, 1, 0, 1];
+/// let text = format!("{:?}", arr);
+/// assert_eq!(text.parse::<BitArray<[u8; 2], Lsb0>>().unwrap(), arr);
+///
+/// assert!("[0x12, 0x34, 0x56]".parse::<BitArray<[u8; 2]>>().is_err());
+/// ```
+impl<A, O> FromStr for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		Self::parse_text(text, None)
+	}
+}
+
 impl<A, O> Default for BitArray<A, O>
 where
 	A: BitViewSized,
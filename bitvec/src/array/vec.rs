@@ -0,0 +1,825 @@
+//! A bit-vector with fixed, inline, capacity.
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp,
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+use super::BitArray;
+use crate::{
+	mem,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+mod tests;
+
+#[doc = include_str!("../../doc/array/BitArrayVec.md")]
+pub struct BitArrayVec<A = [usize; 1], O = Lsb0>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The backing buffer. All bits at and after `len` are kept cleared.
+	bits: BitArray<A, O>,
+	/// The number of live bits in `bits`.
+	len:  usize,
+}
+
+impl<A, O> BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Constructs a new, empty, bit-array-vector.
+	///
+	/// This is a `const fn`, and so can be used to initialize `static` and
+	/// `const` items.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::new`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.new)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// static EMPTY: BitArrayVec<[u8; 2], Msb0> = BitArrayVec::new();
+	///
+	/// assert!(EMPTY.is_empty());
+	/// assert_eq!(EMPTY.capacity(), 16);
+	/// ```
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			bits: BitArray::ZERO,
+			len:  0,
+		}
+	}
+
+	/// Gets the number of bits that the bit-array-vector can hold.
+	///
+	/// This is the width of the `A` buffer, and is a compile-time constant.
+	#[inline]
+	pub const fn capacity(&self) -> usize {
+		mem::bits_of::<A>()
+	}
+
+	/// Gets the number of live bits in the bit-array-vector.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Tests whether the bit-array-vector holds no live bits.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Tests whether the bit-array-vector is filled to its capacity.
+	#[inline]
+	pub const fn is_full(&self) -> bool {
+		self.len == self.capacity()
+	}
+
+	/// Gets the number of bits that can still be pushed into the
+	/// bit-array-vector.
+	#[inline]
+	pub const fn remaining_capacity(&self) -> usize {
+		self.capacity() - self.len
+	}
+
+	/// Explicitly views the live bits as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		unsafe { self.bits.as_bitslice().get_unchecked(.. self.len) }
+	}
+
+	/// Explicitly views the live bits as a mutable bit-slice.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<A::Store, O> {
+		let len = self.len;
+		unsafe { self.bits.as_mut_bitslice().get_unchecked_mut(.. len) }
+	}
+
+	/// Views the entire backing bit-array, including its dead bits.
+	///
+	/// Bits at and after `self.len()` are always cleared.
+	#[inline]
+	pub fn as_bitarray(&self) -> &BitArray<A, O> {
+		&self.bits
+	}
+
+	/// Removes the length counter, returning the backing bit-array.
+	///
+	/// Bits at and after `self.len()` are cleared.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// bav.extend_from_bitslice(bits![1, 0, 1]);
+	/// assert_eq!(bav.into_bitarray().into_inner(), 0b101);
+	/// ```
+	#[inline]
+	pub fn into_bitarray(self) -> BitArray<A, O> {
+		self.bits
+	}
+
+	/// Shortens the bit-array-vector, clearing all bits after `new_len`.
+	///
+	/// This has no effect if `new_len` is not less than `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::truncate`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.truncate)
+	#[inline]
+	pub fn truncate(&mut self, new_len: usize) {
+		if new_len < self.len {
+			self.bits[new_len .. self.len].fill(false);
+			self.len = new_len;
+		}
+	}
+
+	/// Clears all live bits, leaving the bit-array-vector empty.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Appends a bit to the end of the bit-array-vector.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::push`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.push)
+	///
+	/// ## Panics
+	///
+	/// This panics if the bit-array-vector is full.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Msb0>::new();
+	/// bav.push(true);
+	/// bav.push(false);
+	/// assert_eq!(bav, bits![1, 0]);
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: bool) {
+		self.assert_capacity(1);
+		self.try_push(value).ok();
+	}
+
+	/// Attempts to append a bit to the end of the bit-array-vector.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::try_push`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.try_push)
+	///
+	/// ## Returns
+	///
+	/// If the bit-array-vector is full, this returns the rejected bit inside a
+	/// capacity error, and leaves the bit-array-vector unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// for _ in 0 .. 8 {
+	///   bav.try_push(true).unwrap();
+	/// }
+	/// let err = bav.try_push(false).unwrap_err();
+	/// assert!(!err.element());
+	/// ```
+	#[inline]
+	pub fn try_push(&mut self, value: bool) -> Result<(), CapacityError<bool>> {
+		if self.is_full() {
+			return Err(CapacityError::new(value));
+		}
+		unsafe {
+			self.bits.set_unchecked(self.len, value);
+		}
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Removes the last bit from the bit-array-vector, if present.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::pop`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.pop)
+	#[inline]
+	pub fn pop(&mut self) -> Option<bool> {
+		if self.is_empty() {
+			return None;
+		}
+		self.len -= 1;
+		Some(unsafe { self.bits.replace_unchecked(self.len, false) })
+	}
+
+	/// Inserts a bit at a given position, shifting all bits after it one spot
+	/// towards the end.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::insert`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.insert)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`, or if the
+	/// bit-array-vector is full.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// bav.extend_from_bitslice(bits![0, 0, 1]);
+	/// bav.insert(1, true);
+	/// assert_eq!(bav, bits![0, 1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn insert(&mut self, index: usize, value: bool) {
+		self.assert_capacity(1);
+		self.try_insert(index, value).ok();
+	}
+
+	/// Attempts to insert a bit at a given position, shifting all bits after it
+	/// one spot towards the end.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::try_insert`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.try_insert)
+	///
+	/// ## Returns
+	///
+	/// If the bit-array-vector is full, this returns the rejected bit inside a
+	/// capacity error, and leaves the bit-array-vector unchanged.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	#[inline]
+	pub fn try_insert(
+		&mut self,
+		index: usize,
+		value: bool,
+	) -> Result<(), CapacityError<bool>> {
+		self.as_bitslice().assert_in_bounds(index, 0 ..= self.len);
+		self.try_push(value)?;
+		self.as_mut_bitslice()[index ..].rotate_right(1);
+		Ok(())
+	}
+
+	/// Removes a bit at a given position, shifting all bits after it one spot
+	/// towards the front.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::remove`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// bav.extend_from_bitslice(bits![0, 1, 0, 1]);
+	/// assert!(bav.remove(1));
+	/// assert_eq!(bav, bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> bool {
+		self.as_bitslice().assert_in_bounds(index, 0 .. self.len);
+		self.as_mut_bitslice()[index ..].rotate_left(1);
+		self.pop().unwrap()
+	}
+
+	/// Removes a bit at a given position, replacing it with the last bit.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::swap_remove`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.swap_remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	#[inline]
+	pub fn swap_remove(&mut self, index: usize) -> bool {
+		self.as_bitslice().assert_in_bounds(index, 0 .. self.len);
+		let last = self.len - 1;
+		self.as_mut_bitslice().swap(index, last);
+		self.pop().unwrap()
+	}
+
+	/// Appends the contents of a bit-slice to the end of the bit-array-vector.
+	///
+	/// The source may use any storage type and ordering.
+	///
+	/// ## Original
+	///
+	/// [`ArrayVec::try_extend_from_slice`](https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html#method.try_extend_from_slice)
+	///
+	/// ## Panics
+	///
+	/// This panics if `other` does not fit in the remaining capacity.
+	#[inline]
+	pub fn extend_from_bitslice<T, O2>(&mut self, other: &BitSlice<T, O2>)
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_capacity(other.len());
+		self.try_extend_from_bitslice(other).ok();
+	}
+
+	/// Attempts to append the contents of a bit-slice to the end of the
+	/// bit-array-vector.
+	///
+	/// ## Returns
+	///
+	/// If `other` does not fit in the remaining capacity, this returns a
+	/// capacity error, and leaves the bit-array-vector unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// assert!(bav.try_extend_from_bitslice(bits![1; 6]).is_ok());
+	/// assert!(bav.try_extend_from_bitslice(bits![0; 3]).is_err());
+	/// assert_eq!(bav.len(), 6);
+	/// ```
+	#[inline]
+	pub fn try_extend_from_bitslice<T, O2>(
+		&mut self,
+		other: &BitSlice<T, O2>,
+	) -> Result<(), CapacityError>
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len;
+		let new_len = len + other.len();
+		if new_len > self.capacity() {
+			return Err(CapacityError::new(()));
+		}
+		self.bits[len .. new_len].clone_from_bitslice(other);
+		self.len = new_len;
+		Ok(())
+	}
+
+	/// Attempts to append every bit produced by an iterator.
+	///
+	/// ## Returns
+	///
+	/// If the iterator produces more bits than will fit, this returns the first
+	/// rejected bit inside a capacity error. The bits that did fit remain in
+	/// the bit-array-vector, and the iterator is not drained any further.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	/// let err = bav.try_extend((0 .. 10).map(|n| n % 3 == 0)).unwrap_err();
+	/// assert!(!err.element());
+	/// assert!(bav.is_full());
+	/// assert_eq!(bav, bits![1, 0, 0, 1, 0, 0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<bool>>
+	where I: IntoIterator<Item = bool> {
+		iter.into_iter().try_for_each(|bit| self.try_push(bit))
+	}
+
+	/// Panics if `additional` bits do not fit in the remaining capacity.
+	fn assert_capacity(&self, additional: usize) {
+		let capa = self.capacity();
+		assert!(
+			additional <= self.remaining_capacity(),
+			"bit-array-vector capacity exceeded: {} > {}",
+			self.len + additional,
+			capa,
+		);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsRef<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsMut<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Borrow<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> BorrowMut<BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Clone for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			len:  self.len,
+		}
+	}
+}
+
+impl<A, O> Copy for BitArrayVec<A, O>
+where
+	A: BitViewSized + Copy,
+	O: BitOrder,
+{
+}
+
+impl<A, O> Eq for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Ord for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O1, A, O2, T> PartialEq<BitArrayVec<A, O2>> for BitSlice<T, O1>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	A: BitViewSized,
+	T: BitStore,
+{
+	#[inline]
+	fn eq(&self, other: &BitArrayVec<A, O2>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialEq<Rhs> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialEq<Rhs>,
+{
+	#[inline]
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, T, O> PartialOrd<BitArrayVec<A, O>> for BitSlice<T, O>
+where
+	A: BitViewSized,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &BitArrayVec<A, O>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialOrd<Rhs> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialOrd<Rhs>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Default for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<A, O> Debug for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.as_bitslice().as_bitspan().render(fmt, "ArrayVec", &[(
+			"capacity",
+			&self.capacity() as &dyn Debug,
+		)])?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+easy_fmt! {
+	impl Binary
+	impl Display
+	impl LowerHex
+	impl Octal
+	impl UpperHex
+	for BitArrayVec<A>
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Hash for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Deref for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Target = BitSlice<A::Store, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> DerefMut for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+/// Extends the bit-array-vector with bits from an iterator.
+///
+/// ## Panics
+///
+/// This panics if the iterator produces more bits than will fit. Use
+/// [`.try_extend()`] to recover from overflow.
+///
+/// [`.try_extend()`]: BitArrayVec::try_extend
+impl<A, O> Extend<bool> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = bool> {
+		iter.into_iter().for_each(|bit| self.push(bit));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> Extend<&'a bool> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a bool> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+/// Collects bits from an iterator into a new bit-array-vector.
+///
+/// ## Panics
+///
+/// This panics if the iterator produces more bits than will fit.
+#[cfg(not(tarpaulin_include))]
+impl<A, O> FromIterator<bool> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = bool> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+/// Wraps a bit-array as a full bit-array-vector.
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArray<A, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArray<A, O>) -> Self {
+		let len = bits.len();
+		Self { bits, len }
+	}
+}
+
+/// Copies a bit-slice into a new bit-array-vector, if it fits.
+impl<A, O> TryFrom<&BitSlice<A::Store, O>> for BitArrayVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Error = CapacityError;
+
+	#[inline]
+	fn try_from(bits: &BitSlice<A::Store, O>) -> Result<Self, Self::Error> {
+		let mut out = Self::new();
+		out.try_extend_from_bitslice(bits)?;
+		Ok(out)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> IntoIterator for &'a BitArrayVec<A, O>
+where
+	O: BitOrder,
+	A: 'a + BitViewSized,
+{
+	type IntoIter = <&'a BitSlice<A::Store, O> as IntoIterator>::IntoIter;
+	type Item = <&'a BitSlice<A::Store, O> as IntoIterator>::Item;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_bitslice().into_iter()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> IntoIterator for &'a mut BitArrayVec<A, O>
+where
+	O: BitOrder,
+	A: 'a + BitViewSized,
+{
+	type IntoIter = <&'a mut BitSlice<A::Store, O> as IntoIterator>::IntoIter;
+	type Item = <&'a mut BitSlice<A::Store, O> as IntoIterator>::Item;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_bitslice().into_iter()
+	}
+}
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/array/CapacityError.md")]
+pub struct CapacityError<T = ()> {
+	/// The value that could not be stored.
+	element: T,
+}
+
+impl<T> CapacityError<T> {
+	/// Wraps a value that was rejected for lack of space.
+	#[inline]
+	pub const fn new(element: T) -> Self {
+		Self { element }
+	}
+
+	/// Unwraps the rejected value.
+	#[inline]
+	pub fn element(self) -> T {
+		self.element
+	}
+
+	/// Discards the rejected value.
+	#[inline]
+	pub fn simplify(self) -> CapacityError {
+		CapacityError::new(())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T> Debug for CapacityError<T> {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("CapacityError: ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T> Display for CapacityError<T> {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("insufficient capacity in the bit-array-vector")
+	}
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
@@ -0,0 +1,152 @@
+//! Unit tests for bit-array-vectors.
+
+#![cfg(test)]
+
+#[cfg(feature = "alloc")]
+use alloc::{
+	format,
+	vec::Vec,
+};
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cell::Cell,
+	convert::TryFrom,
+	fmt::Debug,
+	hash::Hash,
+};
+
+use static_assertions::*;
+
+use crate::{
+	array::CapacityError,
+	prelude::*,
+};
+
+/// A bit-array-vector that can be built in a `static`.
+static EMPTY: BitArrayVec<[u16; 2], Msb0> = BitArrayVec::new();
+
+#[test]
+fn core_impl() {
+	assert_impl_all!(
+		BitArrayVec: AsMut<BitSlice>,
+		AsRef<BitSlice>,
+		Borrow<BitSlice>,
+		BorrowMut<BitSlice>,
+		Clone,
+		Copy,
+		Debug,
+		Default,
+		Eq,
+		Extend<bool>,
+		Hash,
+		Ord,
+		Send,
+		Sync,
+	);
+	assert_impl_all!(BitArrayVec<[Cell<u8>; 2], Lsb0>: Send);
+	assert_not_impl_any!(BitArrayVec<[Cell<u8>; 2], Lsb0>: Copy, Sync);
+}
+
+#[test]
+fn push_pop() {
+	assert!(EMPTY.is_empty());
+	assert_eq!(EMPTY.capacity(), 32);
+
+	let mut bav = EMPTY;
+	for idx in 0 .. 32 {
+		bav.push(idx % 3 == 0);
+	}
+	assert!(bav.is_full());
+	assert_eq!(bav.remaining_capacity(), 0);
+	let err = bav.try_push(true).unwrap_err();
+	assert!(err.element());
+	assert_eq!(err.simplify(), CapacityError::new(()));
+
+	for idx in (0 .. 32).rev() {
+		assert_eq!(bav.pop(), Some(idx % 3 == 0));
+	}
+	assert_eq!(bav.pop(), None);
+	assert_eq!(bav.into_bitarray().into_inner(), [0; 2]);
+}
+
+#[test]
+#[should_panic = "bit-array-vector capacity exceeded: 9 > 8"]
+fn push_full() {
+	let mut bav = BitArrayVec::<u8, Lsb0>::from(BitArray::ZERO);
+	bav.push(false);
+}
+
+#[test]
+fn insert_remove() {
+	let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	bav.extend_from_bitslice(bits![u16, Msb0; 1, 1, 0, 0]);
+	bav.insert(2, true);
+	bav.insert(5, true);
+	assert_eq!(bav, bits![1, 1, 1, 0, 0, 1]);
+
+	assert!(bav.remove(0));
+	assert_eq!(bav, bits![1, 1, 0, 0, 1]);
+	assert!(bav.swap_remove(1));
+	assert_eq!(bav, bits![1, 1, 0, 0]);
+	assert_eq!(bav.into_bitarray().into_inner(), 0b0011);
+
+	bav.extend([true; 4].iter());
+	assert!(bav.try_insert(0, false).is_err());
+	assert_eq!(bav.into_bitarray().into_inner(), 0xF3);
+}
+
+#[test]
+#[should_panic = "index 3 out of range"]
+fn insert_oob() {
+	let mut bav = BitArrayVec::<u8, Lsb0>::new();
+	bav.push(true);
+	bav.insert(3, true);
+}
+
+#[test]
+fn extend() {
+	let mut bav = BitArrayVec::<[u8; 2], Lsb0>::new();
+	bav.try_extend_from_bitslice(bits![u32, Msb0; 1; 10])
+		.unwrap();
+	assert!(bav.try_extend_from_bitslice(bits![0; 7]).is_err());
+	assert_eq!(bav.len(), 10);
+
+	let err = bav.try_extend((0 .. 10).map(|n| n % 2 == 0)).unwrap_err();
+	assert!(err.element());
+	assert_eq!(bav.into_bitarray().into_inner(), [0xFF, 0b0101_0111]);
+
+	bav.truncate(4);
+	assert_eq!(bav.into_bitarray().into_inner(), [0x0F, 0]);
+	bav.clear();
+	assert!(bav.as_bitarray().not_any());
+
+	let bav = (0 .. 5).map(|n| n > 2).collect::<BitArrayVec>();
+	assert_eq!(bav, bits![0, 0, 0, 1, 1]);
+	let copy = BitArrayVec::<[usize; 1], Lsb0>::try_from(bav.as_bitslice());
+	assert_eq!(copy.unwrap(), bav);
+}
+
+#[test]
+fn traits() {
+	let mut bav = BitArrayVec::<u8, Msb0>::new();
+	bav.extend_from_bitslice(bits![0, 1]);
+	assert!(*bits![0, 1] == bav);
+	assert!(bav < bits![1]);
+	#[cfg(feature = "alloc")]
+	assert_eq!(bav.iter().by_vals().collect::<Vec<_>>(), [false, true]);
+	for mut bit in &mut bav {
+		*bit = !*bit;
+	}
+	assert_eq!(bav, bits![1, 0]);
+
+	#[cfg(feature = "alloc")]
+	{
+		assert_eq!(format!("{}", bav), "[1, 0]");
+		assert_eq!(format!("{:b}", bav), "[10]");
+		assert!(format!("{:?}", bav)
+			.starts_with("BitArrayVec<u8, bitvec::order::Msb0>"));
+	}
+}
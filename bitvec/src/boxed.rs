@@ -1,16 +1,24 @@
 #![cfg(feature = "alloc")]
 #![doc = include_str!("../doc/boxed.md")]
 
-use alloc::boxed::Box;
+use alloc::{
+	boxed::Box,
+	collections::TryReserveError,
+};
 use core::{
 	mem::ManuallyDrop,
+	ptr,
 	slice,
 };
 
-use tap::{
-	Pipe,
-	Tap,
+use allocator_api2::{
+	alloc::{
+		Allocator,
+		Global,
+	},
+	boxed::Box as AllocBox,
 };
+use tap::Pipe;
 use wyz::comu::Mut;
 
 use crate::{
@@ -21,10 +29,14 @@ use crate::{
 		Lsb0,
 	},
 	ptr::{
+		AddressExt,
 		BitPtr,
 		BitSpan,
 	},
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	vec::BitVec,
 	view::BitView,
@@ -38,17 +50,21 @@ mod traits;
 
 pub use self::iter::IntoIter;
 
-#[repr(transparent)]
+#[repr(C)]
 #[doc = include_str!("../doc/boxed/BitBox.md")]
-pub struct BitBox<T = usize, O = Lsb0>
+pub struct BitBox<T = usize, O = Lsb0, A = Global>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// Describes the region that the box owns.
 	bitspan: BitSpan<Mut, T, O>,
+	/// The allocator that owns the buffer.
+	alloc:   A,
 }
 
+/// Constructors and conversions for the global allocator.
 impl<T, O> BitBox<T, O>
 where
 	T: BitStore,
@@ -89,6 +105,61 @@ where
 		BitVec::from_bitslice(slice).into_boxed_bitslice()
 	}
 
+	/// Attempts to copy a bit-slice region into a new bit-box allocation.
+	///
+	/// This behaves exactly as [`::from_bitslice()`] does, except that it
+	/// fails, rather than aborting, if the allocator cannot provide the
+	/// memory.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0b0101_1011u8;
+	/// let bits = data.view_bits::<Msb0>();
+	/// let bb = BitBox::try_from_bitslice(&bits[2 ..]).unwrap();
+	/// assert_eq!(bb, bits[2 ..]);
+	/// ```
+	///
+	/// [`::from_bitslice()`]: Self::from_bitslice
+	#[inline]
+	pub fn try_from_bitslice(
+		slice: &BitSlice<T, O>,
+	) -> Result<Self, TryReserveError> {
+		//  The allocation is requested at its exact size, so boxing it should not
+		//  need to shrink it.
+		BitVec::try_from_bitslice(slice).map(BitVec::into_boxed_bitslice)
+	}
+
+	/// Parses text into a bit-box, reading numerals in a chosen radix.
+	///
+	/// This parses the text into a [`BitVec`], then freezes it. See
+	/// [`BitVec::parse_radix`] for details.
+	///
+	/// ## Panics
+	///
+	/// This panics if `radix_bits` is not in `1 ..= 5`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = BitBox::<u8, Lsb0>::parse_radix("[0o005, 0o207]", 3).unwrap();
+	/// assert_eq!(bb, bits![
+	///   0, 0, 0, 0, 0, 1, 0, 1,
+	///   1, 0, 0, 0, 0, 1, 1, 1,
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn parse_radix(
+		text: &str,
+		radix_bits: usize,
+	) -> Result<Self, ParseBitsError> {
+		BitVec::parse_radix(text, radix_bits).map(BitVec::into_boxed_bitslice)
+	}
+
 	/// Converts a `Box<[T]>` into a `BitBox<T, O>`, in place.
 	///
 	/// This does not affect the referent buffer, and only transforms the
@@ -146,7 +217,10 @@ where
 
 		BitPtr::from_mut_slice(boxed.as_mut())
 			.span(boxed.len() * mem::bits_of::<T::Mem>())
-			.map(|bitspan| Self { bitspan })
+			.map(|bitspan| Self {
+				bitspan,
+				alloc: Global,
+			})
 			.map_err(|_| ManuallyDrop::into_inner(boxed))
 	}
 
@@ -172,6 +246,36 @@ where
 			.as_raw_mut_slice()
 			.pipe(|slice| unsafe { Box::from_raw(slice) })
 	}
+}
+
+impl<T, O, A> BitBox<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	/// Copies a bit-slice region into a new bit-box allocation, made by the
+	/// given allocator.
+	///
+	/// This behaves exactly as [`::from_bitslice()`] does, except that the
+	/// buffer is owned by `alloc` rather than by the global allocator.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let bb = BitBox::from_bitslice_in(bits, Global);
+	/// assert_eq!(bb, bits);
+	/// ```
+	///
+	/// [`::from_bitslice()`]: BitBox::from_bitslice
+	#[inline]
+	pub fn from_bitslice_in(slice: &BitSlice<T, O>, alloc: A) -> Self {
+		BitVec::from_bitslice_in(slice, alloc).into_boxed_bitslice()
+	}
 
 	/// Converts the bit-box into a bit-vector.
 	///
@@ -205,24 +309,26 @@ where
 	///
 	/// [0]: crate::vec::BitVec::into_boxed_bitslice
 	#[inline]
-	pub fn into_bitvec(self) -> BitVec<T, O> {
-		let bitspan = self.bitspan;
-		/* This pipeline converts the underlying `Box<[T]>` into a `Vec<T>`,
-		 * then converts that into a `BitVec`. This handles any changes that
-		 * may occur in the allocator. Once done, the original head/span
-		 * values need to be written into the `BitVec`, since the conversion
-		 * from `Vec` always fully spans the live elements.
+	pub fn into_bitvec(self) -> BitVec<T, O, A> {
+		let mut bitspan = self.bitspan;
+		/* This pipeline converts the underlying `Box<[T], A>` into a
+		 * `Vec<T, A>`, then reassembles that into a `BitVec`. This handles any
+		 * changes that may occur in the allocator. Once done, the original
+		 * head/span values need to be written into the `BitVec`, since the
+		 * conversion from `Vec` always fully spans the live elements.
 		 */
-		self.pipe(ManuallyDrop::new)
-			.with_box(|b| unsafe { ManuallyDrop::take(b) })
-			.into_vec()
-			.pipe(BitVec::from_vec)
-			.tap_mut(|bv| unsafe {
-				// len first! Otherwise, the descriptor might briefly go out of
-				// bounds.
-				bv.set_len_unchecked(bitspan.len());
-				bv.set_head(bitspan.head());
-			})
+		let (addr, _, capacity, alloc) =
+			self.into_alloc_box().into_vec().into_raw_parts_with_alloc();
+		let head = bitspan.head().into_inner() as usize;
+		unsafe {
+			bitspan.set_address(addr.into_address());
+			BitVec::from_raw_parts_in(
+				bitspan.to_bitptr(),
+				bitspan.len(),
+				capacity * mem::bits_of::<T::Mem>() - head,
+				alloc,
+			)
+		}
 	}
 
 	/// Explicitly views the bit-box as a bit-slice.
@@ -348,6 +454,15 @@ where
 		}
 	}
 
+	/// Converts a bit-box into an allocator-aware `Box` of its underlying
+	/// storage, keeping its allocator.
+	#[inline]
+	fn into_alloc_box(self) -> AllocBox<[T], A> {
+		let mut this = ManuallyDrop::new(self);
+		let alloc = unsafe { ptr::read(&this.alloc) };
+		unsafe { AllocBox::from_raw_in(this.as_raw_mut_slice(), alloc) }
+	}
+
 	/// Permits a function to modify the `Box` backing storage of a `BitBox`
 	/// handle.
 	///
@@ -356,9 +471,9 @@ where
 	/// `Box` is written back into `self` and forgotten.
 	#[inline]
 	fn with_box<F, R>(&mut self, func: F) -> R
-	where F: FnOnce(&mut ManuallyDrop<Box<[T]>>) -> R {
-		self.as_raw_mut_slice()
-			.pipe(|raw| unsafe { Box::from_raw(raw) })
+	where F: FnOnce(&mut ManuallyDrop<AllocBox<[T], &A>>) -> R {
+		let raw: *mut [T] = self.as_raw_mut_slice();
+		unsafe { AllocBox::from_raw_in(raw, &self.alloc) }
 			.pipe(ManuallyDrop::new)
 			.pipe_ref_mut(func)
 	}
@@ -1,7 +1,17 @@
 //! Port of the `Box<[T]>` inherent API.
 
-use core::mem;
+use core::{
+	mem::{
+		self,
+		ManuallyDrop,
+	},
+	ptr,
+};
 
+use allocator_api2::alloc::{
+	Allocator,
+	Global,
+};
 use tap::Tap;
 
 use super::BitBox;
@@ -58,8 +68,47 @@ where
 	/// [`::leak()`]: Self::leak
 	#[inline]
 	pub unsafe fn from_raw(raw: *mut BitSlice<T, O>) -> Self {
+		Self::from_raw_in(raw, Global)
+	}
+}
+
+impl<T, O, A> BitBox<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	/// Constructs a bit-box from a raw bit-slice pointer and the allocator
+	/// that owns its buffer.
+	///
+	/// ## Original
+	///
+	/// [`Box::from_raw_in`](alloc::boxed::Box::from_raw_in)
+	///
+	/// ## Safety
+	///
+	/// As with [`::from_raw()`], the pointer must have been produced by
+	/// leaking a prior `BitBox`, and `alloc` must be the allocator that owned
+	/// it. The pointer may be reclaimed at most once.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let bb = BitBox::from_bitslice_in(bits![0, 1, 0, 0, 1], Global);
+	/// let (ptr, alloc) = BitBox::into_raw_with_allocator(bb);
+	/// let bb = unsafe { BitBox::from_raw_in(ptr, alloc) };
+	/// assert_eq!(bb, bits![0, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`::from_raw()`]: BitBox::from_raw
+	#[inline]
+	pub unsafe fn from_raw_in(raw: *mut BitSlice<T, O>, alloc: A) -> Self {
 		Self {
 			bitspan: BitSpan::from_bitslice_ptr_mut(raw),
+			alloc,
 		}
 	}
 
@@ -94,6 +143,34 @@ where
 		Self::leak(this)
 	}
 
+	/// Consumes the bit-box, returning a raw bit-slice pointer and the
+	/// allocator that owns its buffer.
+	///
+	/// The pointer and allocator can be passed to [`::from_raw_in()`] to
+	/// restore the bit-box.
+	///
+	/// ## Original
+	///
+	/// [`Box::into_raw_with_allocator`](alloc::boxed::Box::into_raw_with_allocator)
+	///
+	/// [`::from_raw_in()`]: Self::from_raw_in
+	#[inline]
+	pub fn into_raw_with_allocator(this: Self) -> (*mut BitSlice<T, O>, A) {
+		let this = ManuallyDrop::new(this);
+		let alloc = unsafe { ptr::read(&this.alloc) };
+		(this.bitspan.into_bitslice_ptr_mut(), alloc)
+	}
+
+	/// Gets a reference to the allocator that owns the bit-box’s buffer.
+	///
+	/// ## Original
+	///
+	/// [`Box::allocator`](alloc::boxed::Box::allocator)
+	#[inline]
+	pub fn allocator(this: &Self) -> &A {
+		&this.alloc
+	}
+
 	/// Deliberately leaks the allocated memory, returning an
 	/// `&'static mut BitSlice` reference.
 	///
@@ -125,7 +202,10 @@ where
 	/// [`::into_raw()`]: Self::into_raw
 	#[inline]
 	pub fn leak<'a>(this: Self) -> &'a mut BitSlice<T, O>
-	where T: 'a {
+	where
+		T: 'a,
+		A: 'a,
+	{
 		unsafe { this.bitspan.into_bitslice_mut() }.tap(|_| mem::forget(this))
 	}
 
@@ -133,7 +213,7 @@ where
 	#[doc(hidden)]
 	#[cfg(not(tarpaulin_include))]
 	#[deprecated = "use `.into_bitvec()` instead"]
-	pub fn into_vec(self) -> BitVec<T, O> {
+	pub fn into_vec(self) -> BitVec<T, O, A> {
 		self.into_bitvec()
 	}
 }
@@ -10,6 +10,11 @@ use core::{
 	ops::Range,
 };
 
+use allocator_api2::alloc::{
+	Allocator,
+	Global,
+};
+
 use super::BitBox;
 use crate::{
 	order::{
@@ -21,12 +26,13 @@ use crate::{
 };
 
 /// [Original](alloc::vec::IntoIter)
-impl<T, O> IntoIterator for BitBox<T, O>
+impl<T, O, A> IntoIterator for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
-	type IntoIter = IntoIter<T, O>;
+	type IntoIter = IntoIter<T, O, A>;
 	type Item = bool;
 
 	#[inline]
@@ -41,26 +47,28 @@ where
 
 [`vec::IntoIter`](alloc::vec::IntoIter)
 **/
-pub struct IntoIter<T = usize, O = Lsb0>
+pub struct IntoIter<T = usize, O = Lsb0, A = Global>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// The original `BitBox`, kept so it can correctly drop.
-	_buf: BitBox<T, O>,
+	_buf: BitBox<T, O, A>,
 	/// A range of indices yet to be iterated.
 	//  TODO(myrrlyn): Race this against `BitPtrRange<Mut, T, O>`.
 	iter: Range<usize>,
 }
 
-impl<T, O> IntoIter<T, O>
+impl<T, O, A> IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// Wraps a bit-array in an iterator view. This is irreversible.
 	#[inline]
-	fn new(this: BitBox<T, O>) -> Self {
+	fn new(this: BitBox<T, O, A>) -> Self {
 		let iter = 0 .. this.len();
 		Self { _buf: this, iter }
 	}
@@ -120,10 +128,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.IntoIter.html#impl-AsRef%3C%5BT%5D%3E)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for IntoIter<T, O>
+impl<T, O, A> AsRef<BitSlice<T, O>> for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_ref(&self) -> &BitSlice<T, O> {
@@ -132,10 +141,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Clone for IntoIter<T, O>
+impl<T, O, A> Clone for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Clone,
 {
 	#[inline]
 	fn clone(&self) -> Self {
@@ -148,10 +158,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.IntoIter.html#impl-Debug)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Debug for IntoIter<T, O>
+impl<T, O, A> Debug for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
 		fmt.debug_tuple("IntoIter")
@@ -160,10 +171,11 @@ where
 	}
 }
 
-impl<T, O> Iterator for IntoIter<T, O>
+impl<T, O, A> Iterator for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Item = bool;
 
@@ -184,10 +196,11 @@ where
 	}
 }
 
-impl<T, O> DoubleEndedIterator for IntoIter<T, O>
+impl<T, O, A> DoubleEndedIterator for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
@@ -204,10 +217,11 @@ where
 	}
 }
 
-impl<T, O> ExactSizeIterator for IntoIter<T, O>
+impl<T, O, A> ExactSizeIterator for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn len(&self) -> usize {
@@ -215,26 +229,29 @@ where
 	}
 }
 
-impl<T, O> FusedIterator for IntoIter<T, O>
+impl<T, O, A> FusedIterator for IntoIter<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.IntoIter.html#impl-Send)
 // #[allow(clippy::non_send_fields_in_send_ty)]
-unsafe impl<T, O> Send for IntoIter<T, O>
+unsafe impl<T, O, A> Send for IntoIter<T, O, A>
 where
 	T: BitStore + Sync,
 	O: BitOrder,
+	A: Allocator + Send,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.IntoIter.html#impl-Sync)
-unsafe impl<T, O> Sync for IntoIter<T, O>
+unsafe impl<T, O, A> Sync for IntoIter<T, O, A>
 where
 	T: BitStore + Sync,
 	O: BitOrder,
+	A: Allocator + Sync,
 {
 }
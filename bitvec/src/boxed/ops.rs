@@ -17,6 +17,8 @@ use core::{
 	},
 };
 
+use allocator_api2::alloc::Allocator;
+
 use super::BitBox;
 use crate::{
 	order::BitOrder,
@@ -25,33 +27,36 @@ use crate::{
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitAndAssign<BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: BitBox<T, O>) {
+	fn bitand_assign(&mut self, rhs: BitBox<T, O, A>) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<&BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitAndAssign<&BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: &BitBox<T, O>) {
+	fn bitand_assign(&mut self, rhs: &BitBox<T, O, A>) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
-impl<T, O, Rhs> BitAnd<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitAnd<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitAndAssign<Rhs>,
 {
 	type Output = Self;
@@ -63,10 +68,11 @@ where
 	}
 }
 
-impl<T, O, Rhs> BitAndAssign<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitAndAssign<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitAndAssign<Rhs>,
 {
 	#[inline]
@@ -76,33 +82,36 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitOrAssign<BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: BitBox<T, O>) {
+	fn bitor_assign(&mut self, rhs: BitBox<T, O, A>) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<&BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitOrAssign<&BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: &BitBox<T, O>) {
+	fn bitor_assign(&mut self, rhs: &BitBox<T, O, A>) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
-impl<T, O, Rhs> BitOr<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitOr<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitOrAssign<Rhs>,
 {
 	type Output = Self;
@@ -114,10 +123,11 @@ where
 	}
 }
 
-impl<T, O, Rhs> BitOrAssign<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitOrAssign<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitOrAssign<Rhs>,
 {
 	#[inline]
@@ -127,33 +137,36 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitXorAssign<BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: BitBox<T, O>) {
+	fn bitxor_assign(&mut self, rhs: BitBox<T, O, A>) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<&BitBox<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitXorAssign<&BitBox<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: &BitBox<T, O>) {
+	fn bitxor_assign(&mut self, rhs: &BitBox<T, O, A>) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
-impl<T, O, Rhs> BitXor<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitXor<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitXorAssign<Rhs>,
 {
 	type Output = Self;
@@ -165,10 +178,11 @@ where
 	}
 }
 
-impl<T, O, Rhs> BitXorAssign<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> BitXorAssign<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitXorAssign<Rhs>,
 {
 	#[inline]
@@ -177,10 +191,11 @@ where
 	}
 }
 
-impl<T, O> Deref for BitBox<T, O>
+impl<T, O, A> Deref for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Target = BitSlice<T, O>;
 
@@ -190,10 +205,11 @@ where
 	}
 }
 
-impl<T, O> DerefMut for BitBox<T, O>
+impl<T, O, A> DerefMut for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn deref_mut(&mut self) -> &mut Self::Target {
@@ -201,10 +217,11 @@ where
 	}
 }
 
-impl<T, O> Drop for BitBox<T, O>
+impl<T, O, A> Drop for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn drop(&mut self) {
@@ -213,10 +230,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> Index<Idx> for BitBox<T, O>
+impl<T, O, A, Idx> Index<Idx> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: Index<Idx>,
 {
 	type Output = <BitSlice<T, O> as Index<Idx>>::Output;
@@ -228,10 +246,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> IndexMut<Idx> for BitBox<T, O>
+impl<T, O, A, Idx> IndexMut<Idx> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: IndexMut<Idx>,
 {
 	#[inline]
@@ -240,10 +259,11 @@ where
 	}
 }
 
-impl<T, O> Not for BitBox<T, O>
+impl<T, O, A> Not for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Output = Self;
 
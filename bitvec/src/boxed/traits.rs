@@ -22,25 +22,31 @@ use core::{
 		Hasher,
 	},
 	iter::FromIterator,
+	str::FromStr,
 };
 
+use allocator_api2::alloc::Allocator;
 use tap::Pipe;
 
 use super::BitBox;
 use crate::{
 	array::BitArray,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	vec::BitVec,
 	view::BitViewSized,
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Borrow<BitSlice<T, O>> for BitBox<T, O>
+impl<T, O, A> Borrow<BitSlice<T, O>> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn borrow(&self) -> &BitSlice<T, O> {
@@ -49,10 +55,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BorrowMut<BitSlice<T, O>> for BitBox<T, O>
+impl<T, O, A> BorrowMut<BitSlice<T, O>> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn borrow_mut(&mut self) -> &mut BitSlice<T, O> {
@@ -61,30 +68,33 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Clone for BitBox<T, O>
+impl<T, O, A> Clone for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Clone,
 {
 	#[inline]
 	fn clone(&self) -> Self {
-		self.as_bitslice().pipe(Self::from_bitslice)
+		Self::from_bitslice_in(self.as_bitslice(), self.alloc.clone())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Eq for BitBox<T, O>
+impl<T, O, A> Eq for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Ord for BitBox<T, O>
+impl<T, O, A> Ord for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn cmp(&self, other: &Self) -> cmp::Ordering {
@@ -93,52 +103,56 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<O1, O2, T1, T2> PartialEq<BitBox<T2, O2>> for BitSlice<T1, O1>
+impl<O1, O2, T1, T2, A> PartialEq<BitBox<T2, O2, A>> for BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitBox<T2, O2>) -> bool {
+	fn eq(&self, other: &BitBox<T2, O2, A>) -> bool {
 		self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<O1, O2, T1, T2> PartialEq<BitBox<T2, O2>> for &BitSlice<T1, O1>
+impl<O1, O2, T1, T2, A> PartialEq<BitBox<T2, O2, A>> for &BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitBox<T2, O2>) -> bool {
+	fn eq(&self, other: &BitBox<T2, O2, A>) -> bool {
 		*self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<O1, O2, T1, T2> PartialEq<BitBox<T2, O2>> for &mut BitSlice<T1, O1>
+impl<O1, O2, T1, T2, A> PartialEq<BitBox<T2, O2, A>> for &mut BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitBox<T2, O2>) -> bool {
+	fn eq(&self, other: &BitBox<T2, O2, A>) -> bool {
 		**self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialEq<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> PartialEq<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	Rhs: ?Sized + PartialEq<BitSlice<T, O>>,
 {
 	#[inline]
@@ -148,24 +162,26 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<O1, O2, T1, T2> PartialOrd<BitBox<T2, O2>> for BitSlice<T1, O1>
+impl<O1, O2, T1, T2, A> PartialOrd<BitBox<T2, O2, A>> for BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitBox<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitBox<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialOrd<Rhs> for BitBox<T, O>
+impl<T, O, A, Rhs> PartialOrd<Rhs> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	Rhs: ?Sized + PartialOrd<BitSlice<T, O>>,
 {
 	#[inline]
@@ -175,38 +191,43 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, O1, O2, T1, T2> PartialOrd<BitBox<T2, O2>> for &'a BitSlice<T1, O1>
+impl<'a, O1, O2, T1, T2, A> PartialOrd<BitBox<T2, O2, A>>
+	for &'a BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitBox<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitBox<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, O1, O2, T1, T2> PartialOrd<BitBox<T2, O2>> for &'a mut BitSlice<T1, O1>
+impl<'a, O1, O2, T1, T2, A> PartialOrd<BitBox<T2, O2, A>>
+	for &'a mut BitSlice<T1, O1>
 where
 	O1: BitOrder,
 	O2: BitOrder,
 	T1: BitStore,
 	T2: BitStore,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitBox<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitBox<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for BitBox<T, O>
+impl<T, O, A> AsRef<BitSlice<T, O>> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_ref(&self) -> &BitSlice<T, O> {
@@ -215,10 +236,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsMut<BitSlice<T, O>> for BitBox<T, O>
+impl<T, O, A> AsMut<BitSlice<T, O>> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_mut(&mut self) -> &mut BitSlice<T, O> {
@@ -273,13 +295,14 @@ where
 	}
 }
 
-impl<T, O> From<BitVec<T, O>> for BitBox<T, O>
+impl<T, O, A> From<BitVec<T, O, A>> for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn from(bv: BitVec<T, O>) -> Self {
+	fn from(bv: BitVec<T, O, A>) -> Self {
 		bv.into_boxed_bitslice()
 	}
 }
@@ -308,6 +331,27 @@ where
 	}
 }
 
+/// Parses a bit-box from text.
+///
+/// This parses the text into a [`BitVec`], then freezes it. See the
+/// [`BitVec` implementation][0] for details.
+///
+/// [0]: crate::vec::BitVec#impl-FromStr
+#[cfg(not(tarpaulin_include))]
+impl<T, O> FromStr for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		text.parse::<BitVec<T, O>>()
+			.map(BitVec::into_boxed_bitslice)
+	}
+}
+
 impl<T, O> Default for BitBox<T, O>
 where
 	T: BitStore,
@@ -319,10 +363,11 @@ where
 	}
 }
 
-impl<T, O> Debug for BitBox<T, O>
+impl<T, O, A> Debug for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
 		self.bitspan.render(fmt, "Box", None)?;
@@ -356,10 +401,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Hash for BitBox<T, O>
+impl<T, O, A> Hash for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn hash<H>(&self, state: &mut H)
@@ -368,23 +414,26 @@ where
 	}
 }
 
-unsafe impl<T, O> Send for BitBox<T, O>
+unsafe impl<T, O, A> Send for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Send,
 {
 }
 
-unsafe impl<T, O> Sync for BitBox<T, O>
+unsafe impl<T, O, A> Sync for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Sync,
 {
 }
 
-impl<T, O> Unpin for BitBox<T, O>
+impl<T, O, A> Unpin for BitBox<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Unpin,
 {
 }
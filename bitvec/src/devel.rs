@@ -22,11 +22,25 @@ macro_rules! easy_fmt {
 			}
 		}
 	)+ };
+	($(impl $fmt:ident)+ for $this:ident<A>) => { $(
+		impl<A, O> core::fmt::$fmt for $this<A, O>
+		where
+			O: $crate::order::BitOrder,
+			A: $crate::view::BitViewSized,
+		{
+			#[inline]
+			#[cfg(not(tarpaulin_include))]
+			fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+				core::fmt::$fmt::fmt(self.as_bitslice(), fmt)
+			}
+		}
+	)+ };
 	($(impl $fmt:ident)+ for $this:ident) => { $(
-		impl<T, O> core::fmt::$fmt for $this<T, O>
+		impl<T, O, A> core::fmt::$fmt for $this<T, O, A>
 		where
 			O: $crate::order::BitOrder,
 			T: $crate::store::BitStore,
+			A: allocator_api2::alloc::Allocator,
 		{
 			#[inline]
 			#[cfg(not(tarpaulin_include))]
@@ -10,6 +10,7 @@ use tap::Pipe;
 use wyz::comu::{
 	Const,
 	Mut,
+	Mutability,
 };
 
 use crate::{
@@ -35,9 +36,17 @@ use crate::{
 	vec::BitVec,
 };
 
+mod cursor;
 mod io;
 mod tests;
 
+#[cfg(feature = "alloc")]
+pub use self::cursor::BitWriter;
+pub use self::cursor::{
+	BitReadError,
+	BitReader,
+};
+
 #[doc = include_str!("../doc/field/BitField.md")]
 pub trait BitField {
 	#[inline]
@@ -107,15 +116,279 @@ pub trait BitField {
 	#[doc = include_str!("../doc/field/BitField_store_be.md")]
 	fn store_be<I>(&mut self, value: I)
 	where I: Integral;
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_load_into_bytes_le.md")]
+	fn load_into_bytes_le(&self, out: &mut [u8]) {
+		let bytes = self.load_le::<u128>().to_le_bytes();
+		let (kept, lost) = bytes.split_at(out.len().min(bytes.len()));
+		check_provided_load(out.len(), lost);
+		let (head, rest) = out.split_at_mut(kept.len());
+		head.copy_from_slice(kept);
+		rest.fill(0);
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_load_into_bytes_be.md")]
+	fn load_into_bytes_be(&self, out: &mut [u8]) {
+		let bytes = self.load_be::<u128>().to_be_bytes();
+		let (lost, kept) =
+			bytes.split_at(bytes.len() - out.len().min(bytes.len()));
+		check_provided_load(out.len(), lost);
+		let (rest, tail) = out.split_at_mut(out.len() - kept.len());
+		tail.copy_from_slice(kept);
+		rest.fill(0);
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_store_from_bytes_le.md")]
+	fn store_from_bytes_le(&mut self, src: &[u8]) {
+		check_provided_store(src.len());
+		let mut bytes = [0; mem::size_of::<u128>()];
+		for (slot, byte) in bytes.iter_mut().zip(src) {
+			*slot = *byte;
+		}
+		self.store_le(u128::from_le_bytes(bytes));
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_store_from_bytes_be.md")]
+	fn store_from_bytes_be(&mut self, src: &[u8]) {
+		check_provided_store(src.len());
+		let mut bytes = [0; mem::size_of::<u128>()];
+		for (slot, byte) in bytes.iter_mut().rev().zip(src.iter().rev()) {
+			*slot = *byte;
+		}
+		self.store_be(u128::from_be_bytes(bytes));
+	}
 }
 
-#[doc = include_str!("../doc/field/BitField_Lsb0.md")]
-impl<T> BitField for BitSlice<T, Lsb0>
-where T: BitStore
+#[doc = include_str!("../doc/field/impl_BitSlice.md")]
+impl<T, O> BitField for BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
 {
 	#[inline]
 	#[doc = include_str!("../doc/field/BitField_Lsb0_load_le.md")]
+	#[doc = include_str!("../doc/field/BitField_Msb0_load_le.md")]
 	fn load_le<I>(&self) -> I
+	where I: Integral {
+		if let Some(this) = self.coerce::<T, Lsb0>() {
+			this.sp_load_le()
+		}
+		else if let Some(this) = self.coerce::<T, Msb0>() {
+			this.sp_load_le()
+		}
+		else {
+			self.generic_load_le()
+		}
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_load_be.md")]
+	#[doc = include_str!("../doc/field/BitField_Msb0_load_be.md")]
+	fn load_be<I>(&self) -> I
+	where I: Integral {
+		if let Some(this) = self.coerce::<T, Lsb0>() {
+			this.sp_load_be()
+		}
+		else if let Some(this) = self.coerce::<T, Msb0>() {
+			this.sp_load_be()
+		}
+		else {
+			self.generic_load_be()
+		}
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_store_le.md")]
+	#[doc = include_str!("../doc/field/BitField_Msb0_store_le.md")]
+	fn store_le<I>(&mut self, value: I)
+	where I: Integral {
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			this.sp_store_le(value)
+		}
+		else if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			this.sp_store_le(value)
+		}
+		else {
+			self.generic_store_le(value)
+		}
+	}
+
+	#[inline]
+	#[doc = include_str!("../doc/field/BitField_Lsb0_store_be.md")]
+	#[doc = include_str!("../doc/field/BitField_Msb0_store_be.md")]
+	fn store_be<I>(&mut self, value: I)
+	where I: Integral {
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			this.sp_store_be(value)
+		}
+		else if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			this.sp_store_be(value)
+		}
+		else {
+			self.generic_store_be(value)
+		}
+	}
+
+	#[inline]
+	fn load_into_bytes_le(&self, out: &mut [u8]) {
+		load_bytes(self, out.iter_mut(), false);
+	}
+
+	#[inline]
+	fn load_into_bytes_be(&self, out: &mut [u8]) {
+		load_bytes(self, out.iter_mut().rev(), true);
+	}
+
+	#[inline]
+	fn store_from_bytes_le(&mut self, src: &[u8]) {
+		store_bytes(self, src.iter().copied(), false);
+	}
+
+	#[inline]
+	fn store_from_bytes_be(&mut self, src: &[u8]) {
+		store_bytes(self, src.iter().rev().copied(), true);
+	}
+}
+
+#[doc = include_str!("../doc/field/BitField_generic.md")]
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Loads an integer with little-endian element ordering, using only the
+	/// `BitOrder` masks to find the live bits of each element.
+	pub(crate) fn generic_load_le<I>(&self) -> I
+	where I: Integral {
+		let len = self.len();
+		check::<I>("load", len);
+
+		match self.domain() {
+			| Domain::Enclave(elem) => resize(segment(&elem).0),
+			| Domain::Region { head, body, tail } => {
+				let mut accum = I::ZERO;
+
+				if let Some(elem) = tail {
+					accum = resize(segment(&elem).0);
+				}
+
+				for elem in body.iter().rev().map(BitStore::load_value) {
+					maybe_shift_left(&mut accum, bits_of::<T>());
+					accum |= resize::<T::Mem, I>(elem);
+				}
+
+				if let Some(elem) = head {
+					let (value, width) = segment(&elem);
+					maybe_shift_left(&mut accum, width);
+					accum |= resize::<T::Mem, I>(value);
+				}
+
+				accum
+			},
+		}
+		.pipe(|elem| sign(elem, len))
+	}
+
+	/// Loads an integer with big-endian element ordering, using only the
+	/// `BitOrder` masks to find the live bits of each element.
+	pub(crate) fn generic_load_be<I>(&self) -> I
+	where I: Integral {
+		let len = self.len();
+		check::<I>("load", len);
+
+		match self.domain() {
+			| Domain::Enclave(elem) => resize(segment(&elem).0),
+			| Domain::Region { head, body, tail } => {
+				let mut accum = I::ZERO;
+
+				if let Some(elem) = head {
+					accum = resize(segment(&elem).0);
+				}
+
+				for elem in body.iter().map(BitStore::load_value) {
+					maybe_shift_left(&mut accum, bits_of::<T>());
+					accum |= resize::<T::Mem, I>(elem);
+				}
+
+				if let Some(elem) = tail {
+					let (value, width) = segment(&elem);
+					maybe_shift_left(&mut accum, width);
+					accum |= resize::<T::Mem, I>(value);
+				}
+
+				accum
+			},
+		}
+		.pipe(|elem| sign(elem, len))
+	}
+
+	/// Stores an integer with little-endian element ordering, using only the
+	/// `BitOrder` masks to find the live bits of each element.
+	pub(crate) fn generic_store_le<I>(&mut self, mut value: I)
+	where I: Integral {
+		check::<I>("store", self.len());
+
+		match self.domain_mut() {
+			| Domain::Enclave(elem) => set_segment(elem, resize(value)),
+			| Domain::Region { head, body, tail } => {
+				if let Some(elem) = head {
+					let width = elem.mask().into_inner().count_ones() as usize;
+					set_segment(elem, resize(value));
+					maybe_shift_right(&mut value, width);
+				}
+
+				for elem in body.iter_mut() {
+					elem.store_value(resize(value));
+					maybe_shift_right(&mut value, bits_of::<T>());
+				}
+
+				if let Some(elem) = tail {
+					set_segment(elem, resize(value));
+				}
+			},
+		}
+	}
+
+	/// Stores an integer with big-endian element ordering, using only the
+	/// `BitOrder` masks to find the live bits of each element.
+	pub(crate) fn generic_store_be<I>(&mut self, mut value: I)
+	where I: Integral {
+		check::<I>("store", self.len());
+
+		match self.domain_mut() {
+			| Domain::Enclave(elem) => set_segment(elem, resize(value)),
+			| Domain::Region { head, body, tail } => {
+				if let Some(elem) = tail {
+					let width = elem.mask().into_inner().count_ones() as usize;
+					set_segment(elem, resize(value));
+					maybe_shift_right(&mut value, width);
+				}
+
+				for elem in body.iter_mut().rev() {
+					elem.store_value(resize(value));
+					maybe_shift_right(&mut value, bits_of::<T>());
+				}
+
+				if let Some(elem) = head {
+					set_segment(elem, resize(value));
+				}
+			},
+		}
+	}
+}
+
+/// `BitField` accelerated for `Lsb0`, whose live bits in each element are a
+/// contiguous run that ascends in significance.
+impl<T> BitSlice<T, Lsb0>
+where T: BitStore
+{
+	/// Loads an integer with little-endian element ordering.
+	#[inline]
+	fn sp_load_le<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
@@ -150,9 +423,9 @@ where T: BitStore
 		.pipe(|elem| sign(elem, len))
 	}
 
+	/// Loads an integer with big-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_load_be.md")]
-	fn load_be<I>(&self) -> I
+	fn sp_load_be<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
@@ -183,9 +456,9 @@ where T: BitStore
 		.pipe(|elem| sign(elem, len))
 	}
 
+	/// Stores an integer with little-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_store_le.md")]
-	fn store_le<I>(&mut self, mut value: I)
+	fn sp_store_le<I>(&mut self, mut value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
@@ -214,9 +487,9 @@ where T: BitStore
 		}
 	}
 
+	/// Stores an integer with big-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Lsb0_store_be.md")]
-	fn store_be<I>(&mut self, mut value: I)
+	fn sp_store_be<I>(&mut self, mut value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
@@ -246,13 +519,14 @@ where T: BitStore
 	}
 }
 
-#[doc = include_str!("../doc/field/BitField_Msb0.md")]
-impl<T> BitField for BitSlice<T, Msb0>
+/// `BitField` accelerated for `Msb0`, whose live bits in each element are a
+/// contiguous run that descends in significance.
+impl<T> BitSlice<T, Msb0>
 where T: BitStore
 {
+	/// Loads an integer with little-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Msb0_load_le.md")]
-	fn load_le<I>(&self) -> I
+	fn sp_load_le<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
@@ -288,9 +562,9 @@ where T: BitStore
 		.pipe(|elem| sign(elem, len))
 	}
 
+	/// Loads an integer with big-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Msb0_load_be.md")]
-	fn load_be<I>(&self) -> I
+	fn sp_load_be<I>(&self) -> I
 	where I: Integral {
 		let len = self.len();
 		check::<I>("load", len);
@@ -324,9 +598,9 @@ where T: BitStore
 		.pipe(|elem| sign(elem, len))
 	}
 
+	/// Stores an integer with little-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Msb0_store_le.md")]
-	fn store_le<I>(&mut self, mut value: I)
+	fn sp_store_le<I>(&mut self, mut value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
@@ -356,9 +630,9 @@ where T: BitStore
 		}
 	}
 
+	/// Stores an integer with big-endian element ordering.
 	#[inline]
-	#[doc = include_str!("../doc/field/BitField_Msb0_store_be.md")]
-	fn store_be<I>(&mut self, mut value: I)
+	fn sp_store_be<I>(&mut self, mut value: I)
 	where I: Integral {
 		check::<I>("store", self.len());
 
@@ -438,6 +712,26 @@ where
 			maybe_shift_right(&mut value, bits_of::<A::Store>());
 		}
 	}
+
+	#[inline(always)]
+	fn load_into_bytes_le(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_le(out)
+	}
+
+	#[inline(always)]
+	fn load_into_bytes_be(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_be(out)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_le(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_le(src)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_be(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_be(src)
+	}
 }
 
 #[cfg(feature = "alloc")]
@@ -471,6 +765,26 @@ where
 	where I: Integral {
 		self.as_mut_bitslice().store_be(value)
 	}
+
+	#[inline(always)]
+	fn load_into_bytes_le(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_le(out)
+	}
+
+	#[inline(always)]
+	fn load_into_bytes_be(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_be(out)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_le(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_le(src)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_be(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_be(src)
+	}
 }
 
 #[cfg(feature = "alloc")]
@@ -504,6 +818,26 @@ where
 	where I: Integral {
 		self.as_mut_bitslice().store_be(value)
 	}
+
+	#[inline(always)]
+	fn load_into_bytes_le(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_le(out)
+	}
+
+	#[inline(always)]
+	fn load_into_bytes_be(&self, out: &mut [u8]) {
+		self.as_bitslice().load_into_bytes_be(out)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_le(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_le(src)
+	}
+
+	#[inline(always)]
+	fn store_from_bytes_be(&mut self, src: &[u8]) {
+		self.as_mut_bitslice().store_from_bytes_be(src)
+	}
 }
 
 /** Asserts that a bit-slice is not longer than a memory element.
@@ -532,6 +866,297 @@ where I: Integral {
 	);
 }
 
+/** Asserts that a bit-slice fits in a byte buffer.
+
+## Parameters
+
+- `action`: the verb being performed. One of `"load"` or `"store"`.
+- `len`: the length of the bit-slice under test.
+- `bytes`: the length of the byte buffer.
+
+## Panics
+
+This panics if `len` is not in `1 ..= bytes * 8`.
+**/
+fn check_bytes(action: &'static str, len: usize, bytes: usize) {
+	assert!(
+		(1 ..= bytes.saturating_mul(bits_of::<u8>())).contains(&len),
+		"cannot {} {} bytes from a {}-bit region",
+		action,
+		bytes,
+		len,
+	);
+}
+
+/// Asserts that the provided byte-buffer loads can fill `out` without losing
+/// any bytes of the loaded `u128`.
+///
+/// The provided implementations cannot see the length of the region, so a
+/// region that is wider than `out` is only detected when its value is.
+fn check_provided_load(bytes: usize, lost: &[u8]) {
+	assert!(bytes > 0, "cannot load into an empty byte buffer");
+	assert!(
+		lost.iter().all(|&byte| byte == 0),
+		"cannot load a value wider than {} bytes",
+		bytes,
+	);
+}
+
+/// Asserts that the provided byte-buffer stores have a value to store.
+fn check_provided_store(bytes: usize) {
+	assert!(bytes > 0, "cannot store from an empty byte buffer");
+}
+
+#[doc = include_str!("../doc/field/load_bytes.md")]
+fn load_bytes<'a, T, O, I>(bits: &BitSlice<T, O>, out: I, big: bool)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: ExactSizeIterator<Item = &'a mut u8>,
+{
+	check_bytes("load", bits.len(), out.len());
+	let mut sink = ByteSink::new(out);
+
+	match bits.domain() {
+		| Domain::Enclave(elem) => sink.push_partial(&elem),
+		| Domain::Region { head, body, tail } => {
+			let (first, last) = if big { (tail, head) } else { (head, tail) };
+			if let Some(elem) = first {
+				sink.push_partial(&elem);
+			}
+			let body = body.iter().map(BitStore::load_value);
+			if big {
+				body.rev().for_each(|elem| sink.push(elem, bits_of::<T>()));
+			}
+			else {
+				body.for_each(|elem| sink.push(elem, bits_of::<T>()));
+			}
+			if let Some(elem) = last {
+				sink.push_partial(&elem);
+			}
+		},
+	}
+
+	sink.finish();
+}
+
+#[doc = include_str!("../doc/field/store_bytes.md")]
+fn store_bytes<T, O, I>(bits: &mut BitSlice<T, O>, src: I, big: bool)
+where
+	T: BitStore,
+	O: BitOrder,
+	I: ExactSizeIterator<Item = u8>,
+{
+	check_bytes("store", bits.len(), src.len());
+	let mut source = ByteSource::new(src);
+
+	match bits.domain_mut() {
+		| Domain::Enclave(elem) => source.pull_partial(elem),
+		| Domain::Region { head, body, tail } => {
+			let (first, last) = if big { (tail, head) } else { (head, tail) };
+			if let Some(elem) = first {
+				source.pull_partial(elem);
+			}
+			let width = bits_of::<T>();
+			if big {
+				for elem in body.iter_mut().rev() {
+					elem.store_value(source.pull(width));
+				}
+			}
+			else {
+				for elem in body.iter_mut() {
+					elem.store_value(source.pull(width));
+				}
+			}
+			if let Some(elem) = last {
+				source.pull_partial(elem);
+			}
+		},
+	}
+}
+
+/// Tests whether an ordering is known to place the live bits of any partial
+/// element in one contiguous run.
+fn is_contiguous<O>() -> bool
+where O: BitOrder {
+	dvl::match_order::<O, Lsb0>() || dvl::match_order::<O, Msb0>()
+}
+
+#[doc = include_str!("../doc/field/segment.md")]
+fn segment<M, T, O>(elem: &PartialElement<M, T, O>) -> (T::Mem, usize)
+where
+	M: Mutability,
+	T: BitStore,
+	O: BitOrder,
+{
+	let mask = elem.mask().into_inner();
+	let width = mask.count_ones() as usize;
+	let value = if is_contiguous::<O>() {
+		elem.load_value() >> mask.trailing_zeros()
+	}
+	else {
+		gather(elem.load_value(), mask)
+	};
+	(value, width)
+}
+
+/// Writes an integer segment into a partial element, spreading its low bits
+/// across the element’s live bits in order of ascending position.
+///
+/// This is the inverse of [`segment`].
+fn set_segment<T, O>(mut elem: PartialElement<Mut, T, O>, value: T::Mem)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mask = elem.mask().into_inner();
+	if is_contiguous::<O>() {
+		elem.store_value(value << mask.trailing_zeros());
+	}
+	else {
+		elem.store_value(scatter(value, mask));
+	}
+}
+
+/// Collects the bits of `value` selected by `mask` into the low bits of the
+/// result, preserving their order.
+fn gather<R>(value: R, mut mask: R) -> R
+where R: Integral {
+	let mut out = R::ZERO;
+	let mut bit = R::ONE;
+	while mask != R::ZERO {
+		if value & mask & !(mask - R::ONE) != R::ZERO {
+			out |= bit;
+		}
+		bit <<= 1;
+		mask &= mask - R::ONE;
+	}
+	out
+}
+
+/// Distributes the low bits of `value` into the bits selected by `mask`,
+/// preserving their order.
+///
+/// This is the inverse of [`gather`].
+fn scatter<R>(value: R, mut mask: R) -> R
+where R: Integral {
+	let mut out = R::ZERO;
+	let mut bit = R::ONE;
+	while mask != R::ZERO {
+		if value & bit != R::ZERO {
+			out |= mask & !(mask - R::ONE);
+		}
+		bit <<= 1;
+		mask &= mask - R::ONE;
+	}
+	out
+}
+
+/// Packs segments of a bit-field into bytes, in ascending significance.
+struct ByteSink<I> {
+	/// The bytes of the destination buffer, least significant first.
+	bytes:  I,
+	/// Bits that have been pushed but not yet written out.
+	accum:  u128,
+	/// The number of live bits in `accum`.
+	filled: usize,
+}
+
+impl<'a, I> ByteSink<I>
+where I: Iterator<Item = &'a mut u8>
+{
+	/// Starts writing into a byte sequence.
+	fn new(bytes: I) -> Self {
+		Self {
+			bytes,
+			accum: 0,
+			filled: 0,
+		}
+	}
+
+	/// Appends the low `width` bits of `value` above all previous segments.
+	fn push<R>(&mut self, value: R, width: usize)
+	where R: Integral {
+		self.accum |= resize::<R, u128>(value) << self.filled;
+		self.filled += width;
+		while self.filled >= bits_of::<u8>() {
+			if let Some(byte) = self.bytes.next() {
+				*byte = self.accum as u8;
+			}
+			self.accum >>= bits_of::<u8>();
+			self.filled -= bits_of::<u8>();
+		}
+	}
+
+	/// Appends the live bits of a partial element.
+	fn push_partial<M, T, O>(&mut self, elem: &PartialElement<M, T, O>)
+	where
+		M: Mutability,
+		T: BitStore,
+		O: BitOrder,
+	{
+		let (value, width) = segment(elem);
+		self.push(value, width);
+	}
+
+	/// Writes out any incomplete byte, and zeroes the rest of the buffer.
+	fn finish(self) {
+		let mut accum = self.accum;
+		for byte in self.bytes {
+			*byte = accum as u8;
+			accum = 0;
+		}
+	}
+}
+
+/// Unpacks segments of a bit-field from bytes, in ascending significance.
+struct ByteSource<I> {
+	/// The bytes of the source buffer, least significant first.
+	bytes:  I,
+	/// Bits that have been read in but not yet taken.
+	accum:  u128,
+	/// The number of live bits in `accum`.
+	filled: usize,
+}
+
+impl<I> ByteSource<I>
+where I: Iterator<Item = u8>
+{
+	/// Starts reading from a byte sequence.
+	fn new(bytes: I) -> Self {
+		Self {
+			bytes,
+			accum: 0,
+			filled: 0,
+		}
+	}
+
+	/// Takes the next `width` bits, zero-extending past the end of the source.
+	fn pull<R>(&mut self, width: usize) -> R
+	where R: Integral {
+		while self.filled < width {
+			let byte = self.bytes.next().unwrap_or(0);
+			self.accum |= (byte as u128) << self.filled;
+			self.filled += bits_of::<u8>();
+		}
+		let out = resize::<u128, R>(self.accum & !(!0u128 << width));
+		self.accum >>= width;
+		self.filled -= width;
+		out
+	}
+
+	/// Fills the live bits of a partial element.
+	fn pull_partial<T, O>(&mut self, elem: PartialElement<Mut, T, O>)
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let width = elem.mask().into_inner().count_ones() as usize;
+		let value = self.pull::<T::Mem>(width);
+		set_segment(elem, value);
+	}
+}
+
 /// Shifts a value to the left, if it can support the shift amount.
 fn maybe_shift_left<T: Integral>(elem: &mut T, shamt: usize) {
 	if bits_of::<T>() > shamt {
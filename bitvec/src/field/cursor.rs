@@ -0,0 +1,840 @@
+#![doc = include_str!("../../doc/field/cursor.md")]
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+use funty::Integral;
+
+use super::BitField;
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+/// The number of bits in each LEB128 group.
+const LEB128_BITS: usize = bits_of::<u8>();
+
+#[doc = include_str!("../../doc/field/cursor/BitReader.md")]
+#[derive(Clone, Debug)]
+pub struct BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice being read.
+	bits: &'a BitSlice<T, O>,
+	/// The number of bits that have been consumed from the front of `bits`.
+	pos:  usize,
+}
+
+/// Cursor management.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a reader at the front of a bit-slice.
+	#[inline]
+	pub fn new(bits: &'a BitSlice<T, O>) -> Self {
+		Self { bits, pos: 0 }
+	}
+
+	/// Gets the number of bits that have been consumed.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Gets the number of bits that have not yet been consumed.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.bits.len() - self.pos
+	}
+
+	/// Tests if every bit has been consumed.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.remaining() == 0
+	}
+
+	/// Views the whole bit-slice being read, including consumed bits.
+	#[inline]
+	pub fn get_ref(&self) -> &'a BitSlice<T, O> {
+		self.bits
+	}
+
+	/// Views the bits that have not yet been consumed.
+	#[inline]
+	pub fn unread(&self) -> &'a BitSlice<T, O> {
+		unsafe { self.bits.get_unchecked(self.pos ..) }
+	}
+
+	/// Tests if the cursor is at a multiple of eight bits from the front of
+	/// the bit-slice.
+	#[inline]
+	pub fn is_aligned(&self) -> bool {
+		self.pos % bits_of::<u8>() == 0
+	}
+
+	/// Views the next `width` bits without consuming them.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let bits = bits![0, 1, 1];
+	/// let reader = BitReader::new(bits);
+	/// assert_eq!(reader.peek(2).unwrap(), bits![0, 1]);
+	/// assert_eq!(reader.position(), 0);
+	/// assert!(reader.peek(4).is_err());
+	/// ```
+	#[inline]
+	pub fn peek(
+		&self,
+		width: usize,
+	) -> Result<&'a BitSlice<T, O>, BitReadError> {
+		self.unread()
+			.get(.. width)
+			.ok_or_else(|| self.unexpected_end(width))
+	}
+
+	/// Consumes the next `width` bits, and views them.
+	#[inline]
+	pub fn take(
+		&mut self,
+		width: usize,
+	) -> Result<&'a BitSlice<T, O>, BitReadError> {
+		let out = self.peek(width)?;
+		self.pos += width;
+		Ok(out)
+	}
+
+	/// Consumes the next `width` bits without viewing them.
+	#[inline]
+	pub fn skip(&mut self, width: usize) -> Result<(), BitReadError> {
+		self.take(width).map(drop)
+	}
+
+	/// Skips forward to the next multiple of eight bits from the front of the
+	/// bit-slice.
+	///
+	/// This has no effect if the cursor is already aligned.
+	#[inline]
+	pub fn align(&mut self) -> Result<(), BitReadError> {
+		let byte = bits_of::<u8>();
+		self.skip((byte - self.pos % byte) % byte)
+	}
+
+	/// Views the next bit without consuming it.
+	#[inline]
+	pub fn peek_bool(&self) -> Result<bool, BitReadError> {
+		self.unread()
+			.first()
+			.map(|bit| *bit)
+			.ok_or_else(|| self.unexpected_end(1))
+	}
+
+	/// Consumes the next bit.
+	#[inline]
+	pub fn read_bool(&mut self) -> Result<bool, BitReadError> {
+		let out = self.peek_bool()?;
+		self.pos += 1;
+		Ok(out)
+	}
+
+	/// Produces the error for a read of `width` bits that cannot complete.
+	#[inline]
+	fn unexpected_end(&self, width: usize) -> BitReadError {
+		BitReadError::UnexpectedEnd {
+			position:  self.pos,
+			wanted:    width,
+			available: self.remaining(),
+		}
+	}
+}
+
+/// Variable-length codes.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Reads a unary code: a run of `0` bits terminated by a `1` bit.
+	///
+	/// ## Returns
+	///
+	/// The number of `0` bits before the terminator. Both the run and the
+	/// terminator are consumed.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let bits = bits![0, 0, 0, 1, 1, 0];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_unary().unwrap(), 3);
+	/// assert_eq!(reader.read_unary().unwrap(), 0);
+	/// assert!(reader.read_unary().is_err());
+	/// ```
+	#[inline]
+	pub fn read_unary(&mut self) -> Result<usize, BitReadError> {
+		let unread = self.unread();
+		let zeros = unread
+			.first_one()
+			.ok_or_else(|| self.unexpected_end(unread.len() + 1))?;
+		self.pos += zeros + 1;
+		Ok(zeros)
+	}
+
+	/// Reads an unsigned, order-0, Exp-Golomb code.
+	///
+	/// The code is a run of `n` `0` bits, followed by an `n + 1`-bit number
+	/// whose first bit is its most significant, and always `1`. The decoded
+	/// value is that number, minus one.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let bits = bits![1, 0, 1, 0, 0, 0, 1, 0, 0];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_exp_golomb().unwrap(), 0);
+	/// assert_eq!(reader.read_exp_golomb().unwrap(), 1);
+	/// assert_eq!(reader.read_exp_golomb().unwrap(), 3);
+	/// ```
+	#[inline]
+	pub fn read_exp_golomb(&mut self) -> Result<u64, BitReadError> {
+		let start = self.pos;
+		let value = self.read_exp_golomb_wide()?;
+		u64::try_from(value).map_err(|_| {
+			self.pos = start;
+			BitReadError::Overflow { position: start }
+		})
+	}
+
+	/// Reads a signed, order-0, Exp-Golomb code.
+	///
+	/// The code is read as an unsigned Exp-Golomb code `k`, which maps to the
+	/// sequence `0, 1, -1, 2, -2, …`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1, 1];
+	/// let mut reader = BitReader::new(bits);
+	/// assert_eq!(reader.read_signed_exp_golomb().unwrap(), 1);
+	/// assert_eq!(reader.read_signed_exp_golomb().unwrap(), -1);
+	/// ```
+	#[inline]
+	pub fn read_signed_exp_golomb(&mut self) -> Result<i64, BitReadError> {
+		let start = self.pos;
+		let code = self.read_exp_golomb_wide()?;
+		let magnitude = ((code + 1) / 2) as i128;
+		let value = if code % 2 == 1 { magnitude } else { -magnitude };
+		i64::try_from(value).map_err(|_| {
+			self.pos = start;
+			BitReadError::Overflow { position: start }
+		})
+	}
+
+	/// Reads an Exp-Golomb code into an integer that can hold any code with a
+	/// prefix of up to 64 bits.
+	#[inline]
+	fn read_exp_golomb_wide(&mut self) -> Result<u128, BitReadError> {
+		let start = self.pos;
+		let unread = self.unread();
+		let zeros = match unread.first_one() {
+			| Some(zeros) => zeros,
+			| None => return Err(self.unexpected_end(unread.len() + 1)),
+		};
+		if zeros > bits_of::<u64>() {
+			return Err(BitReadError::Overflow { position: start });
+		}
+		let number = self.peek(2 * zeros + 1)?;
+		self.pos += 2 * zeros + 1;
+		Ok(number[zeros ..]
+			.iter()
+			.by_vals()
+			.fold(0u128, |acc, bit| (acc << 1) | bit as u128)
+			- 1)
+	}
+}
+
+/// Fixed-width fields.
+impl<'a, T, O> BitReader<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Reads a `width`-bit integer, using the target’s endianness.
+	///
+	/// This uses [`BitField::load`] to read the next `width` bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// [`BitField::load`]: crate::field::BitField::load
+	#[inline]
+	pub fn read_bits<I>(&mut self, width: usize) -> Result<I, BitReadError>
+	where I: Integral {
+		self.read_with(width, BitField::load::<I>)
+	}
+
+	/// Reads a `width`-bit integer, using little-endian element order.
+	///
+	/// This uses [`BitField::load_le`] to read the next `width` bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0x34u8, 0x12];
+	/// let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(reader.read_bits_le::<u16>(12).unwrap(), 0x234);
+	/// assert_eq!(reader.read_bits_le::<u8>(4).unwrap(), 1);
+	/// ```
+	///
+	/// [`BitField::load_le`]: crate::field::BitField::load_le
+	#[inline]
+	pub fn read_bits_le<I>(&mut self, width: usize) -> Result<I, BitReadError>
+	where I: Integral {
+		self.read_with(width, BitField::load_le::<I>)
+	}
+
+	/// Reads a `width`-bit integer, using big-endian element order.
+	///
+	/// This uses [`BitField::load_be`] to read the next `width` bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0x12u8, 0x34];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	/// assert_eq!(reader.read_bits_be::<u8>(4).unwrap(), 1);
+	/// assert_eq!(reader.read_bits_be::<u16>(12).unwrap(), 0x234);
+	/// ```
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn read_bits_be<I>(&mut self, width: usize) -> Result<I, BitReadError>
+	where I: Integral {
+		self.read_with(width, BitField::load_be::<I>)
+	}
+
+	/// Reads an unsigned LEB128 varint.
+	///
+	/// Each group is a byte, read with [`BitField::load_be`]. The low seven
+	/// bits of each byte carry the value, least significant group first, and
+	/// the high bit is set on every byte except the last.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0xE5u8, 0x8E, 0x26];
+	/// let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	/// assert_eq!(reader.read_leb128().unwrap(), 624_485);
+	/// ```
+	///
+	/// [`BitField::load_be`]: crate::field::BitField::load_be
+	#[inline]
+	pub fn read_leb128(&mut self) -> Result<u64, BitReadError> {
+		let start = self.pos;
+		let (value, shift, _) = self.read_leb128_groups()?;
+		//  The final group must not carry bits above the top of a `u64`.
+		if shift > bits_of::<u64>() && value >> bits_of::<u64>() != 0 {
+			self.pos = start;
+			return Err(BitReadError::Overflow { position: start });
+		}
+		Ok(value as u64)
+	}
+
+	/// Reads a signed LEB128 varint.
+	///
+	/// This is the same as [`.read_leb128()`], except that the value is
+	/// sign-extended from the highest bit of the final group.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitReader;
+	///
+	/// let data = [0xC0u8, 0xBB, 0x78];
+	/// let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(reader.read_sleb128().unwrap(), -123_456);
+	/// ```
+	///
+	/// [`.read_leb128()`]: Self::read_leb128
+	#[inline]
+	pub fn read_sleb128(&mut self) -> Result<i64, BitReadError> {
+		let start = self.pos;
+		let (value, shift, last) = self.read_leb128_groups()?;
+		let value = if shift < 128 && last & 0x40 != 0 {
+			(value | (!0u128 << shift)) as i128
+		}
+		else {
+			value as i128
+		};
+		i64::try_from(value).map_err(|_| {
+			self.pos = start;
+			BitReadError::Overflow { position: start }
+		})
+	}
+
+	/// Reads the groups of a LEB128 varint.
+	///
+	/// ## Returns
+	///
+	/// The concatenated seven-bit groups, the number of bits they occupy, and
+	/// the final byte.
+	#[inline]
+	fn read_leb128_groups(&mut self) -> Result<(u128, usize, u8), BitReadError> {
+		let start = self.pos;
+		let mut value = 0u128;
+		let mut shift = 0;
+		loop {
+			let byte = match self.read_bits_be::<u8>(LEB128_BITS) {
+				| Ok(byte) => byte,
+				| Err(err) => {
+					self.pos = start;
+					return Err(err);
+				},
+			};
+			//  Ten groups hold 70 bits, which is enough for any 64-bit value.
+			if shift >= 70 {
+				self.pos = start;
+				return Err(BitReadError::Overflow { position: start });
+			}
+			value |= ((byte & 0x7F) as u128) << shift;
+			shift += 7;
+			if byte & 0x80 == 0 {
+				return Ok((value, shift, byte));
+			}
+		}
+	}
+
+	/// Reads a field of `width` bits with a `BitField` loader.
+	#[inline]
+	fn read_with<I>(
+		&mut self,
+		width: usize,
+		load: impl FnOnce(&BitSlice<T, O>) -> I,
+	) -> Result<I, BitReadError>
+	where
+		I: Integral,
+	{
+		assert!(
+			width <= bits_of::<I>(),
+			"cannot read {} bits into a {}-bit integer",
+			width,
+			bits_of::<I>(),
+		);
+		let bits = self.take(width)?;
+		Ok(if width == 0 { I::ZERO } else { load(bits) })
+	}
+}
+
+#[cfg(feature = "alloc")]
+#[doc = include_str!("../../doc/field/cursor/BitWriter.md")]
+#[derive(Debug)]
+pub struct BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-vector being written.
+	bits: &'a mut BitVec<T, O>,
+}
+
+/// Cursor management.
+#[cfg(feature = "alloc")]
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a writer at the back of a bit-vector.
+	#[inline]
+	pub fn new(bits: &'a mut BitVec<T, O>) -> Self {
+		Self { bits }
+	}
+
+	/// Gets the number of bits in the bit-vector, which is where the next
+	/// write begins.
+	#[inline]
+	pub fn position(&self) -> usize {
+		self.bits.len()
+	}
+
+	/// Views the bit-vector being written.
+	#[inline]
+	pub fn get_ref(&self) -> &BitVec<T, O> {
+		self.bits
+	}
+
+	/// Tests if the bit-vector holds a multiple of eight bits.
+	#[inline]
+	pub fn is_aligned(&self) -> bool {
+		self.bits.len() % bits_of::<u8>() == 0
+	}
+
+	/// Pads the bit-vector with `0` bits up to the next multiple of eight bits.
+	///
+	/// This has no effect if the writer is already aligned.
+	#[inline]
+	pub fn align(&mut self) {
+		let byte = bits_of::<u8>();
+		let len = self.bits.len();
+		self.bits.resize(len + (byte - len % byte) % byte, false);
+	}
+
+	/// Writes a single bit.
+	#[inline]
+	pub fn write_bool(&mut self, bit: bool) {
+		self.bits.push(bit);
+	}
+
+	/// Writes the contents of a bit-slice.
+	#[inline]
+	pub fn write_bitslice<T2, O2>(&mut self, bits: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.bits.extend_from_bitslice(bits);
+	}
+}
+
+/// Variable-length codes.
+#[cfg(feature = "alloc")]
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Writes a unary code: `value` `0` bits, followed by a `1` bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// BitWriter::new(&mut bv).write_unary(3);
+	/// assert_eq!(bv, bits![0, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn write_unary(&mut self, value: usize) {
+		let len = self.bits.len();
+		self.bits.resize(len + value, false);
+		self.bits.push(true);
+	}
+
+	/// Writes an unsigned, order-0, Exp-Golomb code.
+	///
+	/// See [`BitReader::read_exp_golomb`] for the format.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// BitWriter::new(&mut bv).write_exp_golomb(3);
+	/// assert_eq!(bv, bits![0, 0, 1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn write_exp_golomb(&mut self, value: u64) {
+		self.write_exp_golomb_wide(value as u128);
+	}
+
+	/// Writes a signed, order-0, Exp-Golomb code.
+	///
+	/// See [`BitReader::read_signed_exp_golomb`] for the format.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = bitvec![];
+	/// BitWriter::new(&mut bv).write_signed_exp_golomb(-1);
+	/// assert_eq!(bv, bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn write_signed_exp_golomb(&mut self, value: i64) {
+		let value = value as i128;
+		let code = if value > 0 { 2 * value - 1 } else { -2 * value };
+		self.write_exp_golomb_wide(code as u128);
+	}
+
+	/// Writes an Exp-Golomb code for any value that needs a prefix of up to
+	/// 64 bits.
+	#[inline]
+	fn write_exp_golomb_wide(&mut self, value: u128) {
+		let number = value + 1;
+		let width = bits_of::<u128>() - number.leading_zeros() as usize;
+		let len = self.bits.len();
+		self.bits.resize(len + width - 1, false);
+		self.bits
+			.extend((0 .. width).rev().map(|shift| (number >> shift) & 1 == 1));
+	}
+}
+
+/// Fixed-width fields.
+#[cfg(feature = "alloc")]
+impl<'a, T, O> BitWriter<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	/// Writes the low `width` bits of an integer, using the target’s
+	/// endianness.
+	///
+	/// This uses [`BitField::store`] to write the new bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// [`BitField::store`]: crate::field::BitField::store
+	#[inline]
+	pub fn write_bits<I>(&mut self, value: I, width: usize)
+	where I: Integral {
+		self.write_with(value, width, BitField::store::<I>);
+	}
+
+	/// Writes the low `width` bits of an integer, using little-endian element
+	/// order.
+	///
+	/// This uses [`BitField::store_le`] to write the new bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Lsb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bits_le(0x234u16, 12);
+	/// writer.write_bits_le(1u8, 4);
+	/// assert_eq!(bv.as_raw_slice(), [0x34, 0x12]);
+	/// ```
+	///
+	/// [`BitField::store_le`]: crate::field::BitField::store_le
+	#[inline]
+	pub fn write_bits_le<I>(&mut self, value: I, width: usize)
+	where I: Integral {
+		self.write_with(value, width, BitField::store_le::<I>);
+	}
+
+	/// Writes the low `width` bits of an integer, using big-endian element
+	/// order.
+	///
+	/// This uses [`BitField::store_be`] to write the new bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `width` is greater than the bit width of `I`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// let mut writer = BitWriter::new(&mut bv);
+	/// writer.write_bits_be(1u8, 4);
+	/// writer.write_bits_be(0x234u16, 12);
+	/// assert_eq!(bv.as_raw_slice(), [0x12, 0x34]);
+	/// ```
+	///
+	/// [`BitField::store_be`]: crate::field::BitField::store_be
+	#[inline]
+	pub fn write_bits_be<I>(&mut self, value: I, width: usize)
+	where I: Integral {
+		self.write_with(value, width, BitField::store_be::<I>);
+	}
+
+	/// Writes an unsigned LEB128 varint.
+	///
+	/// See [`BitReader::read_leb128`] for the format.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Msb0>::new();
+	/// BitWriter::new(&mut bv).write_leb128(624_485);
+	/// assert_eq!(bv.as_raw_slice(), [0xE5, 0x8E, 0x26]);
+	/// ```
+	#[inline]
+	pub fn write_leb128(&mut self, mut value: u64) {
+		loop {
+			let group = (value & 0x7F) as u8;
+			value >>= 7;
+			if value == 0 {
+				self.write_bits_be(group, LEB128_BITS);
+				return;
+			}
+			self.write_bits_be(group | 0x80, LEB128_BITS);
+		}
+	}
+
+	/// Writes a signed LEB128 varint.
+	///
+	/// See [`BitReader::read_sleb128`] for the format.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::field::BitWriter;
+	///
+	/// let mut bv = BitVec::<u8, Lsb0>::new();
+	/// BitWriter::new(&mut bv).write_sleb128(-123_456);
+	/// assert_eq!(bv.as_raw_slice(), [0xC0, 0xBB, 0x78]);
+	/// ```
+	#[inline]
+	pub fn write_sleb128(&mut self, mut value: i64) {
+		loop {
+			let group = (value & 0x7F) as u8;
+			value >>= 7;
+			let done = (value == 0 && group & 0x40 == 0)
+				|| (value == -1 && group & 0x40 != 0);
+			if done {
+				self.write_bits_be(group, LEB128_BITS);
+				return;
+			}
+			self.write_bits_be(group | 0x80, LEB128_BITS);
+		}
+	}
+
+	/// Writes a field of `width` bits with a `BitField` storer.
+	#[inline]
+	fn write_with<I>(
+		&mut self,
+		value: I,
+		width: usize,
+		store: impl FnOnce(&mut BitSlice<T, O>, I),
+	) where
+		I: Integral,
+	{
+		assert!(
+			width <= bits_of::<I>(),
+			"cannot write {} bits from a {}-bit integer",
+			width,
+			bits_of::<I>(),
+		);
+		if width == 0 {
+			return;
+		}
+		let len = self.bits.len();
+		self.bits.resize(len + width, false);
+		store(unsafe { self.bits.get_unchecked_mut(len ..) }, value);
+	}
+}
+
+#[doc = include_str!("../../doc/field/cursor/BitReadError.md")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum BitReadError {
+	/// The bit-slice ended before the read could complete.
+	UnexpectedEnd {
+		/// The cursor position at the start of the read.
+		position:  usize,
+		/// The number of bits the read needed. For variable-length codes that
+		/// ran off the end, this is a lower bound.
+		wanted:    usize,
+		/// The number of bits that remained in the bit-slice.
+		available: usize,
+	},
+	/// A variable-length code held a value too large for its integer type.
+	Overflow {
+		/// The cursor position at the start of the read.
+		position: usize,
+	},
+}
+
+impl BitReadError {
+	/// Gets the cursor position at the start of the failed read.
+	#[inline]
+	pub fn position(&self) -> usize {
+		match *self {
+			| Self::UnexpectedEnd { position, .. } => position,
+			| Self::Overflow { position } => position,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for BitReadError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			| Self::UnexpectedEnd {
+				position,
+				wanted,
+				available,
+			} => write!(
+				fmt,
+				"read of {} bits at position {} ran past the end of the \
+				 bit-slice, which had {} bits remaining",
+				wanted, position, available,
+			),
+			| Self::Overflow { position } => write!(
+				fmt,
+				"variable-length code at position {} overflows its integer type",
+				position,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitReadError {}
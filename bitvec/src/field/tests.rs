@@ -5,7 +5,10 @@ use std::io;
 
 use rand::prelude::*;
 
-use crate::prelude::*;
+use crate::{
+	field::BitField,
+	prelude::*,
+};
 
 #[test]
 fn lsb0_u8_any_u5() {
@@ -257,6 +260,205 @@ fn msb0_u8_be_u24() {
 	);
 }
 
+/// Checks the byte-buffer transfers against the `u128` transfers, at every
+/// offset and width that both can express.
+fn bytes_agree<T, O>()
+where
+	T: BitStore,
+	O: BitOrder,
+	BitSlice<T, O>: BitField,
+{
+	let mut data = BitArray::<[T; 40], O>::ZERO;
+	for start in 0 .. 40 {
+		for len in [1, 7, 8, 9, 31, 64, 65, 100, 127, 128] {
+			let bits = &mut data[start .. start + len];
+			let value = random::<u128>();
+			let bytes = len.div_ceil(8);
+
+			bits.store_from_bytes_le(&value.to_le_bytes());
+			assert_eq!(bits.load_le::<u128>(), value & (!0u128 >> (128 - len)));
+			let mut out = [0u8; 16];
+			bits.load_into_bytes_le(&mut out[.. bytes]);
+			assert_eq!(out, bits.load_le::<u128>().to_le_bytes());
+
+			bits.store_from_bytes_be(&value.to_be_bytes());
+			assert_eq!(bits.load_be::<u128>(), value & (!0u128 >> (128 - len)));
+			let mut out = [0xA5u8; 16];
+			bits.load_into_bytes_be(&mut out);
+			assert_eq!(out, bits.load_be::<u128>().to_be_bytes());
+			bits.load_into_bytes_be(&mut out[16 - bytes ..]);
+			assert_eq!(out, bits.load_be::<u128>().to_be_bytes());
+
+			bits.store_le(value);
+			bits.load_into_bytes_le(&mut out);
+			assert_eq!(out, bits.load_le::<u128>().to_le_bytes());
+			bits.store_be(value);
+			bits.load_into_bytes_be(&mut out);
+			assert_eq!(out, bits.load_be::<u128>().to_be_bytes());
+		}
+	}
+}
+
+#[test]
+fn bytes() {
+	bytes_agree::<u8, Lsb0>();
+	bytes_agree::<u8, Msb0>();
+	bytes_agree::<u16, Lsb0>();
+	bytes_agree::<u16, Msb0>();
+	bytes_agree::<u32, Lsb0>();
+	bytes_agree::<u32, Msb0>();
+	bytes_agree::<usize, Lsb0>();
+	bytes_agree::<usize, Msb0>();
+}
+
+#[test]
+fn wide_bytes() {
+	let value: [u8; 40] = core::array::from_fn(|_| random());
+	let mut data = BitArray::<[u16; 24], Lsb0>::ZERO;
+	let mut out = [0u8; 40];
+
+	for start in [0, 3, 16, 29] {
+		let bits = &mut data[start .. start + 320];
+		bits.store_from_bytes_le(&value);
+		bits.load_into_bytes_le(&mut out);
+		assert_eq!(out, value);
+
+		//  In `Lsb0`, the low bytes of the value are at the front of the field.
+		assert_eq!(bits[.. 16].load_le::<u16>().to_le_bytes(), value[.. 2]);
+
+		bits.store_from_bytes_be(&value);
+		bits.load_into_bytes_be(&mut out);
+		assert_eq!(out, value);
+
+		//  Fields narrower than the buffer truncate when stored, and
+		//  zero-extend when loaded.
+		let bits = &mut bits[.. 317];
+		bits.store_from_bytes_le(&value);
+		bits.load_into_bytes_le(&mut out);
+		assert_eq!(out[.. 39], value[.. 39]);
+		assert_eq!(out[39], value[39] & 0x1F);
+	}
+}
+
+/// A foreign implementor, which only writes the required methods.
+struct Field<'a>(&'a mut BitSlice<u16, Msb0>);
+
+impl BitField for Field<'_> {
+	fn load_le<I>(&self) -> I
+	where I: funty::Integral {
+		self.0.load_le()
+	}
+
+	fn load_be<I>(&self) -> I
+	where I: funty::Integral {
+		self.0.load_be()
+	}
+
+	fn store_le<I>(&mut self, value: I)
+	where I: funty::Integral {
+		self.0.store_le(value)
+	}
+
+	fn store_be<I>(&mut self, value: I)
+	where I: funty::Integral {
+		self.0.store_be(value)
+	}
+}
+
+#[test]
+fn provided_bytes() {
+	let mut data = BitArray::<[u16; 10], Msb0>::ZERO;
+	for len in [1, 9, 16, 64, 100, 128] {
+		let value = random::<u128>();
+		let (mut provided, mut native) = ([0xA5u8; 20], [0x5Au8; 20]);
+
+		Field(&mut data[3 .. 3 + len]).store_from_bytes_le(&value.to_le_bytes());
+		assert_eq!(
+			data[3 .. 3 + len].load_le::<u128>(),
+			value & (!0u128 >> (128 - len)),
+		);
+		Field(&mut data[3 .. 3 + len]).load_into_bytes_le(&mut provided);
+		data[3 .. 3 + len].load_into_bytes_le(&mut native);
+		assert_eq!(provided, native);
+
+		Field(&mut data[3 .. 3 + len]).store_from_bytes_be(&value.to_be_bytes());
+		assert_eq!(
+			data[3 .. 3 + len].load_be::<u128>(),
+			value & (!0u128 >> (128 - len)),
+		);
+		Field(&mut data[3 .. 3 + len]).load_into_bytes_be(&mut provided);
+		data[3 .. 3 + len].load_into_bytes_be(&mut native);
+		assert_eq!(provided, native);
+	}
+}
+
+#[test]
+fn provided_bytes_narrow() {
+	let mut data = [0u16; 2];
+	let bits = &mut data.view_bits_mut::<Msb0>()[4 .. 28];
+	bits.store_le(0x1234u32);
+
+	//  The value fits in a buffer narrower than the region.
+	let mut out = [0xFFu8; 2];
+	Field(bits).load_into_bytes_le(&mut out);
+	assert_eq!(out, [0x34, 0x12]);
+	bits.store_be(0x1234u32);
+	Field(bits).load_into_bytes_be(&mut out);
+	assert_eq!(out, [0x12, 0x34]);
+
+	//  The provided stores zero-extend a buffer narrower than the region.
+	Field(bits).store_from_bytes_be(&[0xAB]);
+	assert_eq!(bits.load_be::<u32>(), 0xAB);
+}
+
+#[test]
+#[should_panic = "cannot load a value wider than 1 bytes"]
+fn provided_bytes_lost() {
+	let mut data = [0x0FFFu16; 2];
+	let bits = &mut data.view_bits_mut::<Msb0>()[4 .. 20];
+	Field(bits).load_into_bytes_be(&mut [0]);
+}
+
+#[test]
+#[should_panic = "cannot store from an empty byte buffer"]
+fn provided_bytes_empty() {
+	let mut data = [0u16; 2];
+	Field(&mut data.view_bits_mut::<Msb0>()[4 .. 20]).store_from_bytes_le(&[]);
+}
+
+#[test]
+fn custom_order() {
+	use crate::order::HiLo;
+
+	let mut data = [0u16; 3];
+	let bits = data.view_bits_mut::<HiLo>();
+
+	bits[5 .. 37].store_le(0x1234_5678u32);
+	assert_eq!(bits[5 .. 37].load_le::<u32>(), 0x1234_5678);
+	bits[5 .. 37].store_be(-2i32);
+	assert_eq!(bits[5 .. 37].load_be::<i32>(), -2);
+	assert!(bits[.. 5].not_any());
+	assert!(bits[37 ..].not_any());
+
+	//  `HiLo` swaps nibbles, so index 0 of a `u8` is bit 4.
+	let mut byte = 0u8;
+	byte.view_bits_mut::<HiLo>()[.. 1].store(1u8);
+	assert_eq!(byte, 0x10);
+
+	let key: [u8; 5] = [0xA1, 0xB2, 0xC3, 0xD4, 0x0E];
+	let mut out = [0u8; 5];
+	bits[3 .. 39].store_from_bytes_le(&key);
+	bits[3 .. 39].load_into_bytes_le(&mut out);
+	assert_eq!(out, key);
+}
+
+#[test]
+#[should_panic = "cannot load 2 bytes from a 17-bit region"]
+fn bytes_too_short() {
+	let data = 0u32;
+	data.view_bits::<Lsb0>()[.. 17].load_into_bytes_le(&mut [0; 2]);
+}
+
 #[test]
 #[cfg(feature = "std")]
 fn read_bits() {
@@ -313,3 +515,95 @@ fn write_bits() {
 
 	assert_eq!(data, [0b1010_0000, 0b1011_0101, 0b0011_0100, 0b0000_1100]);
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn cursors() {
+	use crate::field::{
+		BitReadError,
+		BitReader,
+		BitWriter,
+	};
+
+	let mut bv = BitVec::<u16, Lsb0>::new();
+	let mut writer = BitWriter::new(&mut bv);
+	writer.write_bool(true);
+	writer.write_bits_le(0x5A5u16, 11);
+	writer.write_bits_be(-3i8, 5);
+	writer.write_unary(4);
+	for val in [0, 1, 2, 3, 1000, u64::MAX] {
+		writer.write_exp_golomb(val);
+	}
+	for val in [0, 1, -1, 77, -77, i64::MAX, i64::MIN] {
+		writer.write_signed_exp_golomb(val);
+	}
+	assert!(!writer.is_aligned());
+	writer.align();
+	assert!(writer.is_aligned());
+	for val in [0, 127, 128, 624_485, u64::MAX] {
+		writer.write_leb128(val);
+	}
+	for val in [0, 63, -64, 64, -123_456, i64::MAX, i64::MIN] {
+		writer.write_sleb128(val);
+	}
+	writer.write_bits_le(0u8, 0);
+	let end = writer.position();
+
+	let mut reader = BitReader::new(bv.as_bitslice());
+	assert!(reader.read_bool().unwrap());
+	assert_eq!(reader.read_bits_le::<u16>(11).unwrap(), 0x5A5);
+	assert_eq!(reader.read_bits_be::<i8>(5).unwrap(), -3);
+	assert_eq!(reader.read_unary().unwrap(), 4);
+	for val in [0, 1, 2, 3, 1000, u64::MAX] {
+		assert_eq!(reader.read_exp_golomb().unwrap(), val);
+	}
+	for val in [0, 1, -1, 77, -77, i64::MAX, i64::MIN] {
+		assert_eq!(reader.read_signed_exp_golomb().unwrap(), val);
+	}
+	reader.align().unwrap();
+	for val in [0, 127, 128, 624_485, u64::MAX] {
+		assert_eq!(reader.read_leb128().unwrap(), val);
+	}
+	for val in [0, 63, -64, 64, -123_456, i64::MAX, i64::MIN] {
+		assert_eq!(reader.read_sleb128().unwrap(), val);
+	}
+	assert_eq!(reader.read_bits_le::<u8>(0).unwrap(), 0);
+	assert_eq!(reader.position(), end);
+	assert!(reader.is_empty());
+	assert_eq!(
+		reader.read_bool(),
+		Err(BitReadError::UnexpectedEnd {
+			position:  end,
+			wanted:    1,
+			available: 0,
+		})
+	);
+
+	//  Failed reads leave the cursor in place.
+	let bits = bits![0, 0, 1, 0];
+	let mut reader = BitReader::new(bits);
+	assert!(reader.read_exp_golomb().is_err());
+	assert_eq!(reader.position(), 0);
+	assert!(reader.read_bits_le::<u8>(5).is_err());
+	assert_eq!(reader.position(), 0);
+
+	let data = [0xFFu8; 11];
+	let mut reader = BitReader::new(data.view_bits::<Msb0>());
+	assert!(matches!(
+		reader.read_leb128(),
+		Err(BitReadError::Overflow { position: 0 })
+	));
+	assert_eq!(reader.position(), 0);
+
+	let data = [0x80u8, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02];
+	let mut reader = BitReader::new(data.view_bits::<Lsb0>());
+	assert!(reader.read_leb128().is_err());
+	assert_eq!(reader.position(), 0);
+
+	let mut bv = bitvec![0; 65];
+	bv.push(true);
+	bv.resize(131, false);
+	let mut reader = BitReader::new(bv.as_bitslice());
+	assert!(reader.read_exp_golomb().is_err());
+	assert_eq!(reader.position(), 0);
+}
@@ -0,0 +1,113 @@
+#![doc = include_str!("../doc/fuzz.md")]
+#![cfg(feature = "arbitrary")]
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use arbitrary::{
+	Arbitrary,
+	Result,
+	Unstructured,
+};
+
+use crate::{
+	array::BitArray,
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+	boxed::BitBox,
+	vec::BitVec,
+};
+
+mod tests;
+
+/// Selects a random sub-slice whose head may begin anywhere in the first
+/// element, and whose tail may end anywhere in the last.
+fn subslice<'b, T, O>(
+	u: &mut Unstructured,
+	bits: &'b BitSlice<T, O>,
+) -> Result<&'b BitSlice<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let head = u.int_in_range(0 ..= bits.len().min(bits_of::<T::Mem>() - 1))?;
+	let bits = &bits[head ..];
+	let len = u.int_in_range(0 ..= bits.len())?;
+	Ok(&bits[.. len])
+}
+
+/// Produces bit-slices borrowed from the fuzzer’s input, with random head and
+/// tail offsets.
+impl<'a, O> Arbitrary<'a> for &'a BitSlice<u8, O>
+where O: BitOrder
+{
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let bytes = <&'a [u8]>::arbitrary(u)?;
+		subslice(u, BitSlice::from_slice(bytes))
+	}
+}
+
+impl<'a, A, O> Arbitrary<'a> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	<A::Store as BitStore>::Mem: Arbitrary<'a>,
+{
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let mut out = Self::ZERO;
+		for elem in out.as_raw_mut_slice() {
+			elem.store_value(u.arbitrary()?);
+		}
+		Ok(out)
+	}
+
+	#[inline]
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		let elts = core::mem::size_of::<A>()
+			/ core::mem::size_of::<<A::Store as BitStore>::Mem>();
+		let (min, max) =
+			<<A::Store as BitStore>::Mem as Arbitrary>::size_hint(depth);
+		(min * elts, max.map(|max| max * elts))
+	}
+}
+
+/// Produces bit-vectors whose head may begin anywhere in the first element and
+/// whose tail may end anywhere in the last, so that the partial-element paths
+/// of every algorithm are reachable.
+#[cfg(feature = "alloc")]
+impl<'a, T, O> Arbitrary<'a> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	T::Mem: Arbitrary<'a>,
+{
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let elts = u.arbitrary_len::<T::Mem>()?;
+		let data = (0 .. elts)
+			.map(|_| u.arbitrary().map(T::new))
+			.collect::<Result<Vec<T>>>()?;
+		subslice(u, BitSlice::from_slice(&data)).map(Self::from_bitslice)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T, O> Arbitrary<'a> for BitBox<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	T::Mem: Arbitrary<'a>,
+{
+	#[inline]
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		BitVec::arbitrary(u).map(BitVec::into_boxed_bitslice)
+	}
+}
@@ -0,0 +1,65 @@
+//! Unit tests for the `arbitrary` implementations.
+
+#![cfg(test)]
+
+use core::{
+	cell::Cell,
+	sync::atomic::AtomicU16,
+};
+
+use rand::random;
+
+use super::*;
+use crate::prelude::*;
+
+/// Builds many values from random input, and checks that they are sound
+/// and that their heads are not always aligned.
+fn check<T, O>()
+where
+	T: BitStore,
+	O: BitOrder,
+	T::Mem: for<'a> Arbitrary<'a>,
+{
+	let mut misaligned = false;
+	for _ in 0 .. 64 {
+		let input = (0 .. 256).map(|_| random::<u8>()).collect::<Vec<_>>();
+		let mut u = Unstructured::new(&input);
+		let bv = BitVec::<T, O>::arbitrary(&mut u).unwrap();
+		misaligned |= bv.as_bitptr().bit().into_inner() != 0;
+		assert_eq!(bv.count_ones() + bv.count_zeros(), bv.len());
+		assert_eq!(
+			bv.iter().by_vals().filter(|&b| b).count(),
+			bv.count_ones()
+		);
+
+		let bb = BitBox::<T, O>::arbitrary(&mut u).unwrap();
+		assert_eq!(bb.count_ones() + bb.count_zeros(), bb.len());
+	}
+	assert!(misaligned);
+}
+
+#[test]
+fn bitvec() {
+	check::<u8, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u32, Lsb0>();
+	check::<usize, Msb0>();
+	check::<Cell<u8>, Msb0>();
+	check::<AtomicU16, Lsb0>();
+}
+
+#[test]
+fn borrowed_and_array() {
+	let input = [0xA5u8; 64];
+	let mut u = Unstructured::new(&input);
+	let bits = <&BitSlice<u8, Msb0>>::arbitrary(&mut u).unwrap();
+	assert!(bits.len() <= 64 * 8);
+
+	let mut u = Unstructured::new(&input);
+	let arr = BitArray::<[u16; 2], Lsb0>::arbitrary(&mut u).unwrap();
+	assert_eq!(arr.into_inner(), [0xA5A5; 2]);
+	assert_eq!(
+		<BitArray<[u16; 2], Lsb0> as Arbitrary>::size_hint(0),
+		(4, Some(4)),
+	);
+}
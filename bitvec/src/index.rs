@@ -23,6 +23,7 @@ use core::{
 
 use crate::{
 	mem::{
+		self,
 		bits_of,
 		BitRegister,
 	},
@@ -928,6 +929,75 @@ where R: BitRegister
 			mask: self.mask | sel.sel,
 		}
 	}
+
+	/// Gathers the bits of a register that the mask selects.
+	///
+	/// The selected bits of `value` are packed together, in order of
+	/// significance, into the least significant bits of the result. The rest
+	/// of the result is `0`.
+	///
+	/// This is the parallel bit-extract (`pext`) instruction from x86’s BMI2
+	/// extension. When the `simd` feature is enabled and the processor supports
+	/// BMI2, that instruction is used; otherwise, the mask is walked one set
+	/// bit at a time.
+	///
+	/// ## Parameters
+	///
+	/// - `self`: The bits of `value` to gather.
+	/// - `value`: The register from which bits are gathered.
+	///
+	/// ## Returns
+	///
+	/// The selected bits of `value`, in its least significant bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitMask;
+	///
+	/// let mask = BitMask::new(0b1010_1100u8);
+	/// assert_eq!(mask.extract(0b1001_0110), 0b1001);
+	/// ```
+	#[inline]
+	pub fn extract(self, value: R) -> R {
+		mem::extract(value, self.mask)
+	}
+
+	/// Scatters the low bits of a register into the bits that the mask
+	/// selects.
+	///
+	/// The least significant bits of `value` are placed, in order of
+	/// significance, into each bit that the mask selects. The rest of the
+	/// result is `0`. This is the inverse of [`.extract()`].
+	///
+	/// This is the parallel bit-deposit (`pdep`) instruction from x86’s BMI2
+	/// extension. When the `simd` feature is enabled and the processor supports
+	/// BMI2, that instruction is used; otherwise, the mask is walked one set
+	/// bit at a time.
+	///
+	/// ## Parameters
+	///
+	/// - `self`: The bits of the result to fill.
+	/// - `value`: The register whose low bits are scattered.
+	///
+	/// ## Returns
+	///
+	/// The low bits of `value`, moved into the positions that `self` selects.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::index::BitMask;
+	///
+	/// let mask = BitMask::new(0b1010_1100u8);
+	/// assert_eq!(mask.deposit(0b1001), 0b1000_0100);
+	/// ```
+	///
+	/// [`.extract()`]: Self::extract
+	#[inline]
+	pub fn deposit(self, value: R) -> R {
+		mem::deposit(value, self.mask)
+	}
 }
 
 impl<R> Binary for BitMask<R>
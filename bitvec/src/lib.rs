@@ -48,21 +48,31 @@ pub mod array;
 pub mod boxed;
 pub mod domain;
 pub mod field;
+mod fuzz;
 pub mod index;
+pub mod matrix;
 pub mod mem;
 pub mod order;
 pub mod ptr;
-mod serdes;
+pub mod rank;
+pub mod serdes;
+pub mod set;
 pub mod slice;
+pub mod slot;
 pub mod store;
+pub mod strategy;
 pub mod vec;
 pub mod view;
 
 #[doc = include_str!("../doc/prelude.md")]
 pub mod prelude {
 	pub use crate::{
-		array::BitArray,
+		array::{
+			BitArray,
+			BitArrayVec,
+		},
 		bitarr,
+		bitfield,
 		bits,
 		field::BitField as _,
 		order::{
@@ -91,6 +101,9 @@ pub mod prelude {
 		bitbox,
 		bitvec,
 		boxed::BitBox,
-		vec::BitVec,
+		vec::{
+			BitVec,
+			SmallBitVec,
+		},
 	};
 }
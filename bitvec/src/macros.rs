@@ -363,3 +363,142 @@ macro_rules! bitbox {
 		$crate::bitvec!($($arg)*).into_boxed_bitslice()
 	};
 }
+
+#[macro_export]
+#[doc = include_str!("../doc/macros/bitfield.md")]
+macro_rules! bitfield {
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident: $store:ty, $order:ty {
+			$(
+				$(#[$fattr:meta])*
+				$fvis:vis $field:ident: $fty:ty [$($range:tt)+] $($endian:ident)?
+				=> $($access:ident)+ $(, $set:ident)?
+			);* $(;)?
+		}
+	) => {
+		$(#[$attr])*
+		#[repr(transparent)]
+		$vis struct $name($crate::array::BitArray<$store, $order>);
+
+		#[allow(dead_code)]
+		impl $name {
+			/// A value with every bit cleared.
+			$vis const ZERO: Self = Self($crate::array::BitArray::ZERO);
+
+			/// Wraps a bit-array as this bit-field type.
+			#[inline]
+			$vis const fn new(bits: $crate::array::BitArray<$store, $order>) -> Self {
+				Self(bits)
+			}
+
+			/// Removes the bit-field wrapper, returning the bit-array.
+			#[inline]
+			$vis const fn into_inner(self) -> $crate::array::BitArray<$store, $order> {
+				self.0
+			}
+
+			/// Views the underlying bit-array.
+			#[inline]
+			$vis const fn as_bitarray(&self) -> &$crate::array::BitArray<$store, $order> {
+				&self.0
+			}
+
+			/// Views the underlying bit-array mutably.
+			#[inline]
+			$vis fn as_mut_bitarray(
+				&mut self,
+			) -> &mut $crate::array::BitArray<$store, $order> {
+				&mut self.0
+			}
+		}
+
+		impl $name {
+			$(
+				$(#[$fattr])*
+				$fvis const $field: $crate::macros::internal::core::ops::Range<usize> =
+					$crate::__bitfield!(@range $($range)+);
+
+				$crate::__bitfield!(
+					@access [$(#[$fattr])*] $fvis $field: $fty,
+					$crate::__bitfield!(@endian $($endian)?);
+					$($access)+ $(, $set)?
+				);
+			)*
+		}
+
+		impl $crate::macros::internal::core::fmt::Debug for $name {
+			fn fmt(
+				&self,
+				fmt: &mut $crate::macros::internal::core::fmt::Formatter,
+			) -> $crate::macros::internal::core::fmt::Result {
+				fmt.debug_struct(stringify!($name))
+					$(.field(
+						stringify!($field),
+						$crate::__bitfield!(
+							@debug &self.0[Self::$field], $fty,
+							$crate::__bitfield!(@endian $($endian)?);
+							$($access)+ $(, $set)?
+						),
+					))*
+					.finish()
+			}
+		}
+
+		//  Check the layout during compilation.
+		const _: () = {
+			const BITS: usize = $crate::mem::bits_of::<$store>();
+			$(
+				assert!(
+					$name::$field.start < $name::$field.end,
+					concat!("bit-field `", stringify!($field), "` is empty"),
+				);
+				assert!(
+					$name::$field.end <= BITS,
+					concat!(
+						"bit-field `",
+						stringify!($field),
+						"` extends past the end of `",
+						stringify!($name),
+						"`",
+					),
+				);
+				assert!(
+					$name::$field.end - $name::$field.start
+						<= <$fty as $crate::macros::internal::FieldValue>::BITS,
+					concat!(
+						"bit-field `",
+						stringify!($field),
+						"` is wider than its type",
+					),
+				);
+			)*
+
+			let ranges: &[(usize, usize)] =
+				&[$(($name::$field.start, $name::$field.end)),*];
+			let mut this = 0;
+			while this < ranges.len() {
+				let mut that = this + 1;
+				while that < ranges.len() {
+					assert!(
+						ranges[this].1 <= ranges[that].0
+							|| ranges[that].1 <= ranges[this].0,
+						concat!("bit-fields of `", stringify!($name), "` overlap"),
+					);
+					that += 1;
+				}
+				this += 1;
+			}
+		};
+	};
+
+	(
+		$(#[$attr:meta])*
+		$vis:vis struct $name:ident: $store:ty { $($fields:tt)* }
+	) => {
+		$crate::bitfield! {
+			$(#[$attr])*
+			$vis struct $name: $store, $crate::order::Lsb0 { $($fields)* }
+		}
+	};
+}
@@ -9,6 +9,14 @@ pub use core;
 #[doc(hidden)]
 pub use funty;
 
+use crate::{
+	field::BitField,
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
 #[doc(hidden)]
 #[macro_export]
 #[doc = include_str!("../../doc/macros/encode_bits.md")]
@@ -412,3 +420,189 @@ pub use self::u8_from_be_bits as u8_from_ne_bits;
 #[doc(hidden)]
 #[cfg(target_endian = "little")]
 pub use self::u8_from_le_bits as u8_from_ne_bits;
+
+/// Generates the per-field items of a `bitfield!` type.
+#[doc(hidden)]
+#[macro_export]
+#[doc = include_str!("../../doc/macros/bitfield_internal.md")]
+macro_rules! __bitfield {
+	//  Read-only fields produce only a getter.
+	(@access [$(#[$attr:meta])*] $vis:vis $field:ident: $typ:ty, $endian:expr;
+		ro $get:ident
+	) => {
+		$crate::__bitfield!(@get [$(#[$attr])*] $vis $field: $typ, $endian; $get);
+	};
+	//  Write-only fields produce only a setter.
+	(@access [$(#[$attr:meta])*] $vis:vis $field:ident: $typ:ty, $endian:expr;
+		wo $set:ident
+	) => {
+		$crate::__bitfield!(@set [$(#[$attr])*] $vis $field: $typ, $endian; $set);
+	};
+	(@access [$(#[$attr:meta])*] $vis:vis $field:ident: $typ:ty, $endian:expr;
+		$get:ident, $set:ident
+	) => {
+		$crate::__bitfield!(@get [$(#[$attr])*] $vis $field: $typ, $endian; $get);
+		$crate::__bitfield!(@set [$(#[$attr])*] $vis $field: $typ, $endian; $set);
+	};
+
+	(@get [$(#[$attr:meta])*] $vis:vis $field:ident: $typ:ty, $endian:expr;
+		$get:ident
+	) => {
+		$(#[$attr])*
+		#[inline]
+		$vis fn $get(&self) -> $typ {
+			<$typ as $crate::macros::internal::FieldValue>::load(
+				&self.0[Self::$field],
+				$endian,
+			)
+		}
+	};
+	(@set [$(#[$attr:meta])*] $vis:vis $field:ident: $typ:ty, $endian:expr;
+		$set:ident
+	) => {
+		$(#[$attr])*
+		#[inline]
+		$vis fn $set(&mut self, value: $typ) {
+			<$typ as $crate::macros::internal::FieldValue>::store(
+				&mut self.0[Self::$field],
+				value,
+				$endian,
+			)
+		}
+	};
+
+	//  The `Debug` rendering must not read write-only fields, which may be
+	//  backed by registers that do not support reads.
+	(@debug $bits:expr, $typ:ty, $endian:expr; wo $set:ident) => {
+		&$crate::macros::internal::WriteOnly
+	};
+	(@debug $bits:expr, $typ:ty, $endian:expr; $($access:tt)+) => {
+		&<$typ as $crate::macros::internal::FieldValue>::load($bits, $endian)
+	};
+
+	//  Field ranges are written as `[start .. end]`, `[start ..= last]`, or
+	//  `[index]` for single bits.
+	(@range $start:tt .. $end:tt) => {
+		$start .. $end
+	};
+	(@range $start:tt ..= $last:tt) => {
+		$start .. $last + 1
+	};
+	(@range $index:tt) => {
+		$index .. $index + 1
+	};
+
+	(@endian) => {
+		$crate::macros::internal::Endian::Little
+	};
+	(@endian le) => {
+		$crate::macros::internal::Endian::Little
+	};
+	(@endian be) => {
+		$crate::macros::internal::Endian::Big
+	};
+}
+
+/// Selects which `BitField` method a `bitfield!` accessor uses.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Endian {
+	/// `BitField::load_le` and `BitField::store_le`.
+	Little,
+	/// `BitField::load_be` and `BitField::store_be`.
+	Big,
+}
+
+/// Stands in for the value of a write-only `bitfield!` field in the `Debug`
+/// rendering of its type.
+#[doc(hidden)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct WriteOnly;
+
+impl core::fmt::Debug for WriteOnly {
+	#[inline]
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+		fmt.write_str("<write-only>")
+	}
+}
+
+/// A value that can be held in a `bitfield!` field.
+#[doc(hidden)]
+pub trait FieldValue: Sized + core::fmt::Debug {
+	/// The widest field that can hold this type.
+	const BITS: usize;
+
+	/// Reads the value out of a field.
+	fn load<T, O>(bits: &BitSlice<T, O>, endian: Endian) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField;
+
+	/// Writes the value into a field.
+	fn store<T, O>(bits: &mut BitSlice<T, O>, value: Self, endian: Endian)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField;
+}
+
+impl FieldValue for bool {
+	const BITS: usize = 1;
+
+	#[inline]
+	fn load<T, O>(bits: &BitSlice<T, O>, _: Endian) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		bits[0]
+	}
+
+	#[inline]
+	fn store<T, O>(bits: &mut BitSlice<T, O>, value: Self, _: Endian)
+	where
+		T: BitStore,
+		O: BitOrder,
+		BitSlice<T, O>: BitField,
+	{
+		bits.set(0, value);
+	}
+}
+
+macro_rules! field_value {
+	($($t:ty),+ $(,)?) => { $(
+		impl FieldValue for $t {
+			const BITS: usize = bits_of::<$t>();
+
+			#[inline]
+			fn load<T, O>(bits: &BitSlice<T, O>, endian: Endian) -> Self
+			where
+				T: BitStore,
+				O: BitOrder,
+				BitSlice<T, O>: BitField,
+			{
+				match endian {
+					Endian::Little => bits.load_le(),
+					Endian::Big => bits.load_be(),
+				}
+			}
+
+			#[inline]
+			fn store<T, O>(bits: &mut BitSlice<T, O>, value: Self, endian: Endian)
+			where
+				T: BitStore,
+				O: BitOrder,
+				BitSlice<T, O>: BitField,
+			{
+				match endian {
+					Endian::Little => bits.store_le(value),
+					Endian::Big => bits.store_be(value),
+				}
+			}
+		}
+	)+ };
+}
+
+field_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -2,6 +2,8 @@
 
 #![cfg(test)]
 
+#[cfg(feature = "alloc")]
+use alloc::format;
 use core::{
 	cell::Cell,
 	sync::atomic::*,
@@ -535,6 +537,82 @@ fn compile_bitbox() {
 	}
 }
 
+#[test]
+fn compile_bitfield() {
+	bitfield! {
+		#[derive(Clone, Copy, Default, Eq, PartialEq)]
+		struct Register: [u16; 2], Msb0 {
+			/// A big-endian field that crosses an element boundary.
+			WIDE: u16 [12 .. 24] be => wide, set_wide;
+			LITTLE: u8 [0 ..= 7] le => little, set_little;
+			SIGNED: i8 [8 .. 12] => signed, set_signed;
+			FLAG: bool [24] => flag, set_flag;
+			STATUS: u8 [25 .. 28] => ro status;
+			COMMAND: u8 [28 .. 32] => wo set_command;
+		}
+	}
+
+	bitfield! {
+		struct Byte: u8 {
+			LOW: u8 [0 .. 4] => low, set_low;
+			HIGH: bool [(bits_of::<u8>() - 1)] => high, set_high;
+		}
+	}
+
+	assert_eq!(Register::WIDE, 12 .. 24);
+	assert_eq!(Register::LITTLE, 0 .. 8);
+	assert_eq!(Register::FLAG, 24 .. 25);
+
+	let mut reg = Register::ZERO;
+	assert_eq!(reg, Register::default());
+	reg.set_wide(0xABC);
+	reg.set_little(0x5A);
+	reg.set_signed(-3);
+	reg.set_flag(true);
+	reg.set_command(9);
+
+	assert_eq!(reg.wide(), 0xABC);
+	assert_eq!(reg.little(), 0x5A);
+	assert_eq!(reg.signed(), -3);
+	assert!(reg.flag());
+	assert_eq!(reg.status(), 0);
+	assert_eq!(reg.as_bitarray()[Register::WIDE].load_be::<u16>(), 0xABC);
+	assert_eq!(reg.as_bitarray()[Register::COMMAND].load::<u8>(), 9);
+
+	reg.as_mut_bitarray()[Register::STATUS].store::<u8>(5);
+	assert_eq!(reg.status(), 5);
+	assert_eq!(reg.wide(), 0xABC);
+	assert_eq!(reg.signed(), -3);
+
+	let copy = Register::new(reg.into_inner());
+	assert_eq!(copy, reg);
+
+	#[cfg(feature = "alloc")]
+	assert_eq!(
+		format!("{:?}", reg),
+		"Register { WIDE: 2748, LITTLE: 90, SIGNED: -3, FLAG: true, STATUS: 5, \
+		 COMMAND: <write-only> }",
+	);
+
+	//  Fields without an endianness are little-endian on every target.
+	bitfield! {
+		struct Split: [u8; 2] {
+			MIDDLE: u8 [4 .. 12] => middle, set_middle;
+		}
+	}
+
+	let mut split = Split::ZERO;
+	split.set_middle(0xA5);
+	assert_eq!(split.into_inner().into_inner(), [0x50, 0x0A]);
+
+	let mut byte = Byte::ZERO;
+	byte.set_low(7);
+	byte.set_high(true);
+	assert_eq!(byte.low(), 7);
+	assert!(byte.high());
+	assert_eq!(byte.into_inner().into_inner(), 0x87);
+}
+
 #[test]
 fn encode_bits() {
 	let uint: [u8; 1] = __encode_bits!(u8, Lsb0; 1, 0, 1, 0, 1, 1, 0, 0);
@@ -0,0 +1,834 @@
+#![doc = include_str!("../doc/matrix.md")]
+#![cfg(feature = "alloc")]
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	iter::FusedIterator,
+	ops::Range,
+};
+
+use crate::{
+	field::BitField,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod ops;
+mod tests;
+
+#[doc = include_str!("../doc/matrix/BitMatrix.md")]
+pub struct BitMatrix<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The matrix contents, in row-major order.
+	bits: BitVec<T, O>,
+	/// The number of rows.
+	rows: usize,
+	/// The number of columns, and the length of each row.
+	cols: usize,
+}
+
+/// Constructors and accessors.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a matrix with every bit cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if `rows * cols` overflows.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u8>::new(3, 5);
+	/// assert_eq!((m.rows(), m.cols()), (3, 5));
+	/// assert!(m.as_bitslice().not_any());
+	/// ```
+	#[inline]
+	pub fn new(rows: usize, cols: usize) -> Self {
+		let len = rows.checked_mul(cols).unwrap_or_else(|| {
+			panic!("a {}×{} matrix is too large", rows, cols)
+		});
+		Self {
+			bits: BitVec::repeat(false, len),
+			rows,
+			cols,
+		}
+	}
+
+	/// Creates a square matrix with only its main diagonal set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::<u16>::identity(4);
+	/// assert_eq!(m.get(2, 2), Some(true));
+	/// assert_eq!(m.get(2, 3), Some(false));
+	/// ```
+	#[inline]
+	pub fn identity(size: usize) -> Self {
+		let mut out = Self::new(size, size);
+		for idx in 0 .. size {
+			out.set(idx, idx, true);
+		}
+		out
+	}
+
+	/// Interprets a bit-vector as a row-major matrix.
+	///
+	/// ## Panics
+	///
+	/// This panics if `bits.len()` is not `rows * cols`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::from_bitvec(bitvec![0, 1, 1, 1, 0, 0], 2, 3);
+	/// assert_eq!(m[0], bits![0, 1, 1]);
+	/// assert_eq!(m[1], bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<T, O>, rows: usize, cols: usize) -> Self {
+		assert_eq!(
+			rows.checked_mul(cols),
+			Some(bits.len()),
+			"a {}-bit vector cannot be a {}×{} matrix",
+			bits.len(),
+			rows,
+			cols,
+		);
+		Self { bits, rows, cols }
+	}
+
+	/// Removes the matrix structure, returning its contents in row-major
+	/// order.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+
+	/// Views the matrix contents in row-major order.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Views the matrix contents in row-major order, mutably.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<T, O> {
+		self.bits.as_mut_bitslice()
+	}
+
+	/// Gets the number of rows.
+	#[inline]
+	pub fn rows(&self) -> usize {
+		self.rows
+	}
+
+	/// Gets the number of columns.
+	#[inline]
+	pub fn cols(&self) -> usize {
+		self.cols
+	}
+
+	/// Tests whether the matrix has as many rows as columns.
+	#[inline]
+	pub fn is_square(&self) -> bool {
+		self.rows == self.cols
+	}
+
+	/// Views one row of the matrix.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is not less than `self.rows()`.
+	#[inline]
+	pub fn row(&self, row: usize) -> &BitSlice<T, O> {
+		self.assert_row(row);
+		&self.bits[row * self.cols ..][.. self.cols]
+	}
+
+	/// Views one row of the matrix, mutably.
+	///
+	/// ## Panics
+	///
+	/// This panics if `row` is not less than `self.rows()`.
+	#[inline]
+	pub fn row_mut(&mut self, row: usize) -> &mut BitSlice<T, O> {
+		self.assert_row(row);
+		let cols = self.cols;
+		&mut self.bits[row * cols ..][.. cols]
+	}
+
+	/// Iterates over the rows of the matrix, from top to bottom.
+	#[inline]
+	pub fn iter_rows(
+		&self,
+	) -> impl '_ + DoubleEndedIterator<Item = &BitSlice<T, O>> + ExactSizeIterator
+	{
+		(0 .. self.rows).map(move |row| self.row(row))
+	}
+
+	/// Iterates down one column of the matrix, from top to bottom.
+	///
+	/// ## Panics
+	///
+	/// This panics if `col` is not less than `self.cols()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::from_bitvec(bitvec![0, 1, 1, 1, 0, 0], 3, 2);
+	/// assert!(m.column(1).eq([true, true, false]));
+	/// ```
+	#[inline]
+	pub fn column(&self, col: usize) -> Column<'_, T, O> {
+		assert!(
+			col < self.cols,
+			"column {} out of bounds for a matrix with {} columns",
+			col,
+			self.cols,
+		);
+		Column {
+			bits: self.as_bitslice(),
+			cols: self.cols,
+			col,
+			rows: 0 .. self.rows,
+		}
+	}
+
+	/// Reads the bit at a row and column.
+	///
+	/// ## Returns
+	///
+	/// The bit at `[row][col]`, or `None` if either coördinate is out of
+	/// bounds.
+	#[inline]
+	pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+		if row < self.rows && col < self.cols {
+			Some(self.bits[row * self.cols + col])
+		}
+		else {
+			None
+		}
+	}
+
+	/// Writes the bit at a row and column.
+	///
+	/// ## Panics
+	///
+	/// This panics if either coördinate is out of bounds.
+	#[inline]
+	pub fn set(&mut self, row: usize, col: usize, value: bool) {
+		self.row_mut(row).set(col, value);
+	}
+}
+
+/// Matrix algorithms.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Produces the transpose of the matrix.
+	///
+	/// The matrix is processed in blocks of 64×64 bits, each of which is
+	/// flipped by [`transpose64`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let m = BitMatrix::from_bitvec(bitvec![0, 1, 1, 1, 0, 0], 2, 3);
+	/// let t = m.transpose();
+	/// assert_eq!((t.rows(), t.cols()), (3, 2));
+	/// assert_eq!(t.as_bitslice(), bits![0, 1, 1, 0, 1, 0]);
+	/// ```
+	pub fn transpose(&self) -> Self {
+		let mut out = Self::new(self.cols, self.rows);
+		let mut block = [0u64; 64];
+		for base_row in (0 .. self.rows).step_by(64) {
+			let height = (self.rows - base_row).min(64);
+			for base_col in (0 .. self.cols).step_by(64) {
+				let width = (self.cols - base_col).min(64);
+				for (idx, word) in block.iter_mut().enumerate() {
+					*word = if idx < height {
+						load_word(
+							&self.row(base_row + idx)[base_col ..][.. width],
+						)
+					}
+					else {
+						0
+					};
+				}
+				transpose64(&mut block);
+				for (idx, word) in block.iter().take(width).enumerate() {
+					store_word(
+						&mut out.row_mut(base_col + idx)[base_row ..][.. height],
+						*word,
+					);
+				}
+			}
+		}
+		out
+	}
+
+	/// Computes the Boolean matrix product `self × rhs`.
+	///
+	/// Each bit of the product is the OR of the ANDs of a row of `self` with a
+	/// column of `rhs`. This is computed by OR-ing together the rows of `rhs`
+	/// selected by each row of `self`, so its cost falls with the density of
+	/// `self`.
+	///
+	/// This is also available as the `*` operator on matrix references.
+	///
+	/// ## Panics
+	///
+	/// This panics if `self.cols()` is not `rhs.rows()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// //  0 → 1 → 2
+	/// let mut step = BitMatrix::<u8>::new(3, 3);
+	/// step.set(0, 1, true);
+	/// step.set(1, 2, true);
+	///
+	/// let two = step.product(&step);
+	/// assert_eq!(two.get(0, 2), Some(true));
+	/// assert_eq!(two.as_bitslice().count_ones(), 1);
+	/// ```
+	pub fn product(&self, rhs: &Self) -> Self {
+		assert_eq!(
+			self.cols, rhs.rows,
+			"cannot multiply a {}×{} matrix by a {}×{} matrix",
+			self.rows, self.cols, rhs.rows, rhs.cols,
+		);
+		let mut out = Self::new(self.rows, rhs.cols);
+		for (row, selector) in self.iter_rows().enumerate() {
+			let dst = out.row_mut(row);
+			for idx in selector.iter_ones() {
+				*dst |= rhs.row(idx);
+			}
+		}
+		out
+	}
+
+	/// Computes the transitive closure of a relation.
+	///
+	/// If the matrix is read as an adjacency matrix, where `[i][j]` marks an
+	/// edge from `i` to `j`, then the closure marks `[i][j]` whenever there is
+	/// a path of one or more edges from `i` to `j`. The main diagonal is only
+	/// set for vertices that lie on a cycle.
+	///
+	/// This uses Warshall’s algorithm, OR-ing whole rows at a time.
+	///
+	/// ## Panics
+	///
+	/// This panics if the matrix is not square.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut graph = BitMatrix::<u32>::new(4, 4);
+	/// graph.set(0, 1, true);
+	/// graph.set(1, 2, true);
+	/// graph.set(2, 1, true);
+	///
+	/// let reach = graph.transitive_closure();
+	/// assert!(reach.row(0).iter_ones().eq([1, 2]));
+	/// assert_eq!(reach.get(1, 1), Some(true));
+	/// assert!(reach.row(3).not_any());
+	/// ```
+	pub fn transitive_closure(&self) -> Self {
+		assert!(
+			self.is_square(),
+			"the transitive closure of a {}×{} matrix is undefined",
+			self.rows,
+			self.cols,
+		);
+		let mut out = self.clone();
+		let mut pivot = BitVec::<T, O>::repeat(false, self.cols);
+		for mid in 0 .. self.rows {
+			pivot.copy_from_bitslice(out.row(mid));
+			for row in 0 .. self.rows {
+				if out.bits[row * self.cols + mid] {
+					*out.row_mut(row) |= pivot.as_bitslice();
+				}
+			}
+		}
+		out
+	}
+
+	/// Sets each bit of row `dst` to its AND with row `src`.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[inline]
+	pub fn and_row(&mut self, dst: usize, src: usize) {
+		if let Some((dst, src)) = self.row_pair(dst, src) {
+			*dst &= &*src;
+		}
+	}
+
+	/// Sets each bit of row `dst` to its OR with row `src`.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::matrix::BitMatrix;
+	///
+	/// let mut m = BitMatrix::from_bitvec(bitvec![0, 1, 0, 1, 0, 1], 2, 3);
+	/// m.or_row(0, 1);
+	/// assert_eq!(m[0], bits![1, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn or_row(&mut self, dst: usize, src: usize) {
+		if let Some((dst, src)) = self.row_pair(dst, src) {
+			*dst |= &*src;
+		}
+	}
+
+	/// Sets each bit of row `dst` to its XOR with row `src`.
+	///
+	/// XOR-ing a row with itself clears it.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[inline]
+	pub fn xor_row(&mut self, dst: usize, src: usize) {
+		match self.row_pair(dst, src) {
+			| Some((dst, src)) => *dst ^= &*src,
+			| None => self.row_mut(dst).fill(false),
+		}
+	}
+
+	/// Swaps the contents of two rows.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[inline]
+	pub fn swap_rows(&mut self, a: usize, b: usize) {
+		if let Some((a, b)) = self.row_pair(a, b) {
+			a.swap_with_bitslice(b);
+		}
+	}
+}
+
+/// Internal helpers.
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Asserts that a row index is in bounds.
+	#[inline]
+	fn assert_row(&self, row: usize) {
+		assert!(
+			row < self.rows,
+			"row {} out of bounds for a matrix with {} rows",
+			row,
+			self.rows,
+		);
+	}
+
+	/// Views two different rows at once.
+	///
+	/// ## Returns
+	///
+	/// `None` if `dst` and `src` are the same row; otherwise, the two rows.
+	///
+	/// ## Panics
+	///
+	/// This panics if either row is out of bounds.
+	#[allow(clippy::type_complexity)]
+	fn row_pair(
+		&mut self,
+		dst: usize,
+		src: usize,
+	) -> Option<(&mut BitSlice<T::Alias, O>, &mut BitSlice<T::Alias, O>)> {
+		self.assert_row(dst);
+		self.assert_row(src);
+		let cols = self.cols;
+		if dst < src {
+			let (front, back) = self.bits.split_at_mut(src * cols);
+			Some((&mut front[dst * cols ..][.. cols], &mut back[.. cols]))
+		}
+		else if src < dst {
+			let (front, back) = self.bits.split_at_mut(dst * cols);
+			Some((&mut back[.. cols], &mut front[src * cols ..][.. cols]))
+		}
+		else {
+			None
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+			rows: self.rows,
+			cols: self.cols,
+		}
+	}
+}
+
+impl<T, O> Eq for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+impl<T, O> PartialEq for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.rows == other.rows
+			&& self.cols == other.cols
+			&& self.bits == other.bits
+	}
+}
+
+impl<T, O> Debug for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		/// Renders each row with its `Display` implementation.
+		struct Rows<'a, T, O>(&'a BitMatrix<T, O>)
+		where
+			T: BitStore,
+			O: BitOrder;
+
+		/// Renders one row with its `Display` implementation.
+		struct Row<'a, T, O>(&'a BitSlice<T, O>)
+		where
+			T: BitStore,
+			O: BitOrder;
+
+		impl<T, O> Debug for Rows<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				fmt.debug_list()
+					.entries(self.0.iter_rows().map(Row))
+					.finish()
+			}
+		}
+
+		impl<T, O> Debug for Row<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Display::fmt(self.0, fmt)
+			}
+		}
+
+		fmt.debug_struct("BitMatrix")
+			.field("rows", &self.rows)
+			.field("cols", &self.cols)
+			.field("bits", &Rows(self))
+			.finish()
+	}
+}
+
+/// Renders the matrix as lines of `0` and `1` characters, one line per row.
+impl<T, O> Display for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		for (idx, row) in self.iter_rows().enumerate() {
+			if idx > 0 {
+				fmt.write_str("\n")?;
+			}
+			for bit in row.iter().by_vals() {
+				fmt.write_str(if bit { "1" } else { "0" })?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[doc = include_str!("../doc/matrix/Column.md")]
+#[derive(Clone)]
+pub struct Column<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The matrix contents.
+	bits: &'a BitSlice<T, O>,
+	/// The stride between successive bits of the column.
+	cols: usize,
+	/// The column being walked.
+	col:  usize,
+	/// The rows that have not yet been yielded.
+	rows: Range<usize>,
+}
+
+impl<T, O> Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Reads the column’s bit in a row that is known to be in bounds.
+	#[inline]
+	fn read(&self, row: usize) -> bool {
+		unsafe { *self.bits.get_unchecked(row * self.cols + self.col) }
+	}
+}
+
+impl<T, O> Iterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.rows.next().map(|row| self.read(row))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.rows.size_hint()
+	}
+
+	#[inline]
+	fn count(self) -> usize {
+		self.len()
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.rows.nth(n).map(|row| self.read(row))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<T, O> DoubleEndedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.rows.next_back().map(|row| self.read(row))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.rows.nth_back(n).map(|row| self.read(row))
+	}
+}
+
+impl<T, O> ExactSizeIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.rows.len()
+	}
+}
+
+impl<T, O> FusedIterator for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for Column<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("Column")
+			.field("col", &self.col)
+			.field("rows", &self.rows)
+			.finish()
+	}
+}
+
+/** Transposes an 8×8 bit matrix packed into a `u64`.
+
+Row `r` of the matrix is byte `r` of the integer (`(word >> (8 * r)) as u8`),
+and column `c` is bit `c` of that byte. The result has bit `c` of byte `r` moved
+to bit `r` of byte `c`.
+
+## Examples
+
+```rust
+use bitvec::matrix::transpose8;
+
+//  Row 0 is all set; after the transpose, so is column 0.
+assert_eq!(transpose8(0xFF), 0x0101_0101_0101_0101);
+assert_eq!(transpose8(0x0101_0101_0101_0101), 0xFF);
+```
+**/
+#[inline]
+pub const fn transpose8(mut word: u64) -> u64 {
+	//  Swap 1×1, then 2×2, then 4×4 blocks across the main diagonal.
+	let mut tmp = (word ^ (word >> 7)) & 0x00AA_00AA_00AA_00AA;
+	word ^= tmp ^ (tmp << 7);
+	tmp = (word ^ (word >> 14)) & 0x0000_CCCC_0000_CCCC;
+	word ^= tmp ^ (tmp << 14);
+	tmp = (word ^ (word >> 28)) & 0x0000_0000_F0F0_F0F0;
+	word ^ tmp ^ (tmp << 28)
+}
+
+/** Transposes a 64×64 bit matrix in place.
+
+Row `r` of the matrix is `block[r]`, and column `c` is bit `c` of each row
+(`(block[r] >> c) & 1`). After the transpose, `block[c]` holds what was column
+`c`.
+
+This swaps successively smaller sub-blocks across the main diagonal, touching
+each word six times.
+
+## Examples
+
+```rust
+use bitvec::matrix::transpose64;
+
+let mut block = [0u64; 64];
+block[3] = 1 << 60;
+transpose64(&mut block);
+assert_eq!(block[60], 1 << 3);
+assert_eq!(block.iter().map(|w| w.count_ones()).sum::<u32>(), 1);
+```
+**/
+#[inline]
+pub fn transpose64(block: &mut [u64; 64]) {
+	let mut width = 32;
+	let mut mask = 0x0000_0000_FFFF_FFFFu64;
+	while width != 0 {
+		let mut row = 0;
+		while row < 64 {
+			//  Swap the high `width` columns of `row` with the low `width`
+			//  columns of `row + width`.
+			let tmp = ((block[row] >> width) ^ block[row + width]) & mask;
+			block[row] ^= tmp << width;
+			block[row + width] ^= tmp;
+			row = (row + width + 1) & !width;
+		}
+		width >>= 1;
+		mask ^= mask << width;
+	}
+}
+
+/// Loads up to 64 bits into a word, placing bit `n` of the bit-slice at bit
+/// `n` of the word.
+fn load_word<T, O>(bits: &BitSlice<T, O>) -> u64
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	if bits.is_empty() {
+		return 0;
+	}
+	if let Some(bits) = bits.coerce::<T, Lsb0>() {
+		bits.load_le::<u64>()
+	}
+	else if let Some(bits) = bits.coerce::<T, Msb0>() {
+		bits.load_be::<u64>().reverse_bits() >> (64 - bits.len())
+	}
+	else {
+		bits.iter_ones().fold(0, |word, idx| word | (1 << idx))
+	}
+}
+
+/// Stores the low bits of a word into a bit-slice, placing bit `n` of the word
+/// at bit `n` of the bit-slice.
+fn store_word<T, O>(bits: &mut BitSlice<T, O>, word: u64)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	if bits.is_empty() {
+		return;
+	}
+	let len = bits.len();
+	if let Some(bits) = bits.coerce_mut::<T, Lsb0>() {
+		bits.store_le::<u64>(word);
+	}
+	else if let Some(bits) = bits.coerce_mut::<T, Msb0>() {
+		bits.store_be::<u64>(word.reverse_bits() >> (64 - len));
+	}
+	else {
+		for idx in 0 .. len {
+			bits.set(idx, (word >> idx) & 1 == 1);
+		}
+	}
+}
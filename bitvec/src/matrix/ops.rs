@@ -0,0 +1,126 @@
+//! Operator implementations for bit-matrices.
+
+use core::ops::{
+	BitAndAssign,
+	BitOrAssign,
+	BitXorAssign,
+	Index,
+	IndexMut,
+	Mul,
+	Not,
+};
+
+use super::BitMatrix;
+use crate::{
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+};
+
+impl<T, O> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Asserts that two matrices have the same dimensions.
+	fn assert_same_shape(&self, rhs: &Self, op: &str) {
+		assert!(
+			self.rows == rhs.rows && self.cols == rhs.cols,
+			"cannot {} a {}×{} matrix with a {}×{} matrix",
+			op,
+			self.rows,
+			self.cols,
+			rhs.rows,
+			rhs.cols,
+		);
+	}
+}
+
+impl<T, O> BitAndAssign<&BitMatrix<T, O>> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn bitand_assign(&mut self, rhs: &BitMatrix<T, O>) {
+		self.assert_same_shape(rhs, "AND");
+		*self.bits.as_mut_bitslice() &= rhs.as_bitslice();
+	}
+}
+
+impl<T, O> BitOrAssign<&BitMatrix<T, O>> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn bitor_assign(&mut self, rhs: &BitMatrix<T, O>) {
+		self.assert_same_shape(rhs, "OR");
+		*self.bits.as_mut_bitslice() |= rhs.as_bitslice();
+	}
+}
+
+impl<T, O> BitXorAssign<&BitMatrix<T, O>> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn bitxor_assign(&mut self, rhs: &BitMatrix<T, O>) {
+		self.assert_same_shape(rhs, "XOR");
+		*self.bits.as_mut_bitslice() ^= rhs.as_bitslice();
+	}
+}
+
+impl<T, O> Not for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = Self;
+
+	#[inline]
+	fn not(mut self) -> Self::Output {
+		self.bits = !self.bits;
+		self
+	}
+}
+
+/// Boolean matrix multiplication. See [`BitMatrix::product`].
+impl<T, O> Mul<&BitMatrix<T, O>> for &BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitMatrix<T, O>;
+
+	#[inline]
+	fn mul(self, rhs: &BitMatrix<T, O>) -> Self::Output {
+		self.product(rhs)
+	}
+}
+
+/// Indexing a matrix by a single number selects a row.
+impl<T, O> Index<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSlice<T, O>;
+
+	#[inline]
+	fn index(&self, row: usize) -> &Self::Output {
+		self.row(row)
+	}
+}
+
+impl<T, O> IndexMut<usize> for BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+		self.row_mut(row)
+	}
+}
@@ -0,0 +1,191 @@
+//! Unit tests for bit-matrices.
+
+#![cfg(test)]
+
+use alloc::{
+	format,
+	string::ToString,
+};
+use core::cell::Cell;
+
+use rand::random;
+
+use super::{
+	transpose64,
+	transpose8,
+	BitMatrix,
+};
+use crate::prelude::*;
+
+/// Builds a matrix with random contents.
+fn random_matrix<T, O>(rows: usize, cols: usize) -> BitMatrix<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut out = BitMatrix::new(rows, cols);
+	for bit in out.as_mut_bitslice().iter_mut() {
+		bit.commit(random());
+	}
+	out
+}
+
+/// Checks the transpose against a bit-by-bit copy.
+fn check_transpose<T, O>()
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	for (rows, cols) in [(0, 5), (1, 1), (3, 70), (64, 64), (65, 130), (200, 9)]
+	{
+		let m = random_matrix::<T, O>(rows, cols);
+		let t = m.transpose();
+		assert_eq!((t.rows(), t.cols()), (cols, rows));
+		for r in 0 .. rows {
+			for c in 0 .. cols {
+				assert_eq!(
+					m.get(r, c),
+					t.get(c, r),
+					"{}×{} [{}][{}]",
+					rows,
+					cols,
+					r,
+					c
+				);
+			}
+		}
+		assert_eq!(t.transpose(), m);
+	}
+}
+
+#[test]
+fn kernels() {
+	for _ in 0 .. 32 {
+		let word = random::<u64>();
+		let flipped = transpose8(word);
+		for r in 0 .. 8 {
+			for c in 0 .. 8 {
+				assert_eq!(
+					(word >> (8 * r + c)) & 1,
+					(flipped >> (8 * c + r)) & 1,
+				);
+			}
+		}
+		assert_eq!(transpose8(flipped), word);
+
+		let block = [(); 64].map(|_| random::<u64>());
+		let mut flipped = block;
+		transpose64(&mut flipped);
+		for (r, row) in block.iter().enumerate() {
+			for (c, col) in flipped.iter().enumerate() {
+				assert_eq!((row >> c) & 1, (col >> r) & 1);
+			}
+		}
+		transpose64(&mut flipped);
+		assert_eq!(flipped, block);
+	}
+}
+
+#[test]
+fn transpose() {
+	check_transpose::<u8, Lsb0>();
+	check_transpose::<u16, Msb0>();
+	check_transpose::<u32, Lsb0>();
+	check_transpose::<u64, Msb0>();
+	check_transpose::<usize, crate::order::HiLo>();
+	check_transpose::<Cell<u8>, Msb0>();
+}
+
+#[test]
+fn product_and_closure() {
+	for size in [0, 1, 7, 40] {
+		let a = random_matrix::<u8, Lsb0>(size, size + 3);
+		let b = random_matrix::<u8, Lsb0>(size + 3, size);
+		let p = &a * &b;
+		for r in 0 .. size {
+			for c in 0 .. size {
+				let expected = (0 .. size + 3).any(|k| a[r][k] && b[k][c]);
+				assert_eq!(p.get(r, c), Some(expected));
+			}
+		}
+		assert_eq!(a.product(&BitMatrix::identity(size + 3)), a);
+
+		let mut graph = BitMatrix::<u16, Msb0>::new(size, size);
+		for _ in 0 .. size * 2 {
+			if size > 0 {
+				graph.set(
+					random::<usize>() % size,
+					random::<usize>() % size,
+					true,
+				);
+			}
+		}
+		//  Naïve closure: keep squaring until nothing changes.
+		let mut reach = graph.clone();
+		loop {
+			let mut next = &reach * &graph;
+			next |= &reach;
+			if next == reach {
+				break;
+			}
+			reach = next;
+		}
+		assert_eq!(graph.transitive_closure(), reach);
+	}
+}
+
+#[test]
+fn rows_and_columns() {
+	let mut m = BitMatrix::<u8, Msb0>::new(3, 10);
+	m.set(0, 9, true);
+	m.set(2, 0, true);
+	m[1].set(4, true);
+	assert_eq!(m.get(0, 9), Some(true));
+	assert!(m.get(3, 0).is_none());
+	assert!(m.get(0, 10).is_none());
+
+	assert!(m.column(0).eq([false, false, true]));
+	assert!(m.column(9).rev().eq([false, false, true]));
+	assert_eq!(m.column(4).len(), 3);
+	assert_eq!(m.column(4).nth(1), Some(true));
+	assert_eq!(m.iter_rows().map(BitSlice::count_ones).sum::<usize>(), 3);
+
+	m.or_row(0, 2);
+	assert!(m[0].iter_ones().eq([0, 9]));
+	m.and_row(0, 2);
+	assert!(m[0].iter_ones().eq([0]));
+	m.or_row(1, 1);
+	assert!(m[1].iter_ones().eq([4]));
+	m.xor_row(2, 0);
+	assert!(m[2].not_any());
+	m.swap_rows(1, 2);
+	assert!(m[2].iter_ones().eq([4]));
+	m.xor_row(2, 2);
+	assert!(m[2].not_any());
+
+	let inv = !m.clone();
+	let mut both = m.clone();
+	both &= &inv;
+	assert!(both.as_bitslice().not_any());
+	both ^= &inv;
+	assert_eq!(both, inv);
+}
+
+#[test]
+fn render() {
+	let mut m = BitMatrix::<u8>::new(2, 3);
+	m.set(0, 1, true);
+	assert_eq!(format!("{}", m), "010\n000");
+	assert_eq!(
+		format!("{:?}", m),
+		"BitMatrix { rows: 2, cols: 3, bits: [[0, 1, 0], [0, 0, 0]] }",
+	);
+	assert_eq!(BitMatrix::<u8>::new(0, 4).to_string(), "");
+}
+
+#[test]
+#[should_panic]
+fn shape_mismatch() {
+	let mut a = BitMatrix::<u8>::new(2, 3);
+	a |= &BitMatrix::new(3, 2);
+}
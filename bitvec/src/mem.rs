@@ -56,6 +56,123 @@ pub const fn elts<T>(bits: usize) -> usize {
 	bits / width + (bits % width != 0) as usize
 }
 
+/// Gathers the bits of `value` selected by `mask` into the least significant
+/// bits of the result, preserving their order.
+///
+/// This is the `pext` instruction from x86’s BMI2 extension, which is used
+/// when the `simd` feature is enabled and the processor supports it.
+#[inline]
+pub(crate) fn extract<R>(value: R, mask: R) -> R
+where R: BitRegister {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	if x86::has_bmi2() {
+		return narrow(unsafe { x86::pext(value.as_u64(), mask.as_u64()) });
+	}
+	scalar::extract(value, mask)
+}
+
+/// Scatters the least significant bits of `value`, in order, into the bits
+/// selected by `mask`. Unselected bits of the result are `0`.
+///
+/// This is the `pdep` instruction from x86’s BMI2 extension, which is used
+/// when the `simd` feature is enabled and the processor supports it.
+#[inline]
+pub(crate) fn deposit<R>(value: R, mask: R) -> R
+where R: BitRegister {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	if x86::has_bmi2() {
+		return narrow(unsafe { x86::pdep(value.as_u64(), mask.as_u64()) });
+	}
+	scalar::deposit(value, mask)
+}
+
+/// Narrows the result of a 64-bit instruction back to the register it was
+/// computed for. Both instructions only produce bits within the mask, so this
+/// never discards any.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn narrow<R>(value: u64) -> R
+where R: BitRegister {
+	R::try_from(value).unwrap_or_else(|_| unreachable!("{} overflowed", value))
+}
+
+/// Portable implementations of the register operations, which visit each bit
+/// of the mask in turn.
+mod scalar {
+	use super::BitRegister;
+
+	/// See [`super::extract`].
+	#[inline]
+	pub(super) fn extract<R>(value: R, mut mask: R) -> R
+	where R: BitRegister {
+		let (mut out, mut dest) = (R::ZERO, R::ONE);
+		while mask != R::ZERO {
+			let lowest = mask & mask.wrapping_neg();
+			if value & lowest != R::ZERO {
+				out |= dest;
+			}
+			mask ^= lowest;
+			dest <<= 1u8;
+		}
+		out
+	}
+
+	/// See [`super::deposit`].
+	#[inline]
+	pub(super) fn deposit<R>(value: R, mut mask: R) -> R
+	where R: BitRegister {
+		let (mut out, mut src) = (R::ZERO, R::ONE);
+		while mask != R::ZERO {
+			let lowest = mask & mask.wrapping_neg();
+			if value & src != R::ZERO {
+				out |= lowest;
+			}
+			mask ^= lowest;
+			src <<= 1u8;
+		}
+		out
+	}
+}
+
+/// BMI2 implementations of the register operations.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod x86 {
+	use core::arch::x86_64::{
+		_pdep_u64,
+		_pext_u64,
+	};
+
+	/// Tests whether the processor supports BMI2.
+	#[inline]
+	pub(super) fn has_bmi2() -> bool {
+		#[cfg(target_feature = "bmi2")]
+		{
+			true
+		}
+		#[cfg(all(not(target_feature = "bmi2"), feature = "std"))]
+		{
+			std::is_x86_feature_detected!("bmi2")
+		}
+		#[cfg(all(not(target_feature = "bmi2"), not(feature = "std")))]
+		{
+			false
+		}
+	}
+
+	/// See [`super::extract`].
+	#[inline]
+	#[target_feature(enable = "bmi2")]
+	pub(super) unsafe fn pext(value: u64, mask: u64) -> u64 {
+		_pext_u64(value, mask)
+	}
+
+	/// See [`super::deposit`].
+	#[inline]
+	#[target_feature(enable = "bmi2")]
+	pub(super) unsafe fn pdep(value: u64, mask: u64) -> u64 {
+		_pdep_u64(value, mask)
+	}
+}
+
 /// Tests if a type has alignment equal to its size.
 #[doc(hidden)]
 #[cfg(not(tarpaulin_include))]
@@ -157,4 +274,29 @@ mod tests {
 			assert!(layout_eq::<u64, BitSafeU64>());
 		}
 	}
+
+	#[test]
+	fn extract_deposit() {
+		assert_eq!(extract(0b1001_0110u8, 0b1010_1100), 0b1001);
+		assert_eq!(deposit(0b1001u8, 0b1010_1100), 0b1000_0100);
+		assert_eq!(extract(!0u16, 0), 0);
+		assert_eq!(deposit(!0usize, !0), !0);
+
+		//  The dispatched operations may use BMI2, and must agree with the
+		//  portable ones.
+		for _ in 0 .. 1000 {
+			let (value, mask) = rand::random::<(usize, usize)>();
+			assert_eq!(extract(value, mask), scalar::extract(value, mask));
+			assert_eq!(deposit(value, mask), scalar::deposit(value, mask));
+			assert_eq!(deposit(extract(value, mask), mask), value & mask);
+
+			let (narrow, mask) = (value as u32, mask as u32);
+			assert_eq!(extract(narrow, mask), scalar::extract(narrow, mask));
+			assert_eq!(deposit(narrow, mask), scalar::deposit(narrow, mask));
+
+			let (narrow, mask) = (value as u8, mask as u8);
+			assert_eq!(extract(narrow, mask), scalar::extract(narrow, mask));
+			assert_eq!(deposit(narrow, mask), scalar::deposit(narrow, mask));
+		}
+	}
 }
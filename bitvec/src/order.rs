@@ -1,6 +1,7 @@
 #![doc = include_str!("../doc/order.md")]
 
 use crate::{
+	field::BitField,
 	index::{
 		BitEnd,
 		BitIdx,
@@ -12,6 +13,8 @@ use crate::{
 		bits_of,
 		BitRegister,
 	},
+	store::BitStore,
+	view::BitView,
 };
 
 #[doc = include_str!("../doc/order/BitOrder.md")]
@@ -245,6 +248,14 @@ where O: BitOrder {
 
 	#[cfg(target_pointer_width = "64")]
 	verify_for_type::<u64, O>(verbose);
+
+	verify_field::<u8, O>(verbose);
+	verify_field::<u16, O>(verbose);
+	verify_field::<u32, O>(verbose);
+	verify_field::<usize, O>(verbose);
+
+	#[cfg(target_pointer_width = "64")]
+	verify_field::<u64, O>(verbose);
 }
 
 /// Verification does not access memory, and is both useless and slow in Miri.
@@ -395,6 +406,149 @@ where
 	}
 }
 
+/// Verification does not access memory, and is both useless and slow in Miri.
+#[cfg(miri)]
+fn verify_field<R, O>(_: bool)
+where
+	R: BitRegister + BitStore,
+	O: BitOrder,
+{
+}
+
+/** Verifies `BitField` behavior for an ordering and register type.
+
+`BitField` transfers each element segment of an integer by gathering the bits
+that `O::mask` selects, in order of ascending bit position. This builds the
+memory that a store must produce one bit at a time, and checks both the generic
+`BitField` implementation and the trait dispatch (which is specialized for
+`Lsb0` and `Msb0`) against it.
+
+## Type Parameters
+
+- `R`: The register type being used as bit-slice storage.
+- `O`: The `BitOrder` implementation being tested.
+
+## Parameters
+
+- `verbose`: Controls whether to print each checked region.
+
+## Panics
+
+This panics if any load or store disagrees with the bit-by-bit model.
+**/
+#[cfg(not(miri))]
+fn verify_field<R, O>(verbose: bool)
+where
+	R: BitRegister + BitStore,
+	O: BitOrder,
+{
+	use core::any::type_name;
+
+	let width = bits_of::<R>();
+	//  Alternating bits, so that stores which disturb neighbors are caught.
+	let fill = R::ALL / (R::ONE + R::ONE + R::ONE);
+	let lens = [1, 3, width - 1, width, width + 5, 2 * width, 64];
+
+	for start in 0 .. width {
+		for len in lens.iter().copied().filter(|&len| len <= 64) {
+			let value = 0xA5C3_96E1_7B2D_480Fu64.rotate_left(start as u32)
+				& (!0u64 >> (64 - len));
+			if verbose {
+				#[cfg(feature = "std")]
+				println!(
+					"`BitSlice<{}, {}>` field at {} .. {} holds {:#x}",
+					type_name::<R>(),
+					type_name::<O>(),
+					start,
+					start + len,
+					value,
+				);
+			}
+
+			for big in [false, true] {
+				let mut model = [fill; 9];
+				let mut sig = 0;
+				let first = start / width;
+				let last = (start + len - 1) / width;
+				for elt in 0 .. last - first + 1 {
+					let elt = if big { last - elt } else { first + elt };
+					let base = elt * width;
+					let live =
+						start.max(base) .. (start + len).min(base + width);
+					//  Walk the element’s positions upwards, and place the next
+					//  value bit at whichever live index maps to each.
+					for pos in 0 .. width as u8 {
+						for idx in live.clone() {
+							let bit = unsafe {
+								BitIdx::<R>::new_unchecked((idx - base) as u8)
+							};
+							if O::at::<R>(bit).into_inner() == pos {
+								model
+									.view_bits_mut::<O>()
+									.set(idx, (value >> sig) & 1 == 1);
+								sig += 1;
+							}
+						}
+					}
+				}
+
+				let mut generic = [fill; 9];
+				let mut dispatch = [fill; 9];
+				let region = start .. start + len;
+				let (loaded, dispatched) = if big {
+					generic.view_bits_mut::<O>()[region.clone()]
+						.generic_store_be(value);
+					dispatch.view_bits_mut::<O>()[region.clone()]
+						.store_be(value);
+					(
+						model.view_bits::<O>()[region.clone()]
+							.generic_load_be::<u64>(),
+						model.view_bits::<O>()[region].load_be::<u64>(),
+					)
+				}
+				else {
+					generic.view_bits_mut::<O>()[region.clone()]
+						.generic_store_le(value);
+					dispatch.view_bits_mut::<O>()[region.clone()]
+						.store_le(value);
+					(
+						model.view_bits::<O>()[region.clone()]
+							.generic_load_le::<u64>(),
+						model.view_bits::<O>()[region].load_le::<u64>(),
+					)
+				};
+
+				assert!(
+					generic == model && dispatch == model,
+					"Error when verifying `BitField` for `BitSlice<{}, {}>`: \
+					 storing {:#x} into {} .. {} with {}-endian element order \
+					 did not produce the expected memory",
+					type_name::<R>(),
+					type_name::<O>(),
+					value,
+					start,
+					start + len,
+					if big { "big" } else { "little" },
+				);
+				assert!(
+					loaded == value && dispatched == value,
+					"Error when verifying `BitField` for `BitSlice<{}, {}>`: \
+					 loading {} .. {} with {}-endian element order produced \
+					 {:#x} and {:#x}, not {:#x}",
+					type_name::<R>(),
+					type_name::<O>(),
+					start,
+					start + len,
+					if big { "big" } else { "little" },
+					loaded,
+					dispatched,
+					value,
+				);
+			}
+		}
+	}
+}
+
 /// An ordering that does not provide a contiguous index map or `BitField`
 /// acceleration.
 #[cfg(test)]
@@ -461,6 +615,15 @@ mod tests {
 		fn verify_usize() {
 			verify_for_type::<usize, Lsb0>(cfg!(feature = "verbose"));
 		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_bitfield() {
+			verify_field::<u8, Lsb0>(cfg!(feature = "verbose"));
+			verify_field::<u16, Lsb0>(cfg!(feature = "verbose"));
+			verify_field::<u32, Lsb0>(cfg!(feature = "verbose"));
+			verify_field::<usize, Lsb0>(cfg!(feature = "verbose"));
+		}
 	}
 
 	mod msb0 {
@@ -494,6 +657,15 @@ mod tests {
 		fn verify_usize() {
 			verify_for_type::<usize, Msb0>(cfg!(feature = "verbose"));
 		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_bitfield() {
+			verify_field::<u8, Msb0>(cfg!(feature = "verbose"));
+			verify_field::<u16, Msb0>(cfg!(feature = "verbose"));
+			verify_field::<u32, Msb0>(cfg!(feature = "verbose"));
+			verify_field::<usize, Msb0>(cfg!(feature = "verbose"));
+		}
 	}
 
 	mod hilo {
@@ -527,5 +699,14 @@ mod tests {
 		fn verify_usize() {
 			verify_for_type::<usize, HiLo>(cfg!(feature = "verbose"));
 		}
+
+		#[test]
+		#[cfg(not(tarpaulin))]
+		fn verify_bitfield() {
+			verify_field::<u8, HiLo>(cfg!(feature = "verbose"));
+			verify_field::<u16, HiLo>(cfg!(feature = "verbose"));
+			verify_field::<u32, HiLo>(cfg!(feature = "verbose"));
+			verify_field::<usize, HiLo>(cfg!(feature = "verbose"));
+		}
 	}
 }
@@ -0,0 +1,415 @@
+#![doc = include_str!("../doc/rank.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+};
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+mod tests;
+
+/// The number of bits summarized by each entry in the block directory.
+const BLOCK_BITS: usize = 512;
+
+/// The number of blocks summarized by each entry in the superblock directory.
+///
+/// This is chosen so that the number of `1` bits in a superblock always fits in
+/// a `u16`.
+const SUPER_BLOCKS: usize = 8;
+
+/// The number of bits summarized by each entry in the superblock directory.
+const SUPER_BITS: usize = BLOCK_BITS * SUPER_BLOCKS;
+
+#[doc = include_str!("../doc/rank/RankSelect.md")]
+pub struct RankSelect<B, T = usize, O = Lsb0>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The indexed bits.
+	bits:   B,
+	/// The number of `1` bits before the start of each superblock.
+	supers: Vec<usize>,
+	/// The number of `1` bits between the start of each block’s superblock and
+	/// the start of the block.
+	blocks: Vec<u16>,
+	/// The number of `1` bits in the whole bit-slice.
+	ones:   usize,
+	/// Marks the bit-slice type parameters.
+	_typ:   PhantomData<fn(&BitSlice<T, O>)>,
+}
+
+/// Constructors and accessors.
+impl<B, T, O> RankSelect<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Builds a rank/select directory over a bit-slice.
+	///
+	/// This counts every bit in the bit-slice once, and so is O(n).
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bv = bitvec![0, 1, 0, 1];
+	/// let index = RankSelect::new(&bv[1 ..]);
+	/// assert_eq!(index.count_ones(), 2);
+	///
+	/// let index = RankSelect::new(bv);
+	/// assert_eq!(index.count_zeros(), 2);
+	/// ```
+	#[inline]
+	pub fn new(bits: B) -> Self {
+		let slice = bits.borrow();
+		let blocks_len = (slice.len() + BLOCK_BITS - 1) / BLOCK_BITS;
+		let supers_len = (blocks_len + SUPER_BLOCKS - 1) / SUPER_BLOCKS;
+		let mut supers = Vec::with_capacity(supers_len);
+		let mut blocks = Vec::with_capacity(blocks_len);
+
+		let mut ones = 0;
+		let mut rel = 0u16;
+		for (idx, block) in slice.chunks(BLOCK_BITS).enumerate() {
+			if idx % SUPER_BLOCKS == 0 {
+				supers.push(ones);
+				rel = 0;
+			}
+			blocks.push(rel);
+			let count = block.count_ones();
+			rel += count as u16;
+			ones += count;
+		}
+
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+			_typ: PhantomData,
+		}
+	}
+
+	/// Views the indexed bits.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.borrow()
+	}
+
+	/// Discards the directory and returns the indexed bits.
+	#[inline]
+	pub fn into_inner(self) -> B {
+		self.bits
+	}
+
+	/// Gets the number of bits in the indexed bit-slice.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	/// Tests if the indexed bit-slice is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.as_bitslice().is_empty()
+	}
+
+	/// Counts the `1` bits in the indexed bit-slice.
+	///
+	/// This is computed when the directory is built, and so is O(1).
+	#[inline]
+	pub fn count_ones(&self) -> usize {
+		self.ones
+	}
+
+	/// Counts the `0` bits in the indexed bit-slice.
+	///
+	/// This is computed when the directory is built, and so is O(1).
+	#[inline]
+	pub fn count_zeros(&self) -> usize {
+		self.len() - self.ones
+	}
+}
+
+/// Queries.
+impl<B, T, O> RankSelect<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the `1` bits before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_ones()`, but only counts the
+	/// bits in one block rather than the whole prefix.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The number of bits, counted from the front of the bit-slice,
+	///   in which to count `1` bits. This may be equal to `self.len()`.
+	///
+	/// ## Returns
+	///
+	/// The number of `1` bits in `self[.. index]`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank1(0), 0);
+	/// assert_eq!(index.rank1(3), 2);
+	/// assert_eq!(index.rank1(5), 3);
+	/// ```
+	#[inline]
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.len();
+		assert!(
+			index <= len,
+			"rank index {} out of range for length {}",
+			index,
+			len,
+		);
+		if index == len {
+			return self.ones;
+		}
+		let block = index / BLOCK_BITS;
+		let start = block * BLOCK_BITS;
+		let tail = unsafe { self.as_bitslice().get_unchecked(start .. index) };
+		self.supers[block / SUPER_BLOCKS]
+			+ self.blocks[block] as usize
+			+ tail.count_ones()
+	}
+
+	/// Counts the `0` bits before an index.
+	///
+	/// This is equivalent to `bits[.. index].count_zeros()`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.rank0(2), 1);
+	/// assert_eq!(index.rank0(5), 2);
+	/// ```
+	#[inline]
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of the `1` bit with a given rank.
+	///
+	/// This is equivalent to `bits.iter_ones().nth(rank)`, but only scans the
+	/// bits in one block rather than the whole prefix.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of `1` bits that precede the sought bit. `0` finds
+	///   the first `1` bit.
+	///
+	/// ## Returns
+	///
+	/// The index of the sought bit, if the bit-slice has more than `rank` bits
+	/// set to `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select1(0), Some(1));
+	/// assert_eq!(index.select1(1), Some(4));
+	/// assert!(index.select1(2).is_none());
+	/// ```
+	#[inline]
+	pub fn select1(&self, rank: usize) -> Option<usize> {
+		if rank >= self.ones {
+			return None;
+		}
+		let sup = partition(self.supers.len(), |s| self.supers[s] <= rank) - 1;
+		let rank = rank - self.supers[sup];
+		let (first, blocks) = self.super_blocks(sup);
+		let block =
+			first + partition(blocks.len(), |b| blocks[b] as usize <= rank) - 1;
+		let rank = rank - self.blocks[block] as usize;
+		let start = block * BLOCK_BITS;
+		self.block(block)
+			.iter_ones()
+			.nth(rank)
+			.map(|idx| start + idx)
+	}
+
+	/// Finds the index of the `0` bit with a given rank.
+	///
+	/// This is equivalent to `bits.iter_zeros().nth(rank)`, but only scans the
+	/// bits in one block rather than the whole prefix.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `rank`: The number of `0` bits that precede the sought bit. `0` finds
+	///   the first `0` bit.
+	///
+	/// ## Returns
+	///
+	/// The index of the sought bit, if the bit-slice has more than `rank` bits
+	/// set to `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::rank::RankSelect;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let index = RankSelect::new(bits);
+	/// assert_eq!(index.select0(0), Some(0));
+	/// assert_eq!(index.select0(2), Some(3));
+	/// assert!(index.select0(3).is_none());
+	/// ```
+	#[inline]
+	pub fn select0(&self, rank: usize) -> Option<usize> {
+		if rank >= self.count_zeros() {
+			return None;
+		}
+		let sup = partition(self.supers.len(), |s| {
+			s * SUPER_BITS - self.supers[s] <= rank
+		}) - 1;
+		let rank = rank - (sup * SUPER_BITS - self.supers[sup]);
+		let (first, blocks) = self.super_blocks(sup);
+		let block = first
+			+ partition(blocks.len(), |b| {
+				b * BLOCK_BITS - blocks[b] as usize <= rank
+			}) - 1;
+		let rank =
+			rank - ((block - first) * BLOCK_BITS - self.blocks[block] as usize);
+		let start = block * BLOCK_BITS;
+		self.block(block)
+			.iter_zeros()
+			.nth(rank)
+			.map(|idx| start + idx)
+	}
+
+	/// Gets the index of a superblock’s first block, and the block directory
+	/// entries of that superblock.
+	#[inline]
+	fn super_blocks(&self, sup: usize) -> (usize, &[u16]) {
+		let first = sup * SUPER_BLOCKS;
+		let last = (first + SUPER_BLOCKS).min(self.blocks.len());
+		(first, &self.blocks[first .. last])
+	}
+
+	/// Views the bits summarized by one block directory entry.
+	#[inline]
+	fn block(&self, block: usize) -> &BitSlice<T, O> {
+		let bits = self.as_bitslice();
+		let start = block * BLOCK_BITS;
+		let end = (start + BLOCK_BITS).min(bits.len());
+		unsafe { bits.get_unchecked(start .. end) }
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B, T, O> Clone for RankSelect<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>> + Clone,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits:   self.bits.clone(),
+			supers: self.supers.clone(),
+			blocks: self.blocks.clone(),
+			ones:   self.ones,
+			_typ:   PhantomData,
+		}
+	}
+}
+
+impl<B, T, O> Debug for RankSelect<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("RankSelect")
+			.field("bits", &self.as_bitslice())
+			.field("ones", &self.ones)
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B, T, O> AsRef<BitSlice<T, O>> for RankSelect<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<T, O> {
+		self.as_bitslice()
+	}
+}
+
+/// Finds the number of leading indices in `0 .. len` that satisfy a predicate.
+///
+/// The predicate must be `true` for some prefix of the range and `false` for
+/// the rest of it.
+#[inline]
+fn partition(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+	let (mut lo, mut hi) = (0, len);
+	while lo < hi {
+		let mid = lo + (hi - lo) / 2;
+		if pred(mid) {
+			lo = mid + 1;
+		}
+		else {
+			hi = mid;
+		}
+	}
+	lo
+}
@@ -0,0 +1,65 @@
+//! Unit tests for rank/select indices.
+
+#![cfg(test)]
+
+use rand::random;
+
+use super::RankSelect;
+use crate::prelude::*;
+
+/// Checks every query on an index against the equivalent linear scans.
+fn check<T, O>(bits: &BitSlice<T, O>)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let index = RankSelect::new(bits);
+	assert_eq!(index.count_ones(), bits.count_ones());
+	assert_eq!(index.count_zeros(), bits.count_zeros());
+
+	for idx in (0 ..= bits.len()).step_by(7).chain([bits.len()]) {
+		assert_eq!(index.rank1(idx), bits[.. idx].count_ones(), "{}", idx);
+		assert_eq!(index.rank0(idx), bits[.. idx].count_zeros(), "{}", idx);
+	}
+	for (rank, idx) in bits.iter_ones().enumerate() {
+		assert_eq!(index.select1(rank), Some(idx));
+	}
+	for (rank, idx) in bits.iter_zeros().enumerate() {
+		assert_eq!(index.select0(rank), Some(idx));
+	}
+	assert!(index.select1(bits.count_ones()).is_none());
+	assert!(index.select0(bits.count_zeros()).is_none());
+}
+
+#[test]
+fn random_bits() {
+	let mut bv = BitVec::<u16, Msb0>::with_capacity(20_000);
+	while bv.len() < 20_000 {
+		let density = random::<u8>();
+		let run = random::<u16>() as usize % 3000;
+		bv.extend((0 .. run).map(|_| random::<u8>() < density));
+	}
+	check(bv.as_bitslice());
+	check(&bv[3 .. 19_993]);
+
+	let bv = bv.iter().by_vals().collect::<BitVec<u64, Lsb0>>();
+	check(&bv[61 ..]);
+}
+
+#[test]
+fn uniform_bits() {
+	check(BitSlice::<u8, Lsb0>::empty());
+	check(&bitvec![u32, Lsb0; 0; 9000][5 ..]);
+	check(&bitvec![u32, Msb0; 1; 9000][.. 8193]);
+
+	let index = RankSelect::new(bitvec![1; 5000]);
+	assert_eq!(index.rank1(5000), 5000);
+	assert!(index.select0(0).is_none());
+	assert_eq!(index.into_inner().len(), 5000);
+}
+
+#[test]
+#[should_panic = "rank index 6 out of range for length 5"]
+fn rank_out_of_range() {
+	RankSelect::new(bits![0; 5]).rank1(6);
+}
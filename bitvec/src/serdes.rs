@@ -2,7 +2,9 @@
 #![doc = include_str!("../doc/serdes.md")]
 
 mod array;
+pub mod compact;
 mod slice;
+pub mod strict;
 mod utils;
 
 use core::fmt::{
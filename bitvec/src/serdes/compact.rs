@@ -0,0 +1,759 @@
+#![doc = include_str!("../../doc/serdes/compact.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::{
+	fmt::{
+		self,
+		Display,
+		Formatter,
+		Write,
+	},
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+use serde::{
+	de::{
+		Deserialize,
+		Deserializer,
+		Error,
+		SeqAccess,
+		Unexpected,
+		Visitor,
+	},
+	ser::{
+		Serialize,
+		SerializeTuple,
+		Serializer,
+	},
+};
+
+use crate::{
+	array::BitArray,
+	boxed::BitBox,
+	mem::elts,
+	order::{
+		BitOrder,
+		Msb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+	view::BitViewSized,
+};
+
+/// Serializes a bit-sequence in its compact form.
+///
+/// Human-readable formats receive the [`hex`] string; all others receive the
+/// [`bytes`] tuple. This is suitable for use as
+/// `#[serde(with = "bitvec::serdes::compact")]`.
+#[inline]
+pub fn serialize<B, T, O, S>(bits: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+	B: ?Sized + AsRef<BitSlice<T, O>>,
+	T: BitStore,
+	O: BitOrder,
+	S: Serializer,
+{
+	encode(bits.as_ref(), Format::Auto, serializer)
+}
+
+/// Deserializes a bit-sequence from any of its compact forms.
+///
+/// Human-readable formats are asked for a string, which may be either a
+/// [`binary`] or a [`hex`] string; all others are asked for the [`bytes`]
+/// tuple. The result may be a `BitVec`, `BitBox`, or `BitArray` of any type
+/// parameters.
+#[inline]
+pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+where
+	D: Deserializer<'de>,
+	Compact<B>: Deserialize<'de>,
+{
+	Compact::deserialize(deserializer).map(Compact::into_inner)
+}
+
+pub mod binary {
+	//! Transports a bit-sequence as a string of `0` and `1` characters.
+	//!
+	//! This is suitable for use as
+	//! `#[serde(with = "bitvec::serdes::compact::binary")]`.
+
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serializer,
+	};
+
+	use super::{
+		Binary,
+		Format,
+	};
+	use crate::{
+		order::BitOrder,
+		slice::BitSlice,
+		store::BitStore,
+	};
+
+	/// Serializes a bit-sequence as a string of `0` and `1` characters.
+	#[inline]
+	pub fn serialize<B, T, O, S>(
+		bits: &B,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		B: ?Sized + AsRef<BitSlice<T, O>>,
+		T: BitStore,
+		O: BitOrder,
+		S: Serializer,
+	{
+		super::encode(bits.as_ref(), Format::Binary, serializer)
+	}
+
+	/// Deserializes a bit-sequence from a string.
+	#[inline]
+	pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+	where
+		D: Deserializer<'de>,
+		Binary<B>: Deserialize<'de>,
+	{
+		Binary::deserialize(deserializer).map(Binary::into_inner)
+	}
+}
+
+pub mod hex {
+	//! Transports a bit-sequence as a string holding its decimal length, a
+	//! colon, and its bytes in hexadecimal.
+	//!
+	//! This is suitable for use as
+	//! `#[serde(with = "bitvec::serdes::compact::hex")]`.
+
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serializer,
+	};
+
+	use super::{
+		Format,
+		Hex,
+	};
+	use crate::{
+		order::BitOrder,
+		slice::BitSlice,
+		store::BitStore,
+	};
+
+	/// Serializes a bit-sequence as a length-prefixed hexadecimal string.
+	#[inline]
+	pub fn serialize<B, T, O, S>(
+		bits: &B,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		B: ?Sized + AsRef<BitSlice<T, O>>,
+		T: BitStore,
+		O: BitOrder,
+		S: Serializer,
+	{
+		super::encode(bits.as_ref(), Format::Hex, serializer)
+	}
+
+	/// Deserializes a bit-sequence from a string.
+	#[inline]
+	pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+	where
+		D: Deserializer<'de>,
+		Hex<B>: Deserialize<'de>,
+	{
+		Hex::deserialize(deserializer).map(Hex::into_inner)
+	}
+}
+
+pub mod bytes {
+	//! Transports a bit-sequence as a tuple of its length and a byte string.
+	//!
+	//! The byte string is emitted with [`Serializer::serialize_bytes`], which
+	//! binary formats usually store without any per-byte overhead. This is
+	//! suitable for use as `#[serde(with = "bitvec::serdes::compact::bytes")]`.
+
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serializer,
+	};
+
+	use super::{
+		Bytes,
+		Format,
+	};
+	use crate::{
+		order::BitOrder,
+		slice::BitSlice,
+		store::BitStore,
+	};
+
+	/// Serializes a bit-sequence as a `(u64, bytes)` tuple.
+	#[inline]
+	pub fn serialize<B, T, O, S>(
+		bits: &B,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		B: ?Sized + AsRef<BitSlice<T, O>>,
+		T: BitStore,
+		O: BitOrder,
+		S: Serializer,
+	{
+		super::encode(bits.as_ref(), Format::Bytes, serializer)
+	}
+
+	/// Deserializes a bit-sequence from a `(u64, bytes)` tuple.
+	#[inline]
+	pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+	where
+		D: Deserializer<'de>,
+		Bytes<B>: Deserialize<'de>,
+	{
+		Bytes::deserialize(deserializer).map(Bytes::into_inner)
+	}
+}
+
+/// Generates the wrapper types, one for each transport format.
+macro_rules! wrappers {
+	($($(#[$attr:meta])* $name:ident => $format:ident),+ $(,)?) => { $(
+		$(#[$attr])*
+		#[repr(transparent)]
+		#[derive(
+			Clone,
+			Copy,
+			Debug,
+			Default,
+			Eq,
+			Hash,
+			Ord,
+			PartialEq,
+			PartialOrd,
+		)]
+		pub struct $name<B>(pub B);
+
+		impl<B> $name<B> {
+			/// Removes the wrapper, returning the bit-sequence.
+			#[inline]
+			pub fn into_inner(self) -> B {
+				self.0
+			}
+		}
+
+		#[cfg(not(tarpaulin_include))]
+		impl<B> From<B> for $name<B> {
+			#[inline]
+			fn from(bits: B) -> Self {
+				Self(bits)
+			}
+		}
+
+		#[cfg(not(tarpaulin_include))]
+		impl<B> Deref for $name<B> {
+			type Target = B;
+
+			#[inline]
+			fn deref(&self) -> &Self::Target {
+				&self.0
+			}
+		}
+
+		#[cfg(not(tarpaulin_include))]
+		impl<B> DerefMut for $name<B> {
+			#[inline]
+			fn deref_mut(&mut self) -> &mut Self::Target {
+				&mut self.0
+			}
+		}
+
+		impl<B, T, O> Serialize for $name<B>
+		where
+			B: Deref<Target = BitSlice<T, O>>,
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn serialize<S>(&self, serializer: S) -> super::Result<S>
+			where S: Serializer {
+				encode(&self.0, Format::$format, serializer)
+			}
+		}
+
+		impl<'de, T, O> Deserialize<'de> for $name<BitVec<T, O>>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: Deserializer<'de> {
+				decode(deserializer, Format::$format)
+					.map(|bits| Self(convert(&bits)))
+			}
+		}
+
+		impl<'de, T, O> Deserialize<'de> for $name<BitBox<T, O>>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: Deserializer<'de> {
+				decode(deserializer, Format::$format)
+					.map(|bits| Self(convert(&bits).into_boxed_bitslice()))
+			}
+		}
+
+		impl<'de, A, O> Deserialize<'de> for $name<BitArray<A, O>>
+		where
+			A: BitViewSized,
+			O: BitOrder,
+		{
+			#[inline]
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: Deserializer<'de> {
+				let bits = decode(deserializer, Format::$format)?;
+				let mut out = BitArray::<A, O>::ZERO;
+				if bits.len() != out.len() {
+					return Err(D::Error::invalid_length(
+						bits.len(),
+						&ArrayLen(out.len()),
+					));
+				}
+				out.clone_from_bitslice(&bits);
+				Ok(Self(out))
+			}
+		}
+	)+ };
+}
+
+wrappers! {
+	/// Transports a bit-sequence in its compact form.
+	///
+	/// This uses the [`hex`] string in human-readable formats and the
+	/// [`bytes`] tuple in all others. It can deserialize from any of the compact
+	/// forms the transport format permits.
+	Compact => Auto,
+
+	/// Transports a bit-sequence as a string of `0` and `1` characters.
+	///
+	/// See the [`binary`] module.
+	Binary => Binary,
+
+	/// Transports a bit-sequence as a length-prefixed hexadecimal string.
+	///
+	/// See the [`hex`] module.
+	Hex => Hex,
+
+	/// Transports a bit-sequence as a tuple of its length and a byte string.
+	///
+	/// See the [`bytes`] module.
+	Bytes => Bytes,
+}
+
+/// The transport formats that a compact bit-sequence can take.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Format {
+	/// `Hex` in human-readable formats, and `Bytes` in all others.
+	Auto,
+	/// A string of `0` and `1` characters.
+	Binary,
+	/// A string of the decimal bit-count, a colon, and hexadecimal bytes.
+	Hex,
+	/// A tuple of the bit-count and a byte string.
+	Bytes,
+}
+
+/// Serializes a bit-slice in one of the compact formats.
+fn encode<T, O, S>(
+	bits: &BitSlice<T, O>,
+	format: Format,
+	serializer: S,
+) -> super::Result<S>
+where
+	T: BitStore,
+	O: BitOrder,
+	S: Serializer,
+{
+	match format {
+		| Format::Auto if serializer.is_human_readable() => {
+			encode(bits, Format::Hex, serializer)
+		},
+		| Format::Binary => serializer.collect_str(&BinaryStr(bits)),
+		| Format::Hex => {
+			serializer.collect_str(&HexStr(bits.len(), &pack(bits)))
+		},
+		| Format::Auto | Format::Bytes => {
+			let bytes = pack(bits);
+			let mut tuple = serializer.serialize_tuple(2)?;
+			tuple.serialize_element(&(bits.len() as u64))?;
+			tuple.serialize_element(&ByteStr(&bytes))?;
+			tuple.end()
+		},
+	}
+}
+
+/// Deserializes a bit-sequence in one of the compact formats.
+///
+/// The result is always in the canonical `<u8, Msb0>` layout, and is then
+/// converted into the caller’s requested type.
+fn decode<'de, D>(
+	deserializer: D,
+	format: Format,
+) -> Result<BitVec<u8, Msb0>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	match format {
+		| Format::Auto if deserializer.is_human_readable() => {
+			deserializer.deserialize_str(CompactVisitor)
+		},
+		| Format::Binary | Format::Hex => {
+			deserializer.deserialize_str(CompactVisitor)
+		},
+		| Format::Auto | Format::Bytes => {
+			deserializer.deserialize_tuple(2, CompactVisitor)
+		},
+	}
+}
+
+/// Copies a bit-slice into bytes, with bit `0` in the most significant bit of
+/// byte `0`, and any trailing bits of the last byte cleared.
+fn pack<T, O>(bits: &BitSlice<T, O>) -> Vec<u8>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut out = BitVec::<u8, Msb0>::with_capacity(bits.len());
+	out.extend_from_bitslice(bits);
+	out.set_uninitialized(false);
+	out.into_vec()
+}
+
+/// Copies a canonical bit-vector into a bit-vector of any type parameters.
+fn convert<T, O>(bits: &BitSlice<u8, Msb0>) -> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let mut out = BitVec::with_capacity(bits.len());
+	out.extend_from_bitslice(bits);
+	out
+}
+
+/// Renders each bit of a bit-slice as `0` or `1`, for use with
+/// [`Serializer::collect_str`].
+struct BinaryStr<'a, T, O>(&'a BitSlice<T, O>)
+where
+	T: BitStore,
+	O: BitOrder;
+
+impl<T, O> Display for BinaryStr<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		self.0
+			.iter()
+			.by_vals()
+			.try_for_each(|bit| fmt.write_char(if bit { '1' } else { '0' }))
+	}
+}
+
+/// Renders a bit-count, a colon, and each byte as two hexadecimal digits, for
+/// use with [`Serializer::collect_str`].
+struct HexStr<'a>(usize, &'a [u8]);
+
+impl Display for HexStr<'_> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "{}:", self.0)?;
+		self.1
+			.iter()
+			.try_for_each(|byte| write!(fmt, "{:02x}", byte))
+	}
+}
+
+/// Serializes a byte slice with [`Serializer::serialize_bytes`], rather than
+/// as a sequence of integers.
+struct ByteStr<'a>(&'a [u8]);
+
+impl Serialize for ByteStr<'_> {
+	fn serialize<S>(&self, serializer: S) -> super::Result<S>
+	where S: Serializer {
+		serializer.serialize_bytes(self.0)
+	}
+}
+
+/// Deserializes an owned byte buffer from either a byte string or a sequence
+/// of integers.
+struct ByteBuf(Vec<u8>);
+
+impl<'de> Deserialize<'de> for ByteBuf {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_byte_buf(ByteBufVisitor)
+	}
+}
+
+/// Assists in deserialization of a `ByteBuf`.
+struct ByteBufVisitor;
+
+impl<'de> Visitor<'de> for ByteBufVisitor {
+	type Value = ByteBuf;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("a byte string")
+	}
+
+	fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(ByteBuf(value.to_vec()))
+	}
+
+	fn visit_byte_buf<E>(self, value: Vec<u8>) -> Result<Self::Value, E>
+	where E: Error {
+		Ok(ByteBuf(value))
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(byte) = seq.next_element()? {
+			out.push(byte);
+		}
+		Ok(ByteBuf(out))
+	}
+}
+
+/// Assists in deserialization of all compact formats.
+struct CompactVisitor;
+
+impl CompactVisitor {
+	/// Parses a string of `0` and `1` characters.
+	fn parse_binary<E>(value: &str) -> Result<BitVec<u8, Msb0>, E>
+	where E: Error {
+		let mut out = BitVec::with_capacity(value.len());
+		for ch in value.chars() {
+			match ch {
+				| '0' => out.push(false),
+				| '1' => out.push(true),
+				| _ => {
+					return Err(E::invalid_value(
+						Unexpected::Char(ch),
+						&"`0` or `1`",
+					));
+				},
+			}
+		}
+		Ok(out)
+	}
+
+	/// Parses a bit-count and a string of hexadecimal bytes.
+	fn parse_hex<E>(len: &str, digits: &str) -> Result<BitVec<u8, Msb0>, E>
+	where E: Error {
+		let len = len.parse::<u64>().map_err(|_| {
+			E::invalid_value(Unexpected::Str(len), &"a decimal bit-count")
+		})?;
+		if digits.len() % 2 != 0 {
+			return Err(E::invalid_length(
+				digits.len(),
+				&"two hexadecimal digits per byte",
+			));
+		}
+		let bytes = digits
+			.as_bytes()
+			.chunks(2)
+			.map(|pair| {
+				let nibble = |digit: u8| (digit as char).to_digit(16);
+				match (nibble(pair[0]), nibble(pair[1])) {
+					| (Some(hi), Some(lo)) => Ok((hi << 4 | lo) as u8),
+					| _ => Err(E::invalid_value(
+						Unexpected::Str(digits),
+						&"hexadecimal digits",
+					)),
+				}
+			})
+			.collect::<Result<Vec<u8>, E>>()?;
+		Self::assemble(len, bytes)
+	}
+
+	/// Builds a bit-vector from its length and its packed bytes.
+	fn assemble<E>(len: u64, bytes: Vec<u8>) -> Result<BitVec<u8, Msb0>, E>
+	where E: Error {
+		let len = usize::try_from(len)
+			.ok()
+			.filter(|&len| bytes.len() == elts::<u8>(len))
+			.ok_or_else(|| {
+				E::invalid_length(bytes.len(), &"one byte for every eight bits")
+			})?;
+		let mut out = BitVec::try_from_vec(bytes).map_err(|_| {
+			E::invalid_length(len, &"a bit-count that fits in a `BitVec`")
+		})?;
+		out.truncate(len);
+		Ok(out)
+	}
+}
+
+impl<'de> Visitor<'de> for CompactVisitor {
+	type Value = BitVec<u8, Msb0>;
+
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str(
+			"a string of `0`s and `1`s, a `len:hex` string, or a `(len, \
+			 bytes)` tuple",
+		)
+	}
+
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where E: Error {
+		match value.split_once(':') {
+			| Some((len, digits)) => Self::parse_hex(len, digits),
+			| None => Self::parse_binary(value),
+		}
+	}
+
+	fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		let len = seq
+			.next_element::<u64>()?
+			.ok_or_else(|| <V::Error>::invalid_length(0, &self))?;
+		let ByteBuf(bytes) = seq
+			.next_element()?
+			.ok_or_else(|| <V::Error>::invalid_length(1, &self))?;
+		Self::assemble(len, bytes)
+	}
+}
+
+/// Describes the length a bit-array requires, for error reporting.
+struct ArrayLen(usize);
+
+impl serde::de::Expected for ArrayLen {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "exactly {} bits", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use core::cell::Cell;
+
+	use serde_test::{
+		assert_de_tokens,
+		assert_de_tokens_error,
+		assert_tokens,
+		Configure,
+		Token,
+	};
+
+	use super::*;
+	use crate::prelude::*;
+
+	#[test]
+	fn tokens() {
+		let bits = bitvec![u16, Lsb0; 1, 0, 1, 1, 0, 0, 0, 0, 1, 1];
+
+		assert_tokens(&Binary(bits.clone()), &[Token::Str("1011000011")]);
+		assert_tokens(&Hex(bits.clone()), &[Token::Str("10:b0c0")]);
+		assert_tokens(&Bytes(bits.clone()), &[
+			Token::Tuple { len: 2 },
+			Token::U64(10),
+			Token::Bytes(&[0xB0, 0xC0]),
+			Token::TupleEnd,
+		]);
+		assert_tokens(&Compact(bits.clone()).readable(), &[Token::Str(
+			"10:b0c0",
+		)]);
+		assert_tokens(&Compact(bits.clone()).compact(), &[
+			Token::Tuple { len: 2 },
+			Token::U64(10),
+			Token::Bytes(&[0xB0, 0xC0]),
+			Token::TupleEnd,
+		]);
+
+		//  Strings are accepted in either form, and bytes as a sequence.
+		assert_de_tokens(&Hex(bits.clone()), &[Token::Str("1011000011")]);
+		assert_de_tokens(&Binary(bits.clone()), &[Token::Str("10:B0C0")]);
+		assert_de_tokens(&Bytes(bits.clone()), &[
+			Token::Tuple { len: 2 },
+			Token::U64(10),
+			Token::Seq { len: Some(2) },
+			Token::U8(0xB0),
+			Token::U8(0xC0),
+			Token::SeqEnd,
+			Token::TupleEnd,
+		]);
+
+		let empty = BitVec::<u8, Msb0>::new();
+		assert_tokens(&Binary(empty.clone()), &[Token::Str("")]);
+		assert_tokens(&Hex(empty), &[Token::Str("0:")]);
+	}
+
+	#[test]
+	fn any_storage() {
+		let src = bitvec![u32, Msb0; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+		let text = serde_json::to_string(&Compact(&*src)).unwrap();
+		assert_eq!(text, "\"9:6980\"");
+
+		let out: Compact<BitVec<Cell<u8>, Lsb0>> =
+			serde_json::from_str(&text).unwrap();
+		assert_eq!(*out, src);
+		let out: Binary<BitBox<u64, LocalBits>> =
+			serde_json::from_str(&text).unwrap();
+		assert_eq!(*out, src);
+
+		let encoded = bincode::serialize(&Compact(src.clone())).unwrap();
+		assert_eq!(encoded.len(), 8 + 8 + 2);
+		let out: Compact<BitVec<u16, Lsb0>> =
+			bincode::deserialize(&encoded).unwrap();
+		assert_eq!(*out, src);
+
+		let arr: Compact<BitArray<u8, Msb0>> =
+			serde_json::from_str("\"8:a5\"").unwrap();
+		assert_eq!(
+			arr.into_inner(),
+			bitarr![const u8, Msb0; 1, 0, 1, 0, 0, 1, 0, 1]
+		);
+		assert!(serde_json::from_str::<Compact<BitArray<u8, Msb0>>>(
+			"\"9:a580\""
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn errors() {
+		assert_de_tokens_error::<Binary<BitVec>>(
+			&[Token::Str("0120")],
+			"invalid value: character `2`, expected `0` or `1`",
+		);
+		assert_de_tokens_error::<Hex<BitVec>>(
+			&[Token::Str("x:00")],
+			"invalid value: string \"x\", expected a decimal bit-count",
+		);
+		assert_de_tokens_error::<Hex<BitVec>>(
+			&[Token::Str("4:0g")],
+			"invalid value: string \"0g\", expected hexadecimal digits",
+		);
+		assert_de_tokens_error::<Hex<BitVec>>(
+			&[Token::Str("4:000")],
+			"invalid length 3, expected two hexadecimal digits per byte",
+		);
+		assert_de_tokens_error::<Hex<BitVec>>(
+			&[Token::Str("9:00")],
+			"invalid length 1, expected one byte for every eight bits",
+		);
+		assert_de_tokens_error::<Binary<BitArray<u8>>>(
+			&[Token::Str("101")],
+			"invalid length 3, expected exactly 8 bits",
+		);
+	}
+}
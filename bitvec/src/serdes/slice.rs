@@ -14,10 +14,12 @@ use core::{
 use serde::{
 	de::{
 		Deserialize,
+		DeserializeSeed,
 		Deserializer,
 		Error,
 		MapAccess,
 		SeqAccess,
+		Unexpected,
 		Visitor,
 	},
 	ser::{
@@ -28,6 +30,8 @@ use serde::{
 };
 use wyz::comu::Const;
 
+#[cfg(feature = "alloc")]
+use super::utils::RawIdx;
 use super::{
 	utils::TypeName,
 	Field,
@@ -36,6 +40,10 @@ use super::{
 #[cfg(feature = "alloc")]
 use crate::{
 	boxed::BitBox,
+	order::{
+		Lsb0,
+		Msb0,
+	},
 	vec::BitVec,
 };
 use crate::{
@@ -147,22 +155,40 @@ where
 		deserializer.deserialize_struct(
 			"BitSeq",
 			FIELDS,
-			BitSeqVisitor::<T, O, Vec<T>, Self, _>::new(
-				|vec, head, bits| unsafe {
-					let addr = vec.as_ptr().into_address();
-					let mut bv = BitVec::try_from_vec(vec).map_err(|_| {
-						BitSpan::<Const, T, O>::new(addr, head, bits)
-							.unwrap_err()
-					})?;
-					bv.set_head(head);
-					bv.set_len(bits);
-					Ok(bv)
-				},
-			),
+			RepackVisitor::<T, O>::new(),
 		)
 	}
 }
 
+/// Deserializes a `BitVec` only from a stream produced by the same `<T, O>`
+/// type parameters.
+#[cfg(feature = "alloc")]
+pub(super) fn deserialize_strict<'de, D, T, O>(
+	deserializer: D,
+) -> Result<BitVec<T, O>, D::Error>
+where
+	D: Deserializer<'de>,
+	T: BitStore,
+	O: BitOrder,
+	Vec<T>: Deserialize<'de>,
+{
+	deserializer.deserialize_struct(
+		"BitSeq",
+		FIELDS,
+		BitSeqVisitor::<T, O, Vec<T>, BitVec<T, O>, _>::new(
+			|vec, head, bits| unsafe {
+				let addr = vec.as_ptr().into_address();
+				let mut bv = BitVec::try_from_vec(vec).map_err(|_| {
+					BitSpan::<Const, T, O>::new(addr, head, bits).unwrap_err()
+				})?;
+				bv.set_head(head);
+				bv.set_len(bits);
+				Ok(bv)
+			},
+		),
+	)
+}
+
 /// Assists in deserialization of a dynamic `BitSeq`.
 struct BitSeqVisitor<T, O, In, Out, Func>
 where
@@ -293,6 +319,399 @@ where
 	}
 }
 
+/// The ordering named in a `BitSeq` stream.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum WireOrder {
+	/// The stream names the destination ordering.
+	Target,
+	/// The stream names `Lsb0`, which is not the destination ordering.
+	Lsb0,
+	/// The stream names `Msb0`, which is not the destination ordering.
+	Msb0,
+}
+
+/// Deserializes an ordering name into a `WireOrder`.
+#[cfg(feature = "alloc")]
+struct WireOrderSeed<O>(PhantomData<O>)
+where O: BitOrder;
+
+#[cfg(feature = "alloc")]
+impl<'de, O> DeserializeSeed<'de> for WireOrderSeed<O>
+where O: BitOrder
+{
+	type Value = WireOrder;
+
+	#[inline]
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_str(self)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, O> Visitor<'de> for WireOrderSeed<O>
+where O: BitOrder
+{
+	type Value = WireOrder;
+
+	#[inline]
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"one of the strings {:?}, {:?}, or {:?}",
+			any::type_name::<O>(),
+			any::type_name::<Lsb0>(),
+			any::type_name::<Msb0>(),
+		)
+	}
+
+	#[inline]
+	fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+	where E: Error {
+		if value == any::type_name::<O>() {
+			Ok(WireOrder::Target)
+		}
+		else if value == any::type_name::<Lsb0>() {
+			Ok(WireOrder::Lsb0)
+		}
+		else if value == any::type_name::<Msb0>() {
+			Ok(WireOrder::Msb0)
+		}
+		else {
+			Err(E::invalid_value(Unexpected::Str(value), &self))
+		}
+	}
+}
+
+/// The data buffer of a `BitSeq` stream.
+#[cfg(feature = "alloc")]
+enum WireData<T> {
+	/// The stream’s elements have the same width as `T`, and were read
+	/// directly.
+	Native(Vec<T>),
+	/// The stream’s elements have a different (or not yet known) width, and
+	/// were widened to `u64`.
+	Foreign(Vec<u64>),
+}
+
+/// Deserializes a data buffer once the width of its elements is known.
+#[cfg(feature = "alloc")]
+struct WireDataSeed<T> {
+	/// The element width recorded in the stream’s `head` field, if it has
+	/// been seen yet.
+	width: Option<u8>,
+	/// The type of a native data buffer.
+	data:  PhantomData<Vec<T>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T> DeserializeSeed<'de> for WireDataSeed<T>
+where
+	T: BitStore,
+	Vec<T>: Deserialize<'de>,
+{
+	type Value = WireData<T>;
+
+	#[inline]
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where D: Deserializer<'de> {
+		/// Reads a sequence of narrow integers, widening them to `u64`.
+		fn widen<'de, D, R>(deserializer: D) -> Result<Vec<u64>, D::Error>
+		where
+			D: Deserializer<'de>,
+			R: Deserialize<'de> + Into<u64>,
+		{
+			Vec::<R>::deserialize(deserializer)
+				.map(|vec| vec.into_iter().map(Into::into).collect())
+		}
+
+		match self.width {
+			| Some(width) if width as usize == bits_of::<T::Mem>() => {
+				Vec::<T>::deserialize(deserializer).map(WireData::Native)
+			},
+			| Some(8) => widen::<D, u8>(deserializer).map(WireData::Foreign),
+			| Some(16) => widen::<D, u16>(deserializer).map(WireData::Foreign),
+			| Some(32) => widen::<D, u32>(deserializer).map(WireData::Foreign),
+			| Some(64) | None => {
+				widen::<D, u64>(deserializer).map(WireData::Foreign)
+			},
+			| Some(width) => Err(D::Error::invalid_value(
+				Unexpected::Unsigned(width as u64),
+				&"an element width of 8, 16, 32, or 64",
+			)),
+		}
+	}
+}
+
+/// Assists in deserialization of a `BitSeq` into a `BitVec` of any type
+/// parameters.
+///
+/// If the stream was produced by the same `<T, O>` parameters as the
+/// destination, its data buffer becomes the bit-vector’s allocation directly.
+/// Otherwise, it is read at its recorded width and its bits are copied into a
+/// new bit-vector.
+#[cfg(feature = "alloc")]
+struct RepackVisitor<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The deserialized bit-ordering.
+	order: Option<WireOrder>,
+	/// The deserialized head-bit index and element width.
+	head:  Option<RawIdx>,
+	/// The deserialized bit-count.
+	bits:  Option<u64>,
+	/// The deserialized data buffer.
+	data:  Option<WireData<T>>,
+	/// The type produced by the visitor.
+	out:   PhantomData<BitVec<T, O>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> RepackVisitor<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Creates a new visitor in its ready state.
+	#[inline]
+	fn new() -> Self {
+		Self {
+			order: None,
+			head:  None,
+			bits:  None,
+			data:  None,
+			out:   PhantomData,
+		}
+	}
+
+	/// Produces a seed that reads the data buffer at the width recorded in
+	/// the `head` field, if it has been seen.
+	#[inline]
+	fn data_seed(&self) -> WireDataSeed<T> {
+		WireDataSeed {
+			width: self.head.map(|head| head.width),
+			data:  PhantomData,
+		}
+	}
+
+	/// Attempts to assemble deserialized components into an output value.
+	#[inline]
+	fn assemble<E>(mut self) -> Result<BitVec<T, O>, E>
+	where E: Error {
+		let order =
+			self.order.take().ok_or_else(|| E::missing_field("order"))?;
+		let RawIdx { width, index } =
+			self.head.take().ok_or_else(|| E::missing_field("head"))?;
+		let bits = self.bits.take().ok_or_else(|| E::missing_field("bits"))?;
+		let data = self.data.take().ok_or_else(|| E::missing_field("data"))?;
+
+		if index >= width {
+			return Err(E::invalid_value(
+				Unexpected::Unsigned(index as u64),
+				&"a head-bit index less than the element width",
+			));
+		}
+		let bits = usize::try_from(bits).map_err(|_| {
+			E::invalid_value(
+				Unexpected::Unsigned(bits),
+				&"a bit-count that fits in `usize`",
+			)
+		})?;
+
+		match (order, data) {
+			| (WireOrder::Target, WireData::Native(vec)) => {
+				let span = (index as usize).checked_add(bits);
+				if span.map_or(true, |span| {
+					span > vec.len().saturating_mul(bits_of::<T::Mem>())
+				}) {
+					return Err(E::invalid_length(
+						bits,
+						&"no more bits than the data buffer holds",
+					));
+				}
+				let mut bv = BitVec::try_from_vec(vec).map_err(|vec| {
+					E::invalid_length(
+						vec.len(),
+						&"a data buffer that fits in a `BitVec`",
+					)
+				})?;
+				unsafe {
+					bv.set_head(BitIdx::new(index).unwrap_unchecked());
+					bv.set_len(bits);
+				}
+				Ok(bv)
+			},
+			| (WireOrder::Lsb0, WireData::Native(vec)) => {
+				repack::<T, Lsb0, T, O, E>(&vec, index, bits)
+			},
+			| (WireOrder::Msb0, WireData::Native(vec)) => {
+				repack::<T, Msb0, T, O, E>(&vec, index, bits)
+			},
+			| (WireOrder::Target, WireData::Foreign(vec)) => {
+				repack_foreign::<O, T, O, E>(&vec, width, index, bits)
+			},
+			| (WireOrder::Lsb0, WireData::Foreign(vec)) => {
+				repack_foreign::<Lsb0, T, O, E>(&vec, width, index, bits)
+			},
+			| (WireOrder::Msb0, WireData::Foreign(vec)) => {
+				repack_foreign::<Msb0, T, O, E>(&vec, width, index, bits)
+			},
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, T, O> Visitor<'de> for RepackVisitor<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+	Vec<T>: Deserialize<'de>,
+{
+	type Value = BitVec<T, O>;
+
+	#[inline]
+	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(
+			fmt,
+			"a `BitSlice` to store in a `BitVec<u{}, {}>`",
+			bits_of::<T::Mem>(),
+			any::type_name::<O>(),
+		)
+	}
+
+	#[inline]
+	fn visit_seq<V>(mut self, mut seq: V) -> Result<Self::Value, V::Error>
+	where V: SeqAccess<'de> {
+		self.order = Some(
+			seq.next_element_seed(WireOrderSeed::<O>(PhantomData))?
+				.ok_or_else(|| <V::Error>::invalid_length(0, &self))?,
+		);
+		self.head = Some(
+			seq.next_element()?
+				.ok_or_else(|| <V::Error>::invalid_length(1, &self))?,
+		);
+		self.bits = Some(
+			seq.next_element()?
+				.ok_or_else(|| <V::Error>::invalid_length(2, &self))?,
+		);
+		self.data = Some(
+			seq.next_element_seed(self.data_seed())?
+				.ok_or_else(|| <V::Error>::invalid_length(3, &self))?,
+		);
+
+		self.assemble()
+	}
+
+	#[inline]
+	fn visit_map<V>(mut self, mut map: V) -> Result<Self::Value, V::Error>
+	where V: MapAccess<'de> {
+		while let Some(key) = map.next_key()? {
+			match key {
+				| Field::Order => {
+					let order =
+						map.next_value_seed(WireOrderSeed::<O>(PhantomData))?;
+					if self.order.replace(order).is_some() {
+						return Err(<V::Error>::duplicate_field("order"));
+					}
+				},
+				| Field::Head => {
+					if self.head.replace(map.next_value()?).is_some() {
+						return Err(<V::Error>::duplicate_field("head"));
+					}
+				},
+				| Field::Bits => {
+					if self.bits.replace(map.next_value()?).is_some() {
+						return Err(<V::Error>::duplicate_field("bits"));
+					}
+				},
+				| Field::Data => {
+					let data = map.next_value_seed(self.data_seed())?;
+					if self.data.replace(data).is_some() {
+						return Err(<V::Error>::duplicate_field("data"));
+					}
+				},
+			}
+		}
+
+		self.assemble()
+	}
+}
+
+/// Copies the live bits of a data buffer, as viewed through `<T2, O2>`, into a
+/// new `BitVec<T, O>`.
+#[cfg(feature = "alloc")]
+fn repack<T2, O2, T, O, E>(
+	data: &[T2],
+	index: u8,
+	bits: usize,
+) -> Result<BitVec<T, O>, E>
+where
+	T2: BitStore,
+	O2: BitOrder,
+	T: BitStore,
+	O: BitOrder,
+	E: Error,
+{
+	let src = BitSlice::<T2, O2>::try_from_slice(data)
+		.ok()
+		.and_then(|all| all.get(index as usize ..))
+		.and_then(|rest| rest.get(.. bits))
+		.ok_or_else(|| {
+			E::invalid_length(bits, &"no more bits than the data buffer holds")
+		})?;
+	let mut out = BitVec::with_capacity(bits);
+	out.extend_from_bitslice(src);
+	Ok(out)
+}
+
+/// Narrows a widened data buffer back to its recorded element width, then
+/// re-packs it under the `O2` ordering.
+#[cfg(feature = "alloc")]
+fn repack_foreign<O2, T, O, E>(
+	data: &[u64],
+	width: u8,
+	index: u8,
+	bits: usize,
+) -> Result<BitVec<T, O>, E>
+where
+	O2: BitOrder,
+	T: BitStore,
+	O: BitOrder,
+	E: Error,
+{
+	/// Narrows each element, failing if any does not fit in `R`.
+	fn narrow<R, E>(data: &[u64]) -> Result<Vec<R>, E>
+	where
+		R: TryFrom<u64>,
+		E: Error,
+	{
+		data.iter()
+			.map(|&elem| {
+				R::try_from(elem).map_err(|_| {
+					E::invalid_value(
+						Unexpected::Unsigned(elem),
+						&"an element no wider than the recorded width",
+					)
+				})
+			})
+			.collect()
+	}
+
+	match width {
+		| 8 => repack::<u8, O2, T, O, E>(&narrow(data)?, index, bits),
+		| 16 => repack::<u16, O2, T, O, E>(&narrow(data)?, index, bits),
+		| 32 => repack::<u32, O2, T, O, E>(&narrow(data)?, index, bits),
+		| 64 => repack::<u64, O2, T, O, E>(data, index, bits),
+		| _ => Err(E::invalid_value(
+			Unexpected::Unsigned(width as u64),
+			&"an element width of 8, 16, 32, or 64",
+		)),
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#[cfg(all(feature = "alloc", not(feature = "std")))]
@@ -365,6 +784,131 @@ mod tests {
 		assert_de_tokens(&slice, tokens);
 	}
 
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn repack() -> Result<(), alloc::boxed::Box<bincode::ErrorKind>> {
+		use core::cell::Cell;
+
+		use crate::serdes::strict::Strict;
+
+		let mut src = bitvec![u8, Msb0; 0; 100];
+		for (idx, mut bit) in src.iter_mut().enumerate() {
+			*bit = idx % 3 == 0 || idx % 7 == 2;
+		}
+		let src = &src[3 ..];
+
+		let encoded = bincode::serialize(src)?;
+		let json = serde_json::to_string(src).unwrap();
+		macro_rules! check {
+			($($t:ty),+ $(,)?) => { $(
+				assert_eq!(bincode::deserialize::<$t>(&encoded)?, src);
+				assert_eq!(serde_json::from_str::<$t>(&json).unwrap(), src);
+			)+ };
+		}
+		check!(
+			BitVec<u8, Msb0>,
+			BitVec<u8, Lsb0>,
+			BitVec<u16, Msb0>,
+			BitVec<u32, Lsb0>,
+			BitVec<Cell<u64>, Lsb0>,
+			BitBox<usize, Msb0>,
+			BitBox<u16, Lsb0>,
+		);
+
+		let wide = bitvec![u64, Lsb0; 1, 1, 0, 1, 0, 0, 0, 1, 1];
+		let encoded = bincode::serialize(&wide)?;
+		assert_eq!(bincode::deserialize::<BitVec<u8, Msb0>>(&encoded)?, wide);
+		assert!(
+			bincode::deserialize::<Strict<BitVec<u8, Msb0>>>(&encoded).is_err()
+		);
+		assert_eq!(
+			bincode::deserialize::<Strict<BitVec<u64, Lsb0>>>(&encoded)?
+				.into_inner(),
+			wide,
+		);
+
+		//  A self-describing format may present the data before its width.
+		let json = format!(
+			r#"{{"data":[160],"bits":3,"head":{{"width":8,"index":0}},"order":"{}"}}"#,
+			any::type_name::<Msb0>(),
+		);
+		assert_eq!(
+			serde_json::from_str::<BitVec<u16, Lsb0>>(&json).unwrap(),
+			bits![1, 0, 1],
+		);
+
+		let tokens = &[
+			Token::Seq { len: Some(4) },
+			Token::BorrowedStr(any::type_name::<Lsb0>()),
+			Token::Seq { len: Some(2) },
+			Token::U8(16),
+			Token::U8(2),
+			Token::SeqEnd,
+			Token::U64(5),
+			Token::Seq { len: Some(1) },
+			Token::U16(0b1101_0100),
+			Token::SeqEnd,
+			Token::SeqEnd,
+		];
+		assert_de_tokens(&bitvec![u8, Msb0; 1, 0, 1, 0, 1], tokens);
+		assert_de_tokens(&bitvec![u16, Lsb0; 1, 0, 1, 0, 1], tokens);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn repack_errors() {
+		let stream = |order: &'static str, width, index, bits, data| {
+			[
+				Token::Seq { len: Some(4) },
+				Token::BorrowedStr(order),
+				Token::Seq { len: Some(2) },
+				Token::U8(width),
+				Token::U8(index),
+				Token::SeqEnd,
+				Token::U64(bits),
+				Token::Seq { len: Some(1) },
+				Token::U8(data),
+				Token::SeqEnd,
+				Token::SeqEnd,
+			]
+		};
+		let lsb0 = any::type_name::<Lsb0>();
+
+		assert_de_tokens_error::<BitVec<u16, Msb0>>(
+			&stream("Lsb1", 8, 0, 8, 0)[.. 2],
+			&format!(
+				"invalid value: string \"Lsb1\", expected one of the strings \
+				 {:?}, {:?}, or {:?}",
+				any::type_name::<Msb0>(),
+				lsb0,
+				any::type_name::<Msb0>(),
+			),
+		);
+		assert_de_tokens_error::<BitVec<u16, Msb0>>(
+			&stream(lsb0, 12, 0, 8, 0)[.. 8],
+			"invalid value: integer `12`, expected an element width of 8, 16, \
+			 32, or 64",
+		);
+		assert_de_tokens_error::<BitVec<u16, Msb0>>(
+			&stream(lsb0, 8, 8, 1, 0),
+			"invalid value: integer `8`, expected a head-bit index less than \
+			 the element width",
+		);
+		assert_de_tokens_error::<BitVec<u16, Msb0>>(
+			&stream(lsb0, 8, 2, 7, 0),
+			"invalid length 7, expected no more bits than the data buffer holds",
+		);
+		assert_de_tokens_error::<BitVec<u8, Lsb0>>(
+			&stream(lsb0, 8, 2, 7, 0),
+			"invalid length 7, expected no more bits than the data buffer holds",
+		);
+		assert_de_tokens_error::<crate::serdes::strict::Strict<BitVec<u16>>>(
+			&stream(lsb0, 8, 0, 8, 0)[.. 6],
+			"invalid type: integer `8`, expected a valid `BitIdx<u16>`",
+		);
+	}
+
 	#[test]
 	#[cfg(feature = "alloc")]
 	fn errors() {
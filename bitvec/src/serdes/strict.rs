@@ -0,0 +1,129 @@
+#![doc = include_str!("../../doc/serdes/strict.md")]
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::ops::{
+	Deref,
+	DerefMut,
+};
+
+use serde::{
+	de::{
+		Deserialize,
+		Deserializer,
+	},
+	ser::{
+		Serialize,
+		Serializer,
+	},
+};
+
+use crate::{
+	boxed::BitBox,
+	order::BitOrder,
+	store::BitStore,
+	vec::BitVec,
+};
+
+/// Serializes a bit-sequence in the ordinary `BitSeq` format.
+///
+/// Strictness only affects deserialization; this exists so that the module can
+/// be used as `#[serde(with = "bitvec::serdes::strict")]`.
+#[inline]
+pub fn serialize<B, S>(bits: &B, serializer: S) -> Result<S::Ok, S::Error>
+where
+	B: ?Sized + Serialize,
+	S: Serializer,
+{
+	bits.serialize(serializer)
+}
+
+/// Deserializes a `BitVec` or `BitBox` only from a stream produced by the same
+/// type parameters.
+#[inline]
+pub fn deserialize<'de, B, D>(deserializer: D) -> Result<B, D::Error>
+where
+	D: Deserializer<'de>,
+	Strict<B>: Deserialize<'de>,
+{
+	Strict::deserialize(deserializer).map(Strict::into_inner)
+}
+
+/// Deserializes a bit-sequence only from a stream produced by the same
+/// `<T, O>` type parameters.
+///
+/// This serializes exactly as the wrapped value does.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Strict<B>(pub B);
+
+impl<B> Strict<B> {
+	/// Removes the wrapper, returning the bit-sequence.
+	#[inline]
+	pub fn into_inner(self) -> B {
+		self.0
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B> From<B> for Strict<B> {
+	#[inline]
+	fn from(bits: B) -> Self {
+		Self(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B> Deref for Strict<B> {
+	type Target = B;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B> DerefMut for Strict<B> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<B> Serialize for Strict<B>
+where B: Serialize
+{
+	#[inline]
+	fn serialize<S>(&self, serializer: S) -> super::Result<S>
+	where S: Serializer {
+		self.0.serialize(serializer)
+	}
+}
+
+impl<'de, T, O> Deserialize<'de> for Strict<BitVec<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+	Vec<T>: Deserialize<'de>,
+{
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		super::slice::deserialize_strict(deserializer).map(Self)
+	}
+}
+
+impl<'de, T, O> Deserialize<'de> for Strict<BitBox<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+	Vec<T>: Deserialize<'de>,
+{
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		super::slice::deserialize_strict(deserializer)
+			.map(|bv: BitVec<T, O>| Self(bv.into_boxed_bitslice()))
+	}
+}
@@ -15,6 +15,7 @@ use serde::{
 		Deserialize,
 		Deserializer,
 		Error,
+		Expected,
 		MapAccess,
 		SeqAccess,
 		Unexpected,
@@ -142,11 +143,26 @@ where R: BitRegister
 	#[inline]
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where D: Deserializer<'de> {
-		deserializer.deserialize_struct(
-			"BitIdx",
-			FIELDS,
-			BitIdxVisitor::<R>::THIS,
-		)
+		let RawIdx { width, index } = RawIdx::deserialize(deserializer)?;
+		BitIdxVisitor::<R>::THIS.assemble(width, index)
+	}
+}
+
+/// The `BitIdx` transport format, before it has been checked against any
+/// register type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct RawIdx {
+	/// The bit-width of the register that produced the index.
+	pub(super) width: u8,
+	/// The value of the index.
+	pub(super) index: u8,
+}
+
+impl<'de> Deserialize<'de> for RawIdx {
+	#[inline]
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where D: Deserializer<'de> {
+		deserializer.deserialize_struct("BitIdx", FIELDS, RawIdxVisitor)
 	}
 }
 
@@ -302,14 +318,24 @@ where R: BitRegister
 	}
 }
 
-impl<'de, R> Visitor<'de> for BitIdxVisitor<R>
+impl<R> Expected for BitIdxVisitor<R>
 where R: BitRegister
 {
-	type Value = BitIdx<R>;
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "a valid `BitIdx<u{}>`", bits_of::<R>())
+	}
+}
+
+/// Assists in deserialization of a `RawIdx` value.
+struct RawIdxVisitor;
+
+impl<'de> Visitor<'de> for RawIdxVisitor {
+	type Value = RawIdx;
 
 	#[inline]
 	fn expecting(&self, fmt: &mut Formatter) -> fmt::Result {
-		write!(fmt, "a valid `BitIdx<u{}>`", bits_of::<R>())
+		fmt.write_str("a `BitIdx`")
 	}
 
 	#[inline]
@@ -322,7 +348,7 @@ where R: BitRegister
 			.next_element::<u8>()?
 			.ok_or_else(|| <V::Error>::invalid_length(1, &self))?;
 
-		self.assemble(width, index)
+		Ok(RawIdx { width, index })
 	}
 
 	#[inline]
@@ -349,7 +375,7 @@ where R: BitRegister
 		let width = width.ok_or_else(|| <V::Error>::missing_field("width"))?;
 		let index = index.ok_or_else(|| <V::Error>::missing_field("index"))?;
 
-		self.assemble(width, index)
+		Ok(RawIdx { width, index })
 	}
 }
 
@@ -0,0 +1,584 @@
+#![doc = include_str!("../doc/set.md")]
+
+#[cfg(feature = "alloc")]
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+	vec::BitVec,
+};
+
+mod array;
+mod iter;
+mod tests;
+#[cfg(feature = "alloc")]
+mod traits;
+
+#[cfg(feature = "alloc")]
+pub use self::iter::IntoIter;
+pub use self::{
+	array::BitArraySet,
+	iter::{
+		Difference,
+		Intersection,
+		SymmetricDifference,
+		Union,
+	},
+};
+
+#[cfg(feature = "alloc")]
+#[doc = include_str!("../doc/set/BitSet.md")]
+pub struct BitSet<T = usize, O = Lsb0>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-map of set members. A value is in the set when the bit at its
+	/// index is `1`.
+	bits: BitVec<T, O>,
+}
+
+/// Constructors and converters.
+#[cfg(feature = "alloc")]
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Constructs a new, empty, bit-set.
+	///
+	/// This does not allocate until a value is inserted.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::new`](alloc::collections::BTreeSet::new)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::<u8>::new();
+	/// assert!(set.is_empty());
+	/// ```
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			bits: BitVec::new(),
+		}
+	}
+
+	/// Constructs a new, empty, bit-set that is able to hold any value in
+	/// `0 .. capacity` before reällocating.
+	///
+	/// ## Panics
+	///
+	/// This panics if `capacity` is too large for a bit-vector to represent.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::<u8>::with_capacity(20);
+	/// assert!(set.capacity() >= 20);
+	/// ```
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			bits: BitVec::with_capacity(capacity),
+		}
+	}
+
+	/// Views a bit-vector as the bit-map of a set.
+	///
+	/// The produced set contains the index of each `1` bit in `bits`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set = BitSet::from_bitvec(bitvec![0, 1, 1, 0, 1]);
+	/// assert!(set.iter().eq([1, 2, 4]));
+	/// ```
+	#[inline]
+	pub fn from_bitvec(bits: BitVec<T, O>) -> Self {
+		Self { bits }
+	}
+
+	/// Removes the set wrapper, returning its bit-map.
+	///
+	/// The bit-vector may have trailing `0` bits beyond the largest member of
+	/// the set.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::set::BitSet;
+	///
+	/// let set: BitSet = [1, 3].into_iter().collect();
+	/// assert_eq!(set.into_bitvec(), bits![0, 1, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		self.bits
+	}
+
+	/// Views the set’s bit-map as a bit-slice.
+	///
+	/// Each `1` bit in the bit-slice marks a member of the set.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Gets the number of values that the set can hold without reällocating.
+	///
+	/// All values in `0 .. self.capacity()` can be inserted without touching
+	/// the allocator.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.bits.capacity()
+	}
+
+	/// Reserves space for the set to hold all values in `0 .. max`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `max` is too large for a bit-vector to represent.
+	#[inline]
+	pub fn reserve(&mut self, max: usize) {
+		let len = self.bits.len();
+		if max > len {
+			self.bits.reserve(max - len);
+		}
+	}
+
+	/// Trims trailing `0` bits from the bit-map, and releases any excess
+	/// allocation.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u8>::new();
+	/// set.insert(100);
+	/// set.insert(3);
+	/// set.remove(100);
+	///
+	/// set.shrink_to_fit();
+	/// assert_eq!(set.as_bitslice().len(), 4);
+	/// ```
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		let len = self.bits.last_one().map_or(0, |idx| idx + 1);
+		self.bits.truncate(len);
+		self.bits.shrink_to_fit();
+	}
+}
+
+/// Port of the `BTreeSet<usize>` inherent API.
+#[cfg(feature = "alloc")]
+impl<T, O> BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Clears the set, removing all members.
+	///
+	/// This does not release the allocation.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::clear`](alloc::collections::BTreeSet::clear)
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.clear();
+	}
+
+	/// Tests if the set contains a value.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::contains`](alloc::collections::BTreeSet::contains)
+	///
+	/// ## API Differences
+	///
+	/// This takes the value directly, rather than through a reference.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set: BitSet = [1, 2].into_iter().collect();
+	/// assert!(set.contains(1));
+	/// assert!(!set.contains(3));
+	/// assert!(!set.contains(!0));
+	/// ```
+	#[inline]
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).map_or(false, |bit| *bit)
+	}
+
+	/// Finds the smallest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::first`](alloc::collections::BTreeSet::first)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u16>::new();
+	/// assert_eq!(set.first(), None);
+	/// set.insert(20);
+	/// set.insert(10);
+	/// assert_eq!(set.first(), Some(10));
+	/// ```
+	#[inline]
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Finds the largest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::last`](alloc::collections::BTreeSet::last)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u16>::new();
+	/// assert_eq!(set.last(), None);
+	/// set.insert(10);
+	/// set.insert(20);
+	/// assert_eq!(set.last(), Some(20));
+	/// ```
+	#[inline]
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Removes and returns the smallest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::pop_first`](alloc::collections::BTreeSet::pop_first)
+	#[inline]
+	pub fn pop_first(&mut self) -> Option<usize> {
+		let value = self.first()?;
+		self.bits.set(value, false);
+		Some(value)
+	}
+
+	/// Removes and returns the largest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::pop_last`](alloc::collections::BTreeSet::pop_last)
+	#[inline]
+	pub fn pop_last(&mut self) -> Option<usize> {
+		let value = self.last()?;
+		self.bits.set(value, false);
+		Some(value)
+	}
+
+	/// Adds a value to the set.
+	///
+	/// If the value is larger than any the set can currently hold, the bit-map
+	/// grows to include it.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::insert`](alloc::collections::BTreeSet::insert)
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was not already in the set; `false` if it was.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` is too large for a bit-vector to represent. See
+	/// [`BitSlice::MAX_BITS`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set = BitSet::<u8>::new();
+	/// assert!(set.insert(30));
+	/// assert!(!set.insert(30));
+	/// assert_eq!(set.len(), 1);
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	#[inline]
+	pub fn insert(&mut self, value: usize) -> bool {
+		if value >= self.bits.len() {
+			let len = value.checked_add(1).unwrap_or_else(|| {
+				panic!(
+					"bit-set capacity exceeded: {} > {}",
+					value,
+					BitSlice::<T, O>::MAX_BITS,
+				)
+			});
+			self.bits.resize(len, false);
+		}
+		!self.bits.replace(value, true)
+	}
+
+	/// Removes a value from the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::remove`](alloc::collections::BTreeSet::remove)
+	///
+	/// ## API Differences
+	///
+	/// This takes the value directly, rather than through a reference.
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was in the set; `false` if it was not.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set: BitSet = [2].into_iter().collect();
+	/// assert!(set.remove(2));
+	/// assert!(!set.remove(2));
+	/// assert!(!set.remove(200));
+	/// ```
+	#[inline]
+	pub fn remove(&mut self, value: usize) -> bool {
+		value < self.bits.len() && self.bits.replace(value, false)
+	}
+
+	/// Retains only the values that a predicate accepts.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::retain`](alloc::collections::BTreeSet::retain)
+	///
+	/// ## API Differences
+	///
+	/// The predicate receives each value directly, rather than through a
+	/// reference.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut set: BitSet = (0 .. 8).collect();
+	/// set.retain(|n| n % 3 == 0);
+	/// assert!(set.iter().eq([0, 3, 6]));
+	/// ```
+	#[inline]
+	pub fn retain<F>(&mut self, mut func: F)
+	where F: FnMut(usize) -> bool {
+		for (idx, mut bit) in self.bits.iter_mut().enumerate() {
+			if *bit && !func(idx) {
+				*bit = false;
+			}
+		}
+	}
+
+	/// Moves all members of `other` into `self`, leaving `other` empty.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::append`](alloc::collections::BTreeSet::append)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let mut a: BitSet = [1, 2].into_iter().collect();
+	/// let mut b: BitSet = [2, 30].into_iter().collect();
+	///
+	/// a.append(&mut b);
+	/// assert!(a.iter().eq([1, 2, 30]));
+	/// assert!(b.is_empty());
+	/// ```
+	#[inline]
+	pub fn append(&mut self, other: &mut Self) {
+		let len = other.bits.len();
+		if len > self.bits.len() {
+			self.bits.resize(len, false);
+		}
+		*self.bits.as_mut_bitslice() |= other.bits.as_bitslice();
+		other.clear();
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::iter`](alloc::collections::BTreeSet::iter)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let set: BitSet = [3, 1, 2].into_iter().collect();
+	/// assert!(set.iter().eq([1, 2, 3]));
+	/// assert!(set.iter().rev().eq([3, 2, 1]));
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> IterOnes<'_, T, O> {
+		self.bits.iter_ones()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::len`](alloc::collections::BTreeSet::len)
+	///
+	/// ## Performance
+	///
+	/// The set does not keep a running count of its members, so this scans
+	/// the entire bit-map.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_empty`](alloc::collections::BTreeSet::is_empty)
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Iterates over the values that are in `self` or `other`, without
+	/// duplicates, in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::union`](alloc::collections::BTreeSet::union)
+	#[inline]
+	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T, O> {
+		Union::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in both `self` and `other`, in
+	/// ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::intersection`](alloc::collections::BTreeSet::intersection)
+	#[inline]
+	pub fn intersection<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Intersection<'a, T, O> {
+		Intersection::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in `self` but not in `other`, in
+	/// ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::difference`](alloc::collections::BTreeSet::difference)
+	#[inline]
+	pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T, O> {
+		Difference::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in exactly one of `self` or `other`,
+	/// in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::symmetric_difference`](alloc::collections::BTreeSet::symmetric_difference)
+	#[inline]
+	pub fn symmetric_difference<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> SymmetricDifference<'a, T, O> {
+		SymmetricDifference::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_disjoint`](alloc::collections::BTreeSet::is_disjoint)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2].into_iter().collect();
+	/// let b: BitSet = [3].into_iter().collect();
+	/// let c: BitSet = [2, 3].into_iter().collect();
+	///
+	/// assert!(a.is_disjoint(&b));
+	/// assert!(!a.is_disjoint(&c));
+	/// ```
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).next().is_none()
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_subset`](alloc::collections::BTreeSet::is_subset)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::set::BitSet;
+	///
+	/// let a: BitSet = [1, 2].into_iter().collect();
+	/// let b: BitSet = [1, 2, 3].into_iter().collect();
+	///
+	/// assert!(a.is_subset(&b));
+	/// assert!(!b.is_subset(&a));
+	/// assert!(BitSet::new().is_subset(&a));
+	/// ```
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).next().is_none()
+	}
+
+	/// Tests if every member of `other` is also a member of `self`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_superset`](alloc::collections::BTreeSet::is_superset)
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+}
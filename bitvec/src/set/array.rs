@@ -0,0 +1,557 @@
+//! Fixed-capacity bit-sets.
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+};
+
+use super::{
+	Difference,
+	Intersection,
+	SymmetricDifference,
+	Union,
+};
+use crate::{
+	array::BitArray,
+	mem,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	view::BitViewSized,
+};
+
+#[doc = include_str!("../../doc/set/BitArraySet.md")]
+pub struct BitArraySet<A = [usize; 1], O = Lsb0>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The bit-map of set members. A value is in the set when the bit at its
+	/// index is `1`.
+	bits: BitArray<A, O>,
+}
+
+/// Constructors and converters.
+impl<A, O> BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The number of values that the set is able to hold. Every member of the
+	/// set is in the range `0 .. CAPACITY`.
+	pub const CAPACITY: usize = mem::bits_of::<A>();
+
+	/// Constructs a new, empty, bit-set.
+	///
+	/// This is usable in `const` contexts, including `static` initializers.
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			bits: BitArray::ZERO,
+		}
+	}
+
+	/// Views a bit-array as the bit-map of a set.
+	///
+	/// The produced set contains the index of each `1` bit in `bits`.
+	#[inline]
+	pub fn from_bitarray(bits: BitArray<A, O>) -> Self {
+		Self { bits }
+	}
+
+	/// Removes the set wrapper, returning its bit-map.
+	#[inline]
+	pub fn into_inner(self) -> BitArray<A, O> {
+		self.bits
+	}
+
+	/// Views the set’s bit-map as a bit-slice.
+	///
+	/// Each `1` bit in the bit-slice marks a member of the set.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		self.bits.as_bitslice()
+	}
+
+	/// Gets the number of values that the set is able to hold.
+	///
+	/// This method is a compile-time constant.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		Self::CAPACITY
+	}
+}
+
+/// Port of the `BTreeSet<usize>` inherent API.
+impl<A, O> BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Clears the set, removing all members.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::clear`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.clear)
+	#[inline]
+	pub fn clear(&mut self) {
+		self.bits.fill(false);
+	}
+
+	/// Tests if the set contains a value.
+	///
+	/// Values that are not less than [`CAPACITY`] are never members.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::contains`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.contains)
+	///
+	/// [`CAPACITY`]: Self::CAPACITY
+	#[inline]
+	pub fn contains(&self, value: usize) -> bool {
+		self.bits.get(value).map_or(false, |bit| *bit)
+	}
+
+	/// Finds the smallest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::first`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.first)
+	#[inline]
+	pub fn first(&self) -> Option<usize> {
+		self.bits.first_one()
+	}
+
+	/// Finds the largest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::last`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.last)
+	#[inline]
+	pub fn last(&self) -> Option<usize> {
+		self.bits.last_one()
+	}
+
+	/// Removes and returns the smallest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::pop_first`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.pop_first)
+	#[inline]
+	pub fn pop_first(&mut self) -> Option<usize> {
+		let value = self.first()?;
+		self.bits.set(value, false);
+		Some(value)
+	}
+
+	/// Removes and returns the largest member of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::pop_last`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.pop_last)
+	#[inline]
+	pub fn pop_last(&mut self) -> Option<usize> {
+		let value = self.last()?;
+		self.bits.set(value, false);
+		Some(value)
+	}
+
+	/// Adds a value to the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::insert`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.insert)
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was not already in the set; `false` if it was.
+	///
+	/// ## Panics
+	///
+	/// This panics if `value` is not less than [`CAPACITY`].
+	///
+	/// [`CAPACITY`]: Self::CAPACITY
+	#[inline]
+	pub fn insert(&mut self, value: usize) -> bool {
+		!self.bits.replace(value, true)
+	}
+
+	/// Attempts to add a value to the set.
+	///
+	/// ## Returns
+	///
+	/// If `value` is less than [`CAPACITY`], this returns `Ok` with the same
+	/// value that [`.insert()`] would return. Otherwise, the set is unchanged
+	/// and this returns `value` as an error.
+	///
+	/// [`CAPACITY`]: Self::CAPACITY
+	/// [`.insert()`]: Self::insert
+	#[inline]
+	pub fn try_insert(&mut self, value: usize) -> Result<bool, usize> {
+		if value < Self::CAPACITY {
+			Ok(self.insert(value))
+		}
+		else {
+			Err(value)
+		}
+	}
+
+	/// Removes a value from the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::remove`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.remove)
+	///
+	/// ## Returns
+	///
+	/// `true` if the value was in the set; `false` if it was not.
+	#[inline]
+	pub fn remove(&mut self, value: usize) -> bool {
+		value < Self::CAPACITY && self.bits.replace(value, false)
+	}
+
+	/// Retains only the values that a predicate accepts.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::retain`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.retain)
+	#[inline]
+	pub fn retain<F>(&mut self, mut func: F)
+	where F: FnMut(usize) -> bool {
+		for (idx, mut bit) in self.bits.iter_mut().enumerate() {
+			if *bit && !func(idx) {
+				*bit = false;
+			}
+		}
+	}
+
+	/// Iterates over the members of the set, in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::iter`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.iter)
+	#[inline]
+	pub fn iter(&self) -> IterOnes<'_, A::Store, O> {
+		self.bits.iter_ones()
+	}
+
+	/// Counts the members of the set.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::len`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.len)
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.bits.count_ones()
+	}
+
+	/// Tests if the set has no members.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_empty`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.is_empty)
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.bits.not_any()
+	}
+
+	/// Iterates over the values that are in `self` or `other`, without
+	/// duplicates, in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::union`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.union)
+	#[inline]
+	pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, A::Store, O> {
+		Union::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in both `self` and `other`, in
+	/// ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::intersection`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.intersection)
+	#[inline]
+	pub fn intersection<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Intersection<'a, A::Store, O> {
+		Intersection::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in `self` but not in `other`, in
+	/// ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::difference`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.difference)
+	#[inline]
+	pub fn difference<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> Difference<'a, A::Store, O> {
+		Difference::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Iterates over the values that are in exactly one of `self` or `other`,
+	/// in ascending order.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::symmetric_difference`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.symmetric_difference)
+	#[inline]
+	pub fn symmetric_difference<'a>(
+		&'a self,
+		other: &'a Self,
+	) -> SymmetricDifference<'a, A::Store, O> {
+		SymmetricDifference::new(self.as_bitslice(), other.as_bitslice())
+	}
+
+	/// Tests if `self` and `other` have no members in common.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_disjoint`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.is_disjoint)
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.intersection(other).next().is_none()
+	}
+
+	/// Tests if every member of `self` is also a member of `other`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_subset`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.is_subset)
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.difference(other).next().is_none()
+	}
+
+	/// Tests if every member of `other` is also a member of `self`.
+	///
+	/// ## Original
+	///
+	/// [`BTreeSet::is_superset`](https://doc.rust-lang.org/std/collections/struct.BTreeSet.html#method.is_superset)
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		other.is_subset(self)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Clone for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			bits: self.bits.clone(),
+		}
+	}
+}
+
+impl<A, O> Copy for BitArraySet<A, O>
+where
+	A: BitViewSized + Copy,
+	O: BitOrder,
+{
+}
+
+impl<A, O> Debug for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Default for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<A, O> Eq for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Ord for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.iter().cmp(other.iter())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> PartialEq for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.bits == other.bits
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> PartialOrd for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Hash for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.iter().for_each(|value| value.hash(hasher));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArray<A, O>> for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArray<A, O>) -> Self {
+		Self::from_bitarray(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArraySet<A, O>> for BitArray<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(set: BitArraySet<A, O>) -> Self {
+		set.into_inner()
+	}
+}
+
+/// Inserts each value into the set.
+///
+/// ## Panics
+///
+/// This panics if any value is not less than the set’s capacity.
+impl<A, O> Extend<usize> for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		iter.into_iter().for_each(|value| {
+			self.insert(value);
+		});
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> Extend<&'a usize> for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a usize> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+/// Collects values into a new set.
+///
+/// ## Panics
+///
+/// This panics if any value is not less than the set’s capacity.
+impl<A, O> FromIterator<usize> for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> FromIterator<&'a usize> for BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a usize> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> IntoIterator for &'a BitArraySet<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type IntoIter = IterOnes<'a, A::Store, O>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
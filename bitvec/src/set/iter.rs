@@ -0,0 +1,397 @@
+#![doc = include_str!("../../doc/set/iter.md")]
+
+use core::{
+	cmp::{
+		self,
+		Ordering,
+	},
+	iter::{
+		FusedIterator,
+		Peekable,
+	},
+};
+
+#[cfg(feature = "alloc")]
+use super::BitSet;
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	order::BitOrder,
+	slice::{
+		BitSlice,
+		IterOnes,
+	},
+	store::BitStore,
+};
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/set/iter/Union.md")]
+pub struct Union<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The members of the left-hand set.
+	this: Peekable<IterOnes<'a, T, O>>,
+	/// The members of the right-hand set.
+	that: Peekable<IterOnes<'a, T, O>>,
+}
+
+impl<'a, T, O> Union<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Merges the members of two bit-maps.
+	#[inline]
+	pub(super) fn new(
+		this: &'a BitSlice<T, O>,
+		that: &'a BitSlice<T, O>,
+	) -> Self {
+		Self {
+			this: this.iter_ones().peekable(),
+			that: that.iter_ones().peekable(),
+		}
+	}
+}
+
+impl<T, O> Iterator for Union<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match (self.this.peek(), self.that.peek()) {
+			| (Some(a), Some(b)) => match a.cmp(b) {
+				| Ordering::Less => self.this.next(),
+				| Ordering::Greater => self.that.next(),
+				| Ordering::Equal => {
+					self.that.next();
+					self.this.next()
+				},
+			},
+			| (Some(_), None) => self.this.next(),
+			| (None, _) => self.that.next(),
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (a_min, a_max) = self.this.size_hint();
+		let (b_min, b_max) = self.that.size_hint();
+		let max = a_max.zip(b_max).and_then(|(a, b)| a.checked_add(b));
+		(cmp::max(a_min, b_min), max)
+	}
+}
+
+impl<T, O> FusedIterator for Union<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/set/iter/Intersection.md")]
+pub struct Intersection<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The members of the left-hand set that are within the bounds of the
+	/// right-hand set.
+	this: IterOnes<'a, T, O>,
+	/// The bit-map of the right-hand set.
+	that: &'a BitSlice<T, O>,
+}
+
+impl<'a, T, O> Intersection<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Intersects the members of two bit-maps.
+	///
+	/// Only the region in which both bit-maps exist can have common members,
+	/// so the left-hand bit-map is truncated to the length of the right.
+	#[inline]
+	pub(super) fn new(
+		this: &'a BitSlice<T, O>,
+		that: &'a BitSlice<T, O>,
+	) -> Self {
+		let len = cmp::min(this.len(), that.len());
+		Self {
+			this: unsafe { this.get_unchecked(.. len) }.iter_ones(),
+			that,
+		}
+	}
+}
+
+impl<T, O> Iterator for Intersection<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let that = self.that;
+		self.this
+			.by_ref()
+			.find(|&idx| unsafe { *that.get_unchecked(idx) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.this.size_hint().1)
+	}
+}
+
+impl<T, O> FusedIterator for Intersection<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/set/iter/Difference.md")]
+pub struct Difference<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The members of the left-hand set.
+	this: IterOnes<'a, T, O>,
+	/// The bit-map of the right-hand set.
+	that: &'a BitSlice<T, O>,
+}
+
+impl<'a, T, O> Difference<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Removes the members of one bit-map from another.
+	#[inline]
+	pub(super) fn new(
+		this: &'a BitSlice<T, O>,
+		that: &'a BitSlice<T, O>,
+	) -> Self {
+		Self {
+			this: this.iter_ones(),
+			that,
+		}
+	}
+}
+
+impl<T, O> Iterator for Difference<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let that = self.that;
+		self.this
+			.by_ref()
+			.find(|&idx| !that.get(idx).map_or(false, |bit| *bit))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.this.size_hint().1)
+	}
+}
+
+impl<T, O> FusedIterator for Difference<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/set/iter/SymmetricDifference.md")]
+pub struct SymmetricDifference<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The members of the left-hand set.
+	this: Peekable<IterOnes<'a, T, O>>,
+	/// The members of the right-hand set.
+	that: Peekable<IterOnes<'a, T, O>>,
+}
+
+impl<'a, T, O> SymmetricDifference<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Merges the members of two bit-maps, discarding common members.
+	#[inline]
+	pub(super) fn new(
+		this: &'a BitSlice<T, O>,
+		that: &'a BitSlice<T, O>,
+	) -> Self {
+		Self {
+			this: this.iter_ones().peekable(),
+			that: that.iter_ones().peekable(),
+		}
+	}
+}
+
+impl<T, O> Iterator for SymmetricDifference<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match (self.this.peek(), self.that.peek()) {
+				| (Some(a), Some(b)) => match a.cmp(b) {
+					| Ordering::Less => return self.this.next(),
+					| Ordering::Greater => return self.that.next(),
+					| Ordering::Equal => {
+						self.this.next();
+						self.that.next();
+					},
+				},
+				| (Some(_), None) => return self.this.next(),
+				| (None, _) => return self.that.next(),
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (_, a_max) = self.this.size_hint();
+		let (_, b_max) = self.that.size_hint();
+		(0, a_max.zip(b_max).and_then(|(a, b)| a.checked_add(b)))
+	}
+}
+
+impl<T, O> FusedIterator for SymmetricDifference<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/set/iter/IntoIter.md")]
+pub struct IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-map of the set being consumed.
+	bits: BitVec<T, O>,
+	/// The index from which forward iteration resumes.
+	head: usize,
+	/// The index at which backward iteration resumes.
+	tail: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Takes ownership of a bit-set’s bit-map for iteration.
+	#[inline]
+	pub(super) fn new(set: BitSet<T, O>) -> Self {
+		let bits = set.into_bitvec();
+		let tail = bits.len();
+		Self {
+			bits,
+			head: 0,
+			tail,
+		}
+	}
+
+	/// Views the bits that have not yet been yielded.
+	#[inline]
+	fn remaining(&self) -> &BitSlice<T, O> {
+		unsafe { self.bits.get_unchecked(self.head .. self.tail) }
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> Iterator for IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	easy_iter!();
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.remaining().first_one() {
+			| Some(idx) => {
+				let out = self.head + idx;
+				self.head = out + 1;
+				Some(out)
+			},
+			| None => {
+				self.head = self.tail;
+				None
+			},
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> DoubleEndedIterator for IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match self.remaining().last_one() {
+			| Some(idx) => {
+				self.tail = self.head + idx;
+				Some(self.tail)
+			},
+			| None => {
+				self.tail = self.head;
+				None
+			},
+		}
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> ExactSizeIterator for IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.remaining().count_ones()
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T, O> FusedIterator for IntoIter<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
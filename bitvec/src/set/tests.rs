@@ -0,0 +1,145 @@
+//! Unit tests for bit-sets.
+
+#![cfg(test)]
+
+#[cfg(feature = "alloc")]
+use alloc::{
+	collections::BTreeSet,
+	format,
+	vec::Vec,
+};
+
+#[cfg(feature = "alloc")]
+use rand::random;
+
+use super::BitArraySet;
+#[cfg(feature = "alloc")]
+use super::BitSet;
+use crate::prelude::*;
+
+#[test]
+#[cfg(feature = "alloc")]
+fn insert_remove() {
+	let mut set = BitSet::<u8, Msb0>::new();
+	assert!(set.is_empty());
+	assert!(set.insert(20));
+	assert!(!set.insert(20));
+	assert!(set.insert(3));
+	assert!(set.contains(3));
+	assert!(set.contains(20));
+	assert!(!set.contains(4));
+	assert!(!set.contains(200));
+	assert_eq!(set.len(), 2);
+	assert_eq!(set.first(), Some(3));
+	assert_eq!(set.last(), Some(20));
+
+	assert!(set.remove(20));
+	assert!(!set.remove(20));
+	assert!(!set.remove(500));
+	assert_eq!(set.pop_last(), Some(3));
+	assert_eq!(set.pop_first(), None);
+	assert!(set.is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn algebra() {
+	let a = (0 .. 64)
+		.filter(|_| random::<bool>())
+		.collect::<Vec<usize>>();
+	let b = (0 .. 100)
+		.filter(|_| random::<bool>())
+		.collect::<Vec<usize>>();
+
+	let bs_a = a.iter().collect::<BitSet<u16, Lsb0>>();
+	let bs_b = b.iter().collect::<BitSet<u16, Lsb0>>();
+	let bt_a = a.iter().copied().collect::<BTreeSet<usize>>();
+	let bt_b = b.iter().copied().collect::<BTreeSet<usize>>();
+
+	assert!(bs_a.iter().eq(bt_a.iter().copied()));
+	assert!(bs_a.union(&bs_b).eq(bt_a.union(&bt_b).copied()));
+	assert!(bs_a
+		.intersection(&bs_b)
+		.eq(bt_a.intersection(&bt_b).copied()));
+	assert!(bs_b.difference(&bs_a).eq(bt_b.difference(&bt_a).copied()));
+	assert!(bs_a
+		.symmetric_difference(&bs_b)
+		.eq(bt_a.symmetric_difference(&bt_b).copied()));
+	assert_eq!(bs_a.is_disjoint(&bs_b), bt_a.is_disjoint(&bt_b));
+	assert_eq!(bs_a.is_subset(&bs_b), bt_a.is_subset(&bt_b));
+
+	assert!((&bs_a | &bs_b).iter().eq((&bt_a | &bt_b).into_iter()));
+	assert!((&bs_a & &bs_b).iter().eq((&bt_a & &bt_b).into_iter()));
+	assert!((&bs_a ^ &bs_b).iter().eq((&bt_a ^ &bt_b).into_iter()));
+	assert!((&bs_a - &bs_b).iter().eq((&bt_a - &bt_b).into_iter()));
+
+	let both = &bs_a & &bs_b;
+	assert!(both.is_subset(&bs_a));
+	assert!(bs_b.is_superset(&both));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn into_iter() {
+	let set = [1, 5, 9, 33, 60].iter().collect::<BitSet<u32, Msb0>>();
+	let mut iter = set.clone().into_iter();
+	assert_eq!(iter.len(), 5);
+	assert_eq!(iter.next(), Some(1));
+	assert_eq!(iter.next_back(), Some(60));
+	assert_eq!(iter.next_back(), Some(33));
+	assert_eq!(iter.next(), Some(5));
+	assert_eq!(iter.len(), 1);
+	assert_eq!(iter.next_back(), Some(9));
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+
+	assert_eq!(set.into_iter().rev().collect::<Vec<_>>(), [60, 33, 9, 5, 1]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn equality() {
+	let mut a = BitSet::<usize, Lsb0>::with_capacity(500);
+	let mut b = BitSet::<usize, Lsb0>::new();
+	a.insert(400);
+	a.insert(4);
+	b.insert(4);
+	assert_ne!(a, b);
+	assert!(a.remove(400));
+	assert_eq!(a, b);
+	assert!(a.as_bitslice().len() > b.as_bitslice().len());
+	a.shrink_to_fit();
+	assert_eq!(a.as_bitslice(), b.as_bitslice());
+
+	a.retain(|value| value != 4);
+	assert!(a.is_empty());
+	a.insert(10);
+	b.append(&mut a);
+	assert!(a.is_empty());
+	assert_eq!(format!("{:?}", b), "{4, 10}");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic = "bit-set capacity exceeded"]
+fn insert_max() {
+	BitSet::<u8, Msb0>::new().insert(usize::MAX);
+}
+
+#[test]
+fn array_set() {
+	let mut set = BitArraySet::<[u8; 2], Lsb0>::new();
+	assert_eq!(BitArraySet::<[u8; 2], Lsb0>::CAPACITY, 16);
+	assert!(set.insert(15));
+	assert!(set.insert(0));
+	assert_eq!(set.try_insert(16), Err(16));
+	assert!(!set.remove(16));
+	assert_eq!(set.len(), 2);
+	assert_eq!(set.into_inner().into_inner(), [0x01, 0x80]);
+
+	let other = [0, 7, 8].iter().collect::<BitArraySet<[u8; 2], Lsb0>>();
+	assert!(set.union(&other).eq([0, 7, 8, 15]));
+	assert!(set.intersection(&other).eq([0]));
+	assert!(set.difference(&other).eq([15]));
+	assert!(set.symmetric_difference(&other).eq([7, 8, 15]));
+}
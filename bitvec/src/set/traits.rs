@@ -0,0 +1,341 @@
+//! General trait implementations for bit-sets.
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::FromIterator,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+		Sub,
+	},
+};
+
+use super::{
+	BitSet,
+	IntoIter,
+};
+use crate::{
+	order::BitOrder,
+	slice::IterOnes,
+	store::BitStore,
+	vec::BitVec,
+};
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self::from_bitvec(self.bits.clone())
+	}
+}
+
+impl<T, O> Debug for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_set().entries(self.iter()).finish()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Default for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, O> Eq for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Ord for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.iter().cmp(other.iter())
+	}
+}
+
+/// Sets are equal when they have the same members. The lengths of their
+/// bit-maps are not considered.
+impl<T, O> PartialEq for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		let (this, that) = (self.as_bitslice(), other.as_bitslice());
+		let len = cmp::min(this.len(), that.len());
+		let (this_head, this_rest) = this.split_at(len);
+		let (that_head, that_rest) = that.split_at(len);
+		this_head == that_head && this_rest.not_any() && that_rest.not_any()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> PartialOrd for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Hash for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.iter().for_each(|value| value.hash(hasher));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitVec<T, O>> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitVec<T, O>) -> Self {
+		Self::from_bitvec(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> From<BitSet<T, O>> for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(set: BitSet<T, O>) -> Self {
+		set.into_bitvec()
+	}
+}
+
+impl<T, O> Extend<usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = usize> {
+		iter.into_iter().for_each(|value| {
+			self.insert(value);
+		});
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> Extend<&'a usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a usize> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+impl<T, O> FromIterator<usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = usize> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> FromIterator<&'a usize> for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = &'a usize> {
+		iter.into_iter().copied().collect()
+	}
+}
+
+impl<T, O> IntoIterator for BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type IntoIter = IntoIter<T, O>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		IntoIter::new(self)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T, O> IntoIterator for &'a BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type IntoIter = IterOnes<'a, T, O>;
+	type Item = usize;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Computes the union of two sets as a new set.
+///
+/// ## Original
+///
+/// [`impl BitOr for
+/// &BTreeSet`](alloc::collections::BTreeSet#impl-BitOr%3C%26BTreeSet%3CT,+A%3E%
+/// 3E-for-%26BTreeSet%3CT,+A%3E)
+impl<T, O> BitOr for &BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSet<T, O>;
+
+	#[inline]
+	fn bitor(self, rhs: Self) -> Self::Output {
+		let (long, short) =
+			if self.as_bitslice().len() >= rhs.as_bitslice().len() {
+				(self, rhs)
+			}
+			else {
+				(rhs, self)
+			};
+		let mut out = long.bits.clone();
+		*out.as_mut_bitslice() |= short.as_bitslice();
+		BitSet::from_bitvec(out)
+	}
+}
+
+/// Computes the intersection of two sets as a new set.
+///
+/// ## Original
+///
+/// [`impl BitAnd for
+/// &BTreeSet`](alloc::collections::BTreeSet#impl-BitAnd%3C%26BTreeSet%3CT,+A%
+/// 3E%3E-for-%26BTreeSet%3CT,+A%3E)
+impl<T, O> BitAnd for &BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSet<T, O>;
+
+	#[inline]
+	fn bitand(self, rhs: Self) -> Self::Output {
+		let len = cmp::min(self.as_bitslice().len(), rhs.as_bitslice().len());
+		let mut out = self.bits.clone();
+		out.truncate(len);
+		*out.as_mut_bitslice() &= &rhs.as_bitslice()[.. len];
+		BitSet::from_bitvec(out)
+	}
+}
+
+/// Computes the symmetric difference of two sets as a new set.
+///
+/// ## Original
+///
+/// [`impl BitXor for
+/// &BTreeSet`](alloc::collections::BTreeSet#impl-BitXor%3C%26BTreeSet%3CT,+A%
+/// 3E%3E-for-%26BTreeSet%3CT,+A%3E)
+impl<T, O> BitXor for &BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSet<T, O>;
+
+	#[inline]
+	fn bitxor(self, rhs: Self) -> Self::Output {
+		let (long, short) =
+			if self.as_bitslice().len() >= rhs.as_bitslice().len() {
+				(self, rhs)
+			}
+			else {
+				(rhs, self)
+			};
+		let mut out = long.bits.clone();
+		*out.as_mut_bitslice() ^= short.as_bitslice();
+		BitSet::from_bitvec(out)
+	}
+}
+
+/// Computes the difference of two sets as a new set.
+///
+/// ## Original
+///
+/// [`impl Sub for
+/// &BTreeSet`](alloc::collections::BTreeSet#impl-Sub%3C%26BTreeSet%3CT,+A%3E%
+/// 3E-for-%26BTreeSet%3CT,+A%3E)
+impl<T, O> Sub for &BitSet<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitSet<T, O>;
+
+	#[inline]
+	fn sub(self, rhs: Self) -> Self::Output {
+		let mut out = self.bits.clone();
+		for idx in self.intersection(rhs) {
+			out.set(idx, false);
+		}
+		BitSet::from_bitvec(out)
+	}
+}
@@ -3,6 +3,7 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::{
+	cmp,
 	marker::PhantomData,
 	ops::RangeBounds,
 };
@@ -27,6 +28,7 @@ use crate::{
 		BitDomain,
 		Domain,
 	},
+	index::BitIdx,
 	mem,
 	order::{
 		BitOrder,
@@ -44,15 +46,37 @@ use crate::{
 };
 
 mod api;
+mod atomic;
+mod extract;
 mod iter;
+mod metric;
 mod ops;
+mod par;
+mod parse;
+mod runs;
+mod search;
 mod specialization;
 mod tests;
 mod traits;
 
+#[cfg(feature = "rayon")]
+pub use self::par::{
+	ParChunks,
+	ParChunksMut,
+	ParIterOnes,
+};
+pub(crate) use self::parse::parse_bits;
+use self::specialization::simd;
 pub use self::{
 	api::*,
 	iter::*,
+	parse::ParseBitsError,
+	runs::{
+		IterOneRuns,
+		IterRuns,
+		IterZeroRuns,
+	},
+	search::MatchIndices,
 };
 
 #[repr(transparent)]
@@ -1064,14 +1088,16 @@ where
 			| Domain::Enclave(elem) => elem.load_value().count_ones() as usize,
 			| Domain::Region { head, body, tail } => {
 				head.map_or(0, |elem| elem.load_value().count_ones() as usize)
-					+ body
-						.iter()
-						.map(BitStore::load_value)
-						.map(|elem| elem.count_ones() as usize)
-						.sum::<usize>()
-					+ tail.map_or(0, |elem| {
-						elem.load_value().count_ones() as usize
-					})
+					+ simd::bytes(body).map_or_else(
+						|| {
+							body.iter()
+								.map(BitStore::load_value)
+								.map(|elem| elem.count_ones() as usize)
+								.sum::<usize>()
+						},
+						simd::count_ones,
+					) + tail
+					.map_or(0, |elem| elem.load_value().count_ones() as usize)
 			},
 		}
 	}
@@ -1489,10 +1515,7 @@ where
 			len,
 		);
 
-		unsafe {
-			self.copy_within_unchecked(by .., 0);
-			self.get_unchecked_mut(len - by ..).fill(false);
-		}
+		self.shift_start_fill(by, false);
 	}
 
 	#[inline]
@@ -1556,10 +1579,7 @@ where
 			len,
 		);
 
-		unsafe {
-			self.copy_within_unchecked(.. len - by, by);
-			self.get_unchecked_mut(.. by).fill(false);
-		}
+		self.shift_end_fill(by, false);
 	}
 
 	#[inline]
@@ -1569,6 +1589,193 @@ where
 	pub fn shift_right(&mut self, by: usize) {
 		self.shift_end(by);
 	}
+
+	/// Shifts the contents of a bit-slice “left” (towards the zero-index),
+	/// filling the vacated “right” bits with the value of the last bit.
+	///
+	/// This is an arithmetic shift for bit-slices whose last bit is the most
+	/// significant, such as a `BitArray<_, Lsb0>` used as a little-endian
+	/// register: the sign bit is preserved, and copied into each bit that is
+	/// vacated. See [`.shift_start()`] for the logical shift that fills with
+	/// `0`.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
+	/// bit-slice is entirely filled with its last bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 0, 1, 1, 0, 1];
+	/// bits.shift_start_signed(2);
+	/// assert_eq!(bits, bits![1, 1, 0, 1, 1, 1]);
+	///
+	/// let mut arr = bitarr![u8, Lsb0; 0; 8];
+	/// arr.store_le(-100i8);
+	/// arr.shift_start_signed(3);
+	/// assert_eq!(arr.load_le::<i8>(), -100 >> 3);
+	/// ```
+	///
+	/// [`.shift_start()`]: Self::shift_start
+	#[inline]
+	pub fn shift_start_signed(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+		let len = self.len();
+		assert!(
+			by <= len,
+			"shift must not exceed the length of the bit-slice: {} > {}",
+			by,
+			len,
+		);
+		let sign = unsafe { *self.get_unchecked(len - 1) };
+		self.shift_start_fill(by, sign);
+	}
+
+	/// Shifts the contents of a bit-slice “right” (away from the zero-index),
+	/// filling the vacated “left” bits with the value of the first bit.
+	///
+	/// This is an arithmetic shift for bit-slices whose first bit is the most
+	/// significant, such as a `BitArray<_, Msb0>` used as a big-endian
+	/// register: the sign bit is preserved, and copied into each bit that is
+	/// vacated. See [`.shift_end()`] for the logical shift that fills with
+	/// `0`.
+	///
+	/// This has no effect when `by` is `0`. When `by` is `self.len()`, the
+	/// bit-slice is entirely filled with its first bit.
+	///
+	/// ## Panics
+	///
+	/// This panics if `by` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 1, 1, 0, 0];
+	/// bits.shift_end_signed(2);
+	/// assert_eq!(bits, bits![1, 1, 1, 0, 1, 1]);
+	///
+	/// let mut arr = bitarr![u16, Msb0; 0; 16];
+	/// arr.store_be(-1000i16);
+	/// arr.shift_end_signed(5);
+	/// assert_eq!(arr.load_be::<i16>(), -1000 >> 5);
+	/// ```
+	///
+	/// [`.shift_end()`]: Self::shift_end
+	#[inline]
+	pub fn shift_end_signed(&mut self, by: usize) {
+		if by == 0 {
+			return;
+		}
+		let len = self.len();
+		assert!(
+			by <= len,
+			"shift must not exceed the length of the bit-slice: {} > {}",
+			by,
+			len,
+		);
+		let sign = unsafe { *self.get_unchecked(0) };
+		self.shift_end_fill(by, sign);
+	}
+
+	/// Rotates the contents of a bit-slice “left” (towards the zero-index),
+	/// passing through an external carry bit.
+	///
+	/// The bit-slice and the carry bit are treated as a single ring of
+	/// `self.len() + 1` bits, with the carry bit sitting after the last bit of
+	/// the bit-slice. Bits that leave the front of the bit-slice move into the
+	/// carry, and the old carry moves into the back of the bit-slice. This is
+	/// the semantic equivalent of a rotate-through-carry instruction.
+	///
+	/// `by` is taken modulo `self.len() + 1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 0, 1, 1];
+	/// let mut carry = false;
+	///
+	/// bits.rotate_start_through_carry(1, &mut carry);
+	/// assert_eq!(bits, bits![0, 0, 1, 1, 0]);
+	/// assert!(carry);
+	///
+	/// bits.rotate_start_through_carry(2, &mut carry);
+	/// assert_eq!(bits, bits![1, 1, 0, 1, 0]);
+	/// assert!(!carry);
+	/// ```
+	#[inline]
+	pub fn rotate_start_through_carry(&mut self, by: usize, carry: &mut bool) {
+		let len = self.len();
+		let by = by % (len + 1);
+		if by == 0 {
+			return;
+		}
+		//  The bit at `by - 1` is the last to leave the front, and so becomes
+		//  the new carry. The old carry enters the back ahead of the `by - 1`
+		//  bits that wrap around.
+		let old = *carry;
+		*carry = unsafe { *self.get_unchecked(by - 1) };
+		self.rotate_left(by);
+		let wrapped = unsafe { self.get_unchecked_mut(len - by ..) };
+		wrapped.shift_end(1);
+		unsafe {
+			wrapped.set_unchecked(0, old);
+		}
+	}
+
+	/// Rotates the contents of a bit-slice “right” (away from the zero-index),
+	/// passing through an external carry bit.
+	///
+	/// The bit-slice and the carry bit are treated as a single ring of
+	/// `self.len() + 1` bits, with the carry bit sitting before the first bit
+	/// of the bit-slice. Bits that leave the back of the bit-slice move into
+	/// the carry, and the old carry moves into the front of the bit-slice.
+	/// This is the semantic equivalent of a rotate-through-carry instruction.
+	///
+	/// `by` is taken modulo `self.len() + 1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 1, 0, 0, 1, 1];
+	/// let mut carry = false;
+	///
+	/// bits.rotate_end_through_carry(1, &mut carry);
+	/// assert_eq!(bits, bits![0, 1, 0, 0, 1]);
+	/// assert!(carry);
+	///
+	/// bits.rotate_end_through_carry(2, &mut carry);
+	/// assert_eq!(bits, bits![1, 1, 0, 1, 0]);
+	/// assert!(!carry);
+	/// ```
+	#[inline]
+	pub fn rotate_end_through_carry(&mut self, by: usize, carry: &mut bool) {
+		let len = self.len();
+		let by = by % (len + 1);
+		if by == 0 {
+			return;
+		}
+		let old = *carry;
+		*carry = unsafe { *self.get_unchecked(len - by) };
+		self.rotate_right(by);
+		let wrapped = unsafe { self.get_unchecked_mut(.. by) };
+		wrapped.shift_start(1);
+		unsafe {
+			wrapped.set_unchecked(by - 1, old);
+		}
+	}
 }
 
 /// Crate internals.
@@ -1577,6 +1784,85 @@ where
 	T: BitStore,
 	O: BitOrder,
 {
+	/// Shifts the bit-slice towards the zero-index, and sets the vacated bits
+	/// at the back to `fill`.
+	///
+	/// `by` must not exceed the length of the bit-slice.
+	pub(crate) fn shift_start_fill(&mut self, by: usize, fill: bool) {
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			return this.sp_shift_start(by, fill);
+		}
+		if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			return this.sp_shift_start(by, fill);
+		}
+		let len = self.len();
+		unsafe {
+			self.copy_within_unchecked(by .., 0);
+			self.get_unchecked_mut(len - by ..).fill(fill);
+		}
+	}
+
+	/// Shifts the bit-slice away from the zero-index, and sets the vacated
+	/// bits at the front to `fill`.
+	///
+	/// `by` must not exceed the length of the bit-slice.
+	pub(crate) fn shift_end_fill(&mut self, by: usize, fill: bool) {
+		if let Some(this) = self.coerce_mut::<T, Lsb0>() {
+			return this.sp_shift_end(by, fill);
+		}
+		if let Some(this) = self.coerce_mut::<T, Msb0>() {
+			return this.sp_shift_end(by, fill);
+		}
+		let len = self.len();
+		unsafe {
+			self.copy_within_unchecked(.. len - by, by);
+			self.get_unchecked_mut(.. by).fill(fill);
+		}
+	}
+
+	/// Tests whether the ordering places the zero-index at the least
+	/// significant bit of an element.
+	///
+	/// This decides which end of the bit-slice is “high” when it is treated as
+	/// a wide integer register.
+	pub(crate) fn zero_index_is_lsb() -> bool {
+		BitIdx::<T::Mem>::MIN.position::<O>().into_inner() == 0
+	}
+
+	/// Shifts the bit-slice towards its most significant end, as the `<<`
+	/// operator does on integers, clearing the vacated bits.
+	///
+	/// Shifting by the length of the bit-slice or more clears it.
+	pub(crate) fn shift_register_up(&mut self, by: usize) {
+		let by = cmp::min(by, self.len());
+		if by == 0 {
+			return;
+		}
+		if Self::zero_index_is_lsb() {
+			self.shift_end_fill(by, false);
+		}
+		else {
+			self.shift_start_fill(by, false);
+		}
+	}
+
+	/// Shifts the bit-slice towards its least significant end, as the `>>`
+	/// operator does on unsigned integers, clearing the vacated bits.
+	///
+	/// Shifting by the length of the bit-slice or more clears it.
+	pub(crate) fn shift_register_down(&mut self, by: usize) {
+		let by = cmp::min(by, self.len());
+		if by == 0 {
+			return;
+		}
+		if Self::zero_index_is_lsb() {
+			self.shift_start_fill(by, false);
+		}
+		else {
+			self.shift_end_fill(by, false);
+		}
+	}
+
 	/// Gets the structural form of the encoded reference.
 	pub(crate) fn as_bitspan(&self) -> BitSpan<Const, T, O> {
 		BitSpan::from_bitslice_ptr(self)
@@ -1809,6 +2095,131 @@ where
 				bv.set_len(self.len());
 			})
 	}
+
+	/// Copies a bit-slice into an owned bit-vector that is at least `len` bits
+	/// long.
+	///
+	/// If `len` is greater than the length of `self`, then the new bit-vector
+	/// has `0` bits appended to it until it reaches `len`. Otherwise, this is
+	/// equivalent to [`.to_bitvec()`].
+	///
+	/// This is the explicit form of zero-extension for the non-assigning
+	/// Boolean operators, which truncate to the shorter operand.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1];
+	/// assert_eq!(bits.zero_extended(4), bits![1, 1, 0, 0]);
+	/// assert_eq!(bits.zero_extended(1), bits![1, 1]);
+	/// ```
+	///
+	/// [`.to_bitvec()`]: Self::to_bitvec
+	#[inline]
+	pub fn zero_extended(&self, len: usize) -> BitVec<T::Unalias, O> {
+		let mut out = self.to_bitvec();
+		if len > out.len() {
+			out.resize(len, false);
+		}
+		out
+	}
+
+	/// Computes `self & !rhs` into a new bit-vector.
+	///
+	/// Like the `&` operator, this truncates to the shorter operand.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.and_not(b), bits![0, 0, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn and_not<T2, O2>(
+		&self,
+		rhs: &BitSlice<T2, O2>,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		//  `(a & b) ^ a` is `a & !b`, and keeps both passes accelerated.
+		let mut out = self & rhs;
+		let len = out.len();
+		*out.as_mut_bitslice() ^= unsafe { self.get_unchecked(.. len) };
+		out
+	}
+
+	/// Computes `!(self & rhs)` into a new bit-vector.
+	///
+	/// Like the `&` operator, this truncates to the shorter operand.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.nand(b), bits![1, 1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn nand<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!(self & rhs)
+	}
+
+	/// Computes `!(self | rhs)` into a new bit-vector.
+	///
+	/// Like the `|` operator, this truncates to the shorter operand.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.nor(b), bits![1, 0, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn nor<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!(self | rhs)
+	}
+
+	/// Computes `!(self ^ rhs)` into a new bit-vector.
+	///
+	/// Like the `^` operator, this truncates to the shorter operand.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 0, 1, 1];
+	/// let b = bits![0, 1, 0, 1];
+	/// assert_eq!(a.xnor(b), bits![1, 0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn xnor<T2, O2>(&self, rhs: &BitSlice<T2, O2>) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		!(self ^ rhs)
+	}
 }
 
 #[inline]
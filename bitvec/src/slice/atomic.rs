@@ -0,0 +1,365 @@
+#![doc = include_str!("../../doc/slice/atomic.md")]
+
+use core::{
+	ops::{
+		Range,
+		RangeBounds,
+	},
+	slice,
+	sync::atomic::Ordering,
+};
+
+use funty::Integral;
+use radium::Radium;
+use wyz::range::RangeExt;
+
+use super::BitSlice;
+use crate::{
+	index::BitIdx,
+	mem,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Atomic read-modify-write operations.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Radium<Item = <T as BitStore>::Mem> + Sync,
+	O: BitOrder,
+{
+	/// Atomically sets a bit to `1`, returning its previous value.
+	///
+	/// This is a single `fetch_or` on the element containing the bit, so it
+	/// can be used as a lock-free test-and-set.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to set. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write instruction.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit immediately before it was set.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicUsize, Ordering};
+	///
+	/// let data = AtomicUsize::new(0);
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert!(!bits.fetch_set(3, Ordering::AcqRel));
+	/// assert!(bits.fetch_set(3, Ordering::AcqRel));
+	/// assert_eq!(data.into_inner(), 8);
+	/// ```
+	#[inline]
+	pub fn fetch_set(&self, index: usize, order: Ordering) -> bool {
+		let (elem, bit) = self.locate(index);
+		let select = bit.select::<O>().into_inner();
+		elem.fetch_or(select, order) & select != <T::Mem as Integral>::ZERO
+	}
+
+	/// Atomically clears a bit to `0`, returning its previous value.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to clear. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write instruction.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit immediately before it was cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = AtomicU8::new(0b1000_0000);
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert!(bits.fetch_clear(0, Ordering::Release));
+	/// assert!(!bits.fetch_clear(0, Ordering::Release));
+	/// assert_eq!(data.into_inner(), 0);
+	/// ```
+	#[inline]
+	pub fn fetch_clear(&self, index: usize, order: Ordering) -> bool {
+		let (elem, bit) = self.locate(index);
+		let select = bit.select::<O>().into_inner();
+		elem.fetch_and(!select, order) & select != <T::Mem as Integral>::ZERO
+	}
+
+	/// Atomically inverts a bit, returning its previous value.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `index`: The bit to invert. It must be in `0 .. self.len()`.
+	/// - `order`: The memory ordering of the read-modify-write instruction.
+	///
+	/// ## Returns
+	///
+	/// The value of the bit immediately before it was inverted.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU16, Ordering};
+	///
+	/// let data = AtomicU16::new(0);
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert!(!bits.fetch_toggle(15, Ordering::Relaxed));
+	/// assert!(bits.fetch_toggle(15, Ordering::Relaxed));
+	/// assert_eq!(data.into_inner(), 0);
+	/// ```
+	#[inline]
+	pub fn fetch_toggle(&self, index: usize, order: Ordering) -> bool {
+		let (elem, bit) = self.locate(index);
+		let select = bit.select::<O>().into_inner();
+		elem.fetch_xor(select, order) & select != <T::Mem as Integral>::ZERO
+	}
+
+	/// Atomically replaces a field of bits, if it holds an expected value.
+	///
+	/// The field is the bits of `self[range]`, which must all lie in the same
+	/// memory element. Its value is those bits, gathered together in order of
+	/// ascending significance within the element, so that the least
+	/// significant bit of the field is the least significant bit of the value.
+	/// This is the same value that [`BitField`] loads from and stores into
+	/// `self[range]`, for every ordering. The field does not need to be
+	/// contiguous in the element.
+	///
+	/// The replacement is performed with [`Radium::fetch_update`], so the
+	/// other bits of the element may be freely modified by other threads while
+	/// this runs. It only fails if the field itself does not match `current`.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `range`: The bits of the field.
+	/// - `current`: The value that the field must have in order to be replaced.
+	/// - `new`: The value to write into the field. Bits of `new` that do not
+	///   fit in the field are discarded.
+	/// - `order`: The memory ordering of the successful read-modify-write.
+	///   Failed comparisons load the field with the strongest ordering that a
+	///   load permits, which is `Acquire` for `AcqRel` and `Relaxed` for
+	///   `Release`.
+	///
+	/// ## Returns
+	///
+	/// `Ok` with the previous value of the field if it was replaced, or `Err`
+	/// with its current value if it did not match `current`.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds, is empty, or crosses an
+	/// element boundary.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU32, Ordering};
+	///
+	/// let data = AtomicU32::new(0);
+	/// let bits = data.view_bits::<Lsb0>();
+	/// let order = Ordering::AcqRel;
+	///
+	/// assert_eq!(bits.compare_exchange_field(4 .. 12, 0, 0xA5, order), Ok(0));
+	/// assert_eq!(
+	///   bits.compare_exchange_field(4 .. 12, 0, 0x5A, order),
+	///   Err(0xA5),
+	/// );
+	/// assert_eq!(bits[4 .. 12].load::<u8>(), 0xA5);
+	/// assert_eq!(data.into_inner(), 0xA50);
+	/// ```
+	///
+	/// [`BitField`]: crate::field::BitField
+	#[inline]
+	pub fn compare_exchange_field<R>(
+		&self,
+		range: R,
+		current: T::Mem,
+		new: T::Mem,
+		order: Ordering,
+	) -> Result<T::Mem, T::Mem>
+	where
+		R: RangeBounds<usize>,
+	{
+		let bits = self.subslice(range);
+		let mut elems = bits.masked_elements();
+		let (elem, mask) = match (elems.next(), elems.next()) {
+			| (Some(only), None) => only,
+			| _ => panic!(
+				"fields must contain at least one bit and lie within one \
+				 element, but this field has {} bits and covers {} elements",
+				bits.len(),
+				bits.masked_elements().count(),
+			),
+		};
+		let field = |elem: T::Mem| mem::extract(elem, mask);
+		let new = mem::deposit(new, mask);
+		elem.fetch_update(order, load_order(order), |elem| {
+			if field(elem) == current {
+				Some(elem & !mask | new)
+			}
+			else {
+				None
+			}
+		})
+		.map(field)
+		.map_err(field)
+	}
+
+	/// Atomically sets every bit in a range to `1`.
+	///
+	/// Each memory element that the range touches is modified by exactly one
+	/// `fetch_or`. The update as a whole is not atomic: other threads may
+	/// observe some elements before they are written and others after.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `range`: The bits to set.
+	/// - `order`: The memory ordering of each read-modify-write instruction.
+	///
+	/// ## Returns
+	///
+	/// The number of bits in the range that were `0` before they were set.
+	/// When this is the length of the range, no other thread had set any of
+	/// its bits.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU8, Ordering};
+	///
+	/// let data = [AtomicU8::new(0), AtomicU8::new(0), AtomicU8::new(0)];
+	/// let bits = data.view_bits::<Lsb0>();
+	/// assert_eq!(bits.fetch_set_range(4 .. 20, Ordering::AcqRel), 16);
+	/// assert_eq!(bits.fetch_set_range(0 .. 6, Ordering::AcqRel), 4);
+	/// assert_eq!(bits.count_ones(), 20);
+	/// ```
+	#[inline]
+	pub fn fetch_set_range<R>(&self, range: R, order: Ordering) -> usize
+	where R: RangeBounds<usize> {
+		self.subslice(range)
+			.masked_elements()
+			.map(|(elem, mask)| {
+				(!elem.fetch_or(mask, order) & mask).count_ones() as usize
+			})
+			.sum()
+	}
+
+	/// Atomically clears every bit in a range to `0`.
+	///
+	/// Each memory element that the range touches is modified by exactly one
+	/// `fetch_and`. The update as a whole is not atomic: other threads may
+	/// observe some elements before they are written and others after.
+	///
+	/// ## Parameters
+	///
+	/// - `&self`
+	/// - `range`: The bits to clear.
+	/// - `order`: The memory ordering of each read-modify-write instruction.
+	///
+	/// ## Returns
+	///
+	/// The number of bits in the range that were `1` before they were
+	/// cleared.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::sync::atomic::{AtomicU64, Ordering};
+	///
+	/// let data = [AtomicU64::new(!0), AtomicU64::new(!0)];
+	/// let bits = data.view_bits::<Msb0>();
+	/// assert_eq!(bits.fetch_clear_range(60 .. 70, Ordering::AcqRel), 10);
+	/// assert_eq!(bits.fetch_clear_range(50 .. 65, Ordering::AcqRel), 10);
+	/// assert_eq!(bits.count_zeros(), 20);
+	/// ```
+	#[inline]
+	pub fn fetch_clear_range<R>(&self, range: R, order: Ordering) -> usize
+	where R: RangeBounds<usize> {
+		self.subslice(range)
+			.masked_elements()
+			.map(|(elem, mask)| {
+				(elem.fetch_and(!mask, order) & mask).count_ones() as usize
+			})
+			.sum()
+	}
+
+	/// Takes the subslice selected by a range.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds.
+	fn subslice<R>(&self, range: R) -> &Self
+	where R: RangeBounds<usize> {
+		let Range { start, end } = range.normalize(0, self.len());
+		&self[start .. end]
+	}
+
+	/// Finds the memory element and bit that an index refers to.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	fn locate(&self, index: usize) -> (&T, BitIdx<T::Mem>) {
+		self.assert_in_bounds(index, 0 .. self.len());
+		let (addr, bit) = unsafe { self.as_bitptr().add(index) }.raw_parts();
+		(unsafe { &*addr.to_const() }, bit)
+	}
+
+	/// Produces each memory element that the bit-slice touches, along with
+	/// the mask of its bits that the bit-slice governs.
+	pub(crate) fn masked_elements(
+		&self,
+	) -> impl '_ + Iterator<Item = (&T, T::Mem)> {
+		let (addr, head) = self.as_bitptr().raw_parts();
+		let (elts, tail) = head.span(self.len());
+		let elems = unsafe { slice::from_raw_parts(addr.to_const(), elts) };
+		elems.iter().enumerate().map(move |(idx, elem)| {
+			let from = if idx == 0 { Some(head) } else { None };
+			let upto = if idx + 1 == elts { Some(tail) } else { None };
+			(elem, O::mask(from, upto).into_inner())
+		})
+	}
+}
+
+/// Produces the ordering used to load an element when an atomic update does
+/// not store to it. Loads may not have `Release` semantics.
+fn load_order(order: Ordering) -> Ordering {
+	match order {
+		| Ordering::Release => Ordering::Relaxed,
+		| Ordering::AcqRel => Ordering::Acquire,
+		| order => order,
+	}
+}
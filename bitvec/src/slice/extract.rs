@@ -0,0 +1,189 @@
+#![doc = include_str!("../../doc/slice/extract.md")]
+
+use super::{
+	metric::Bits,
+	BitSlice,
+};
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+use crate::{
+	field::BitField,
+	index::BitMask,
+	mem::bits_of,
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	store::BitStore,
+	view::BitView,
+};
+
+/// Parallel bit extraction and deposit.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Gathers the bits of `self` that a mask selects into a new bit-vector.
+	///
+	/// Each bit of `self` whose counterpart in `mask` is `1` is copied into the
+	/// output, in order; the other bits are skipped. The output is as long as
+	/// `mask.count_ones()`. The mask does not need to share type parameters
+	/// with the bit-slice.
+	///
+	/// This applies [`BitMask::extract`] to each `usize`-wide stretch of the
+	/// two bit-slices, and so uses the BMI2 `pext` instruction where it is
+	/// available.
+	///
+	/// ## Panics
+	///
+	/// This panics if `mask` is not as long as `self`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 0, 0, 1];
+	/// let mask = bits![u8, Lsb0; 1, 1, 0, 0, 1, 1, 0, 1];
+	/// assert_eq!(bits.extract_by(mask), bits![0, 1, 1, 0, 1]);
+	/// ```
+	#[inline]
+	#[cfg(feature = "alloc")]
+	pub fn extract_by<T2, O2>(
+		&self,
+		mask: &BitSlice<T2, O2>,
+	) -> BitVec<T::Unalias, O>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.assert_mask_len(mask);
+		let mut out = BitVec::repeat(false, mask.count_ones());
+		let mut filled = 0;
+		for (chunk, mask) in self
+			.chunks(bits_of::<usize>())
+			.zip(mask.chunks(bits_of::<usize>()))
+		{
+			let mask = Bits::load(mask);
+			if mask == 0 {
+				continue;
+			}
+			let width = mask.count_ones() as usize;
+			let bits = BitMask::new(mask).extract(Bits::load(chunk));
+			store(&mut out[filled .. filled + width], bits);
+			filled += width;
+		}
+		out
+	}
+
+	/// Scatters the bits of a source bit-slice into the bits of `self` that a
+	/// mask selects.
+	///
+	/// Each bit of `self` whose counterpart in `mask` is `1` is overwritten by
+	/// the next bit of `src`, in order; the other bits are left unchanged. Only
+	/// the first `mask.count_ones()` bits of `src` are used. Neither `mask` nor
+	/// `src` needs to share type parameters with the bit-slice.
+	///
+	/// This is the inverse of [`.extract_by()`], and applies
+	/// [`BitMask::deposit`] to each `usize`-wide stretch of the bit-slices, so
+	/// it uses the BMI2 `pdep` instruction where it is available.
+	///
+	/// ## Panics
+	///
+	/// This panics if `mask` is not as long as `self`, or if `src` has fewer
+	/// bits than `mask` has `1` bits.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![mut 0, 1, 1, 0, 1, 0, 0, 1];
+	/// let mask = bits![u8, Lsb0; 1, 1, 0, 0, 1, 1, 0, 1];
+	/// bits.deposit_by(mask, bits![1, 0, 0, 1, 0]);
+	/// assert_eq!(bits, bits![1, 0, 1, 0, 0, 1, 0, 0]);
+	/// ```
+	///
+	/// [`.extract_by()`]: Self::extract_by
+	#[inline]
+	pub fn deposit_by<T2, O2, T3, O3>(
+		&mut self,
+		mask: &BitSlice<T2, O2>,
+		src: &BitSlice<T3, O3>,
+	) where
+		T2: BitStore,
+		O2: BitOrder,
+		T3: BitStore,
+		O3: BitOrder,
+	{
+		self.assert_mask_len(mask);
+		let needed = mask.count_ones();
+		assert!(
+			src.len() >= needed,
+			"deposit source has {} bits, but the mask selects {}",
+			src.len(),
+			needed,
+		);
+		let mut src = src;
+		for (chunk, mask) in self
+			.chunks_mut(bits_of::<usize>())
+			.zip(mask.chunks(bits_of::<usize>()))
+		{
+			let mask = Bits::load(mask);
+			if mask == 0 {
+				continue;
+			}
+			let (bits, rest) =
+				unsafe { src.split_at_unchecked(mask.count_ones() as usize) };
+			src = rest;
+			let word = Bits::load(chunk) & !mask
+				| BitMask::new(mask).deposit(Bits::load(bits));
+			store(chunk, word);
+		}
+	}
+
+	/// Asserts that a mask bit-slice is as long as `self`.
+	///
+	/// ## Panics
+	///
+	/// This panics if the lengths differ.
+	#[inline]
+	fn assert_mask_len<T2, O2>(&self, mask: &BitSlice<T2, O2>)
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		assert_eq!(
+			self.len(),
+			mask.len(),
+			"masking a bit-slice requires equal lengths",
+		);
+	}
+}
+
+/// Stores a word into a bit-slice no wider than a `usize`, with the least
+/// significant bit of the word going into the first bit of the bit-slice.
+///
+/// This is the inverse of [`Bits::load`].
+fn store<T, O>(bits: &mut BitSlice<T, O>, word: usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = bits.len();
+	if len == 0 {
+		return;
+	}
+	if let Some(bits) = bits.coerce_mut::<T, Lsb0>() {
+		bits.store_le(word);
+	}
+	else if let Some(bits) = bits.coerce_mut::<T, Msb0>() {
+		//  `Msb0` places the first bit in the most significant position.
+		bits.store_be(word.reverse_bits() >> (bits_of::<usize>() - len));
+	}
+	else {
+		bits.clone_from_bitslice(&word.view_bits::<Lsb0>()[.. len]);
+	}
+}
@@ -1513,6 +1513,25 @@ new_group! {
 	RChunksMut mut .alias_mut(),
 }
 
+#[cfg(feature = "rayon")]
+impl<'a, T, O> ChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Resumes chunking a bit-slice that has already been marked as aliased.
+	///
+	/// The parallel chunker divides its source before handing each piece to a
+	/// serial chunker, and must not apply a second alias marker.
+	#[inline]
+	pub(super) fn from_aliased(
+		slice: &'a mut BitSlice<T::Alias, O>,
+		width: usize,
+	) -> Self {
+		Self { slice, width }
+	}
+}
+
 /// Creates splitting iterators.
 macro_rules! split {
 	(
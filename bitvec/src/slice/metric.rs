@@ -0,0 +1,371 @@
+#![doc = include_str!("../../doc/slice/metric.md")]
+
+use core::{
+	marker::PhantomData,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+	},
+	slice,
+};
+
+use wyz::comu::Const;
+
+use super::BitSlice;
+use crate::{
+	devel as dvl,
+	domain::{
+		Domain,
+		PartialElement,
+	},
+	index::{
+		BitEnd,
+		BitIdx,
+	},
+	mem::{
+		bits_of,
+		BitRegister,
+	},
+	order::{
+		BitOrder,
+		Lsb0,
+		Msb0,
+	},
+	store::BitStore,
+};
+
+/// Pairwise population counts.
+///
+/// Each of these treats the shorter bit-slice as if it were extended with `0`
+/// bits to the length of the longer, just as the Boolean-arithmetic operators
+/// do.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Counts the bits that are set to `1` in both `self` and `other`.
+	///
+	/// This is equivalent to `(self & other).count_ones()`, but does not
+	/// allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![u8, Msb0; 0, 1, 1, 0, 1];
+	/// let b = bits![u16, Lsb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.count_ones_and(b), 2);
+	/// ```
+	#[inline]
+	pub fn count_ones_and<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_ones_with(other, BitAnd::bitand)
+	}
+
+	/// Counts the bits that are set to `1` in either `self` or `other`.
+	///
+	/// This is equivalent to `(self | other).count_ones()`, but does not
+	/// allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![u8, Msb0; 0, 1, 1, 0, 1];
+	/// let b = bits![u16, Lsb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.count_ones_or(b), 5);
+	/// ```
+	#[inline]
+	pub fn count_ones_or<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_ones_with(other, BitOr::bitor)
+	}
+
+	/// Counts the bits that are set to `1` in exactly one of `self` and
+	/// `other`.
+	///
+	/// This is equivalent to `(self ^ other).count_ones()`, but does not
+	/// allocate.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![u8, Msb0; 0, 1, 1, 0, 1];
+	/// let b = bits![u16, Lsb0; 1, 1, 0, 0, 1, 1];
+	/// assert_eq!(a.count_ones_xor(b), 3);
+	/// ```
+	#[inline]
+	pub fn count_ones_xor<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_ones_with(other, BitXor::bitxor)
+	}
+
+	/// Counts the positions at which `self` and `other` differ.
+	///
+	/// This is another name for [`.count_ones_xor()`]. Bit-slices of different
+	/// lengths differ at every `1` bit in the overhang of the longer one.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![1, 1, 0, 0, 1];
+	/// assert_eq!(a.hamming_distance(b), 2);
+	/// ```
+	///
+	/// [`.count_ones_xor()`]: Self::count_ones_xor
+	#[inline]
+	#[cfg(not(tarpaulin_include))]
+	pub fn hamming_distance<T2, O2>(&self, other: &BitSlice<T2, O2>) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		self.count_ones_xor(other)
+	}
+
+	/// Computes the Jaccard similarity of `self` and `other`.
+	///
+	/// Viewing each bit-slice as the set of indices of its `1` bits, this is
+	/// the size of their intersection divided by the size of their union. For
+	/// binary vectors, this is also known as the Tanimoto coefficient.
+	///
+	/// Two bit-slices with no `1` bits at all are considered identical, and
+	/// have a similarity of `1.0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![0, 1, 1, 0, 1];
+	/// let b = bits![1, 1, 0, 0, 1];
+	/// assert_eq!(a.jaccard(b), 0.5);
+	/// assert_eq!(bits![0; 4].jaccard(bits![0; 2]), 1.0);
+	/// ```
+	#[inline]
+	pub fn jaccard<T2, O2>(&self, other: &BitSlice<T2, O2>) -> f64
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		match self.count_ones_or(other) {
+			| 0 => 1.0,
+			| union => self.count_ones_and(other) as f64 / union as f64,
+		}
+	}
+
+	/// Counts the `1` bits in a Boolean combination of two bit-slices.
+	///
+	/// `word_op` must produce `0` bits wherever both of its arguments have `0`
+	/// bits, which holds for all of `&`, `|`, and `^`.
+	fn count_ones_with<T2, O2>(
+		&self,
+		other: &BitSlice<T2, O2>,
+		word_op: fn(usize, usize) -> usize,
+	) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len().min(other.len());
+		let (this, this_rest) = unsafe { self.split_at_unchecked(len) };
+		let (that, that_rest) = unsafe { other.split_at_unchecked(len) };
+
+		let common = if let (Some(this), Some(that)) =
+			(this.coerce::<T, Lsb0>(), that.coerce::<T, Lsb0>())
+		{
+			this.sp_count_ones_with(that, word_op)
+		}
+		else if let (Some(this), Some(that)) =
+			(this.coerce::<T, Msb0>(), that.coerce::<T, Msb0>())
+		{
+			this.sp_count_ones_with(that, word_op)
+		}
+		else {
+			lockstep(this, that, word_op)
+		};
+
+		//  Only one of the remainders is non-empty, and it is combined with
+		//  the zero-extension of the other.
+		common
+			+ match word_op(!0, 0) {
+				| 0 => 0,
+				| _ => this_rest.count_ones() + that_rest.count_ones(),
+			}
+	}
+}
+
+/// Counts the `1` bits in a Boolean combination of two equal-length bit-slices
+/// of any type parameters.
+///
+/// This walks the memory elements of both bit-slices in order, gathering the
+/// live bits of each into a buffer, and combines word-sized runs of bits taken
+/// from the front of both buffers.
+fn lockstep<T1, O1, T2, O2>(
+	this: &BitSlice<T1, O1>,
+	that: &BitSlice<T2, O2>,
+	word_op: fn(usize, usize) -> usize,
+) -> usize
+where
+	T1: BitStore,
+	O1: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	debug_assert_eq!(this.len(), that.len(), "lengths must be equal");
+	let word = bits_of::<usize>() as u32;
+	let (mut this, mut that) = (Bits::new(this), Bits::new(that));
+	let mut count = 0;
+	loop {
+		let n = this.fill().min(that.fill()).min(word);
+		if n == 0 {
+			return count;
+		}
+		count += word_op(this.take(n), that.take(n)).count_ones() as usize;
+	}
+}
+
+/// A buffer over the live bits of a bit-slice, in index order.
+///
+/// The bit-slice’s `Domain` is consumed one memory element at a time, and bits
+/// are taken from the front of the buffer in runs of any width up to a
+/// `usize`.
+pub(super) struct Bits<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The live bits of the partially-used head element, if any.
+	head:   Option<(u64, u32)>,
+	/// The fully-used interior elements.
+	body:   slice::Iter<'a, T::Unalias>,
+	/// The live bits of the partially-used tail element, if any.
+	tail:   Option<(u64, u32)>,
+	/// Bits that have been gathered but not yet taken. The lowest bit is the
+	/// next in index order.
+	buffer: u128,
+	/// The number of bits in the buffer.
+	len:    u32,
+	/// The bit-slice’s ordering, which governs how elements are gathered.
+	_ord:   PhantomData<O>,
+}
+
+impl<'a, T, O> Bits<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Buffers the bits of a bit-slice.
+	pub(super) fn new(bits: &'a BitSlice<T, O>) -> Self {
+		let (head, body, tail) = match bits.domain() {
+			| Domain::Enclave(elem) => (Some(elem), &[][..], None),
+			| Domain::Region { head, body, tail } => (head, body, tail),
+		};
+		let partial = |elem: PartialElement<Const, T, O>| {
+			let (head, tail) = elem.bounds();
+			gather::<T::Mem, O>(elem.load_value(), head, tail)
+		};
+		Self {
+			head:   head.map(partial),
+			body:   body.iter(),
+			tail:   tail.map(partial),
+			buffer: 0,
+			len:    0,
+			_ord:   PhantomData,
+		}
+	}
+
+	/// Loads a bit-slice no wider than a `usize` into a word, with its first
+	/// bit in the least significant position.
+	pub(super) fn load(bits: &'a BitSlice<T, O>) -> usize {
+		debug_assert!(bits.len() <= bits_of::<usize>(), "too wide to load");
+		let mut this = Self::new(bits);
+		let len = this.fill();
+		this.take(len)
+	}
+
+	/// Gathers memory elements until at least 64 bits are buffered, or the
+	/// bit-slice is exhausted, and reports how many bits are buffered.
+	pub(super) fn fill(&mut self) -> u32 {
+		while self.len < 64 {
+			let next = self
+				.head
+				.take()
+				.or_else(|| {
+					self.body.next().map(|elem| {
+						gather::<T::Mem, O>(
+							elem.load_value(),
+							BitIdx::MIN,
+							BitEnd::MAX,
+						)
+					})
+				})
+				.or_else(|| self.tail.take());
+			match next {
+				| Some((bits, len)) => {
+					self.buffer |= (bits as u128) << self.len;
+					self.len += len;
+				},
+				| None => break,
+			}
+		}
+		self.len
+	}
+
+	/// Takes the first `n` buffered bits. `n` must be no greater than the
+	/// width of `usize` or the number of buffered bits.
+	pub(super) fn take(&mut self, n: u32) -> usize {
+		let out = self.buffer & ((1 << n) - 1);
+		self.buffer >>= n;
+		self.len -= n;
+		out as usize
+	}
+}
+
+/// Moves the bits of `elem` in `head .. tail` into the low bits of a word,
+/// preserving their index order, and reports how many bits were moved.
+///
+/// `Lsb0` and `Msb0` only need a shift; other orderings are gathered one bit at
+/// a time.
+fn gather<R, O>(elem: R, head: BitIdx<R>, tail: BitEnd<R>) -> (u64, u32)
+where
+	R: BitRegister,
+	O: BitOrder,
+{
+	let len = (tail.into_inner() - head.into_inner()) as u32;
+	let bits = if dvl::match_order::<O, Lsb0>() {
+		elem.as_u64() >> head.into_inner()
+	}
+	else if dvl::match_order::<O, Msb0>() {
+		elem.reverse_bits().as_u64() >> head.into_inner()
+	}
+	else {
+		head.range(tail).enumerate().fold(0u64, |out, (dist, idx)| {
+			if elem & idx.select::<O>().into_inner() == R::ZERO {
+				out
+			}
+			else {
+				out | 1u64 << dist
+			}
+		})
+	};
+	(bits & (!0u64).checked_shr(64 - len).unwrap_or(0), len)
+}
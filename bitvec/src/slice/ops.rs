@@ -22,6 +22,8 @@ use super::{
 	BitSlice,
 	BitSliceIndex,
 };
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
 use crate::{
 	domain::Domain,
 	order::{
@@ -122,6 +124,66 @@ where
 	}
 }
 
+#[cfg(feature = "alloc")]
+impl<T1, T2, O1, O2> BitAnd<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	#[doc = include_str!("../../doc/slice/bitop.md")]
+	fn bitand(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		let len = self.len().min(rhs.len());
+		let mut out = unsafe { self.get_unchecked(.. len) }.to_bitvec();
+		*out.as_mut_bitslice() &= unsafe { rhs.get_unchecked(.. len) };
+		out
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T1, T2, O1, O2> BitOr<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	#[doc = include_str!("../../doc/slice/bitop.md")]
+	fn bitor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		let len = self.len().min(rhs.len());
+		let mut out = unsafe { self.get_unchecked(.. len) }.to_bitvec();
+		*out.as_mut_bitslice() |= unsafe { rhs.get_unchecked(.. len) };
+		out
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T1, T2, O1, O2> BitXor<&BitSlice<T2, O2>> for &BitSlice<T1, O1>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	type Output = BitVec<T1::Unalias, O1>;
+
+	#[inline]
+	#[doc = include_str!("../../doc/slice/bitop.md")]
+	fn bitxor(self, rhs: &BitSlice<T2, O2>) -> Self::Output {
+		let len = self.len().min(rhs.len());
+		let mut out = unsafe { self.get_unchecked(.. len) }.to_bitvec();
+		*out.as_mut_bitslice() ^= unsafe { rhs.get_unchecked(.. len) };
+		out
+	}
+}
+
 impl<T, O> Index<usize> for BitSlice<T, O>
 where
 	T: BitStore,
@@ -237,3 +299,24 @@ where
 		self
 	}
 }
+
+/** Copies the bit-slice into a new bit-vector, with each bit inverted.
+
+The original bit-slice is not modified. The new bit-vector has the same type
+parameters as the original, less any aliasing marker.
+**/
+#[cfg(feature = "alloc")]
+impl<T, O> Not for &BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Output = BitVec<T::Unalias, O>;
+
+	#[inline]
+	fn not(self) -> Self::Output {
+		let mut out = self.to_bitvec();
+		let _ = !out.as_mut_bitslice();
+		out
+	}
+}
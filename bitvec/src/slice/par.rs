@@ -0,0 +1,596 @@
+#![cfg(feature = "rayon")]
+#![doc = include_str!("../../doc/slice/par.md")]
+
+use core::cmp;
+
+use rayon::iter::{
+	plumbing::{
+		bridge,
+		bridge_unindexed,
+		Consumer,
+		Folder,
+		Producer,
+		ProducerCallback,
+		UnindexedConsumer,
+		UnindexedProducer,
+	},
+	IndexedParallelIterator,
+	ParallelIterator,
+};
+
+use super::{
+	BitSlice,
+	Chunks,
+	ChunksMut,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// The shortest bit-slice that the parallel operations will divide.
+///
+/// Pieces shorter than twice this length are processed serially, as the cost
+/// of scheduling them on the thread pool outweighs the work they contain.
+const MIN_SPLIT_BITS: usize = 1 << 12;
+
+/// Parallel operations.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Sync,
+	O: BitOrder,
+{
+	/// Enumerates the indices of bits set to `1` in parallel.
+	///
+	/// This is the parallel equivalent of [`.iter_ones()`]. Collecting the
+	/// produced iterator yields the indices in ascending order.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bv = BitVec::<u64>::repeat(true, 10_000);
+	/// assert_eq!(bv.par_iter_ones().sum::<usize>(), 49_995_000);
+	/// ```
+	///
+	/// [`.iter_ones()`]: Self::iter_ones
+	#[inline]
+	pub fn par_iter_ones(&self) -> ParIterOnes<T, O> {
+		ParIterOnes::new(self)
+	}
+
+	/// Counts the number of bits set to `1` in parallel.
+	///
+	/// This is the parallel equivalent of [`.count_ones()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8>::repeat(true, 100_001);
+	/// assert_eq!(bv.par_count_ones(), 100_001);
+	/// ```
+	///
+	/// [`.count_ones()`]: Self::count_ones
+	#[inline]
+	pub fn par_count_ones(&self) -> usize {
+		rayon::iter::split(self, |bits| match split_point(bits) {
+			| Some(mid) => {
+				let (head, rest) = unsafe { bits.split_at_unchecked(mid) };
+				(head, Some(rest))
+			},
+			| None => (bits, None),
+		})
+		.map(BitSlice::count_ones)
+		.sum()
+	}
+
+	/// Iterates over non-overlapping subslices of a bit-slice in parallel.
+	///
+	/// This is the parallel equivalent of [`.chunks()`].
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1];
+	/// assert_eq!(bits.par_chunks(2).count(), 3);
+	/// ```
+	///
+	/// [`.chunks()`]: Self::chunks
+	#[inline]
+	pub fn par_chunks(&self, chunk_size: usize) -> ParChunks<T, O> {
+		ParChunks::new(self, chunk_size)
+	}
+
+	/// Sets every bit in the bit-slice to some value, in parallel.
+	///
+	/// This is the parallel equivalent of [`.fill()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<u16>::repeat(false, 100_000);
+	/// bv[3 ..].par_fill(true);
+	/// assert_eq!(bv.count_zeros(), 3);
+	/// ```
+	///
+	/// [`.fill()`]: Self::fill
+	#[inline]
+	pub fn par_fill(&mut self, value: bool) {
+		par_split_mut(self, 0, &|bits, _| bits.fill(value));
+	}
+
+	/// Applies Boolean AND to every bit in the bit-slice, in parallel.
+	///
+	/// This is the parallel equivalent of the `&=` operator. As with that
+	/// operator, bits in `self` past the end of `rhs` are cleared.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<u32>::repeat(true, 100_000);
+	/// bv.par_bitand_assign(&BitVec::<u8, Msb0>::repeat(true, 90_000));
+	/// assert_eq!(bv.count_ones(), 90_000);
+	/// ```
+	#[inline]
+	pub fn par_bitand_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		par_split_mut(self, 0, &|bits, offset| {
+			*bits &= rhs_piece(rhs, bits, offset)
+		});
+	}
+
+	/// Applies Boolean inclusive OR to every bit in the bit-slice, in
+	/// parallel.
+	///
+	/// This is the parallel equivalent of the `|=` operator. As with that
+	/// operator, bits in `self` past the end of `rhs` are unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<u32>::repeat(false, 100_000);
+	/// bv.par_bitor_assign(&BitVec::<u8, Msb0>::repeat(true, 90_000));
+	/// assert_eq!(bv.count_ones(), 90_000);
+	/// ```
+	#[inline]
+	pub fn par_bitor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		par_split_mut(self, 0, &|bits, offset| {
+			*bits |= rhs_piece(rhs, bits, offset)
+		});
+	}
+
+	/// Applies Boolean exclusive OR to every bit in the bit-slice, in
+	/// parallel.
+	///
+	/// This is the parallel equivalent of the `^=` operator. As with that
+	/// operator, bits in `self` past the end of `rhs` are unchanged.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<u32>::repeat(true, 100_000);
+	/// bv.par_bitxor_assign(&BitVec::<u8, Msb0>::repeat(true, 90_000));
+	/// assert_eq!(bv.count_ones(), 10_000);
+	/// ```
+	#[inline]
+	pub fn par_bitxor_assign<T2, O2>(&mut self, rhs: &BitSlice<T2, O2>)
+	where
+		T2: BitStore + Sync,
+		O2: BitOrder,
+	{
+		par_split_mut(self, 0, &|bits, offset| {
+			*bits ^= rhs_piece(rhs, bits, offset)
+		});
+	}
+}
+
+/// Parallel operations that produce alias-marked bit-slices.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore + Sync,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	/// Iterates over non-overlapping mutable subslices of a bit-slice in
+	/// parallel.
+	///
+	/// This is the parallel equivalent of [`.chunks_mut()`]. Like it, this
+	/// marks every yielded chunk as `::Alias`ed, since chunk edges may fall
+	/// inside a memory element. It is only available when the alias marker can
+	/// be shared across threads, which requires the `atomic` feature.
+	///
+	/// ## Panics
+	///
+	/// This panics if `chunk_size` is `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use rayon::prelude::*;
+	///
+	/// let bits = bits![mut 0; 9];
+	/// bits.par_chunks_mut(4).for_each(|chunk| chunk.set(0, true));
+	/// assert_eq!(bits, bits![1, 0, 0, 0, 1, 0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`.chunks_mut()`]: Self::chunks_mut
+	#[inline]
+	pub fn par_chunks_mut(&mut self, chunk_size: usize) -> ParChunksMut<T, O> {
+		ParChunksMut::new(self, chunk_size)
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/slice/par/ParIterOnes.md")]
+pub struct ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The region of the source bit-slice that this piece searches.
+	bits:   &'a BitSlice<T, O>,
+	/// The index of `bits` in the source bit-slice.
+	offset: usize,
+}
+
+impl<'a, T, O> ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Begins a parallel search over an entire bit-slice.
+	#[inline]
+	fn new(bits: &'a BitSlice<T, O>) -> Self {
+		Self { bits, offset: 0 }
+	}
+}
+
+impl<'a, T, O> ParallelIterator for ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge_unindexed(self, consumer)
+	}
+}
+
+impl<'a, T, O> UnindexedProducer for ParIterOnes<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn split(self) -> (Self, Option<Self>) {
+		match split_point(self.bits) {
+			| Some(mid) => {
+				let (head, rest) = unsafe { self.bits.split_at_unchecked(mid) };
+				(
+					Self { bits: head, ..self },
+					Some(Self {
+						bits:   rest,
+						offset: self.offset + mid,
+					}),
+				)
+			},
+			| None => (self, None),
+		}
+	}
+
+	#[inline]
+	fn fold_with<F>(self, folder: F) -> F
+	where F: Folder<Self::Item> {
+		let offset = self.offset;
+		folder.consume_iter(self.bits.iter_ones().map(|idx| idx + offset))
+	}
+}
+
+#[derive(Clone, Debug)]
+#[doc = include_str!("../../doc/slice/par/ParChunks.md")]
+pub struct ParChunks<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The source bit-slice.
+	slice: &'a BitSlice<T, O>,
+	/// The width of the produced chunks.
+	width: usize,
+}
+
+impl<'a, T, O> ParChunks<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Begins chunking a bit-slice.
+	#[inline]
+	fn new(slice: &'a BitSlice<T, O>, width: usize) -> Self {
+		assert_ne!(width, 0, "view width cannot be 0");
+		Self { slice, width }
+	}
+}
+
+impl<'a, T, O> ParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunks<'a, T, O>
+where
+	T: 'a + BitStore + Sync,
+	O: BitOrder,
+{
+	type IntoIter = Chunks<'a, T, O>;
+	type Item = &'a BitSlice<T, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.slice.chunks(self.width)
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let mid = cmp::min(index.saturating_mul(self.width), self.slice.len());
+		let (head, rest) = unsafe { self.slice.split_at_unchecked(mid) };
+		(
+			Self {
+				slice: head,
+				..self
+			},
+			Self {
+				slice: rest,
+				..self
+			},
+		)
+	}
+}
+
+#[derive(Debug)]
+#[doc = include_str!("../../doc/slice/par/ParChunksMut.md")]
+pub struct ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The source bit-slice, marked with the alias tainting.
+	slice: &'a mut BitSlice<T::Alias, O>,
+	/// The width of the produced chunks.
+	width: usize,
+}
+
+impl<'a, T, O> ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Begins chunking a bit-slice.
+	#[inline]
+	fn new(slice: &'a mut BitSlice<T, O>, width: usize) -> Self {
+		assert_ne!(width, 0, "view width cannot be 0");
+		Self {
+			slice: slice.alias_mut(),
+			width,
+		}
+	}
+}
+
+impl<'a, T, O> ParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	type Item = &'a mut BitSlice<T::Alias, O>;
+
+	#[inline]
+	fn drive_unindexed<C>(self, consumer: C) -> C::Result
+	where C: UnindexedConsumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(IndexedParallelIterator::len(self))
+	}
+}
+
+impl<'a, T, O> IndexedParallelIterator for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		chunk_count(self.slice.len(), self.width)
+	}
+
+	#[inline]
+	fn drive<C>(self, consumer: C) -> C::Result
+	where C: Consumer<Self::Item> {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB>(self, callback: CB) -> CB::Output
+	where CB: ProducerCallback<Self::Item> {
+		callback.callback(self)
+	}
+}
+
+impl<'a, T, O> Producer for ParChunksMut<'a, T, O>
+where
+	T: 'a + BitStore,
+	T::Alias: Sync,
+	O: BitOrder,
+{
+	type IntoIter = ChunksMut<'a, T, O>;
+	type Item = &'a mut BitSlice<T::Alias, O>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		ChunksMut::from_aliased(self.slice, self.width)
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		let width = self.width;
+		let mid = cmp::min(index.saturating_mul(width), self.slice.len());
+		//  The alias marker is already applied, so no new one is needed.
+		let (head, rest) =
+			unsafe { self.slice.split_at_unchecked_mut_noalias(mid) };
+		(Self { slice: head, width }, Self { slice: rest, width })
+	}
+}
+
+/// Counts the chunks that a bit-slice of `len` bits divides into.
+#[inline]
+fn chunk_count(len: usize, width: usize) -> usize {
+	len / width + (len % width != 0) as usize
+}
+
+/// Selects a memory-element edge near the middle of a bit-slice.
+///
+/// ## Returns
+///
+/// An index in the interior of `bits` that is the front edge of a memory
+/// element, or `None` if `bits` is too short to be worth dividing.
+#[inline]
+fn split_point<T, O>(bits: &BitSlice<T, O>) -> Option<usize>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = bits.len();
+	if len < 2 * MIN_SPLIT_BITS {
+		return None;
+	}
+	let width = bits_of::<T::Mem>();
+	let head = bits.as_bitspan().head().into_inner() as usize;
+	//  Because `len / 2` is much wider than an element, rounding down to an
+	//  element edge cannot reach either end of the bit-slice.
+	Some((head + len / 2) / width * width - head)
+}
+
+/// Recursively divides a bit-slice on memory-element edges, and applies an
+/// operation to each piece in parallel.
+///
+/// ## Parameters
+///
+/// - `bits`: the bit-slice to divide.
+/// - `offset`: the index of `bits` in the bit-slice that the caller provided.
+/// - `op`: the operation to apply to each piece. It receives the piece, and the
+///   piece’s index in the caller’s bit-slice.
+fn par_split_mut<T, O, F>(bits: &mut BitSlice<T, O>, offset: usize, op: &F)
+where
+	T: BitStore + Sync,
+	O: BitOrder,
+	F: Fn(&mut BitSlice<T, O>, usize) + Sync,
+{
+	match split_point(bits) {
+		| Some(mid) => {
+			//  The halves meet on an element edge, so they do not share any
+			//  memory, and do not need alias protection.
+			let (head, rest) =
+				unsafe { bits.split_at_unchecked_mut_noalias(mid) };
+			rayon::join(
+				|| par_split_mut(head, offset, op),
+				|| par_split_mut(rest, offset + mid, op),
+			);
+		},
+		| None => op(bits, offset),
+	}
+}
+
+/// Selects the region of a right-hand operand that lines up with a piece of
+/// the left-hand operand.
+///
+/// The region is truncated, possibly to nothing, if `rhs` ends before `bits`
+/// does.
+#[inline]
+fn rhs_piece<'a, T1, T2, O1, O2>(
+	rhs: &'a BitSlice<T2, O2>,
+	bits: &BitSlice<T1, O1>,
+	offset: usize,
+) -> &'a BitSlice<T2, O2>
+where
+	T1: BitStore,
+	T2: BitStore,
+	O1: BitOrder,
+	O2: BitOrder,
+{
+	let end = cmp::min(offset + bits.len(), rhs.len());
+	rhs.get(offset .. end).unwrap_or_else(|| BitSlice::empty())
+}
@@ -0,0 +1,299 @@
+#![doc = include_str!("../../doc/slice/parse.md")]
+
+use core::fmt::{
+	self,
+	Debug,
+	Display,
+	Formatter,
+};
+
+/// Parses text into a sequence of bits.
+///
+/// ## Parameters
+///
+/// - `text`: the text to parse.
+/// - `radix`: the bit width of each digit (log2 of its radix). When this is
+///   `None`, each numeral selects its radix with a prefix, and defaults to
+///   binary.
+/// - `elem_bits`: the bit width of the destination’s storage elements.
+/// - `push`: receives each parsed bit, in order. It returns `Err(capacity)`
+///   when the destination cannot accept any more bits.
+///
+/// ## Panics
+///
+/// This panics if `radix` is not in `1 ..= 5`.
+pub(crate) fn parse_bits(
+	text: &str,
+	radix: Option<usize>,
+	elem_bits: usize,
+	mut push: impl FnMut(bool) -> Result<(), usize>,
+) -> Result<(), ParseBitsError> {
+	if let Some(radix) = radix {
+		assert!(
+			(1 ..= 5).contains(&radix),
+			"radix digits must be between 1 and 5 bits wide, not {}",
+			radix,
+		);
+	}
+	let bytes = text.as_bytes();
+	let mut pos = skip_space(text, 0);
+
+	//  Skip the header written by the containers’ `Debug` implementations.
+	if text[pos ..].starts_with("Bit") {
+		pos = match text[pos ..].find('}') {
+			| Some(end) => skip_space(text, pos + end + 1),
+			| None => {
+				return Err(ParseBitsError::UnexpectedEnd {
+					position: text.len(),
+				});
+			},
+		};
+		if bytes.get(pos) != Some(&b'[') {
+			return Err(unexpected(text, pos));
+		}
+	}
+
+	if bytes.get(pos) == Some(&b'[') {
+		pos += 1;
+		loop {
+			pos = skip_space(text, pos);
+			match bytes.get(pos) {
+				| Some(b']') => {
+					pos += 1;
+					break;
+				},
+				| Some(_) => {},
+				| None => return Err(unexpected(text, pos)),
+			}
+			pos = parse_numeral(text, pos, radix, Some(elem_bits), &mut push)?;
+			pos = skip_space(text, pos);
+			match bytes.get(pos) {
+				| Some(b',') => pos += 1,
+				| Some(b']') => {
+					pos += 1;
+					break;
+				},
+				| _ => return Err(unexpected(text, pos)),
+			}
+		}
+	}
+	else {
+		pos = parse_numeral(text, pos, radix, None, &mut push)?;
+	}
+
+	pos = skip_space(text, pos);
+	if pos < text.len() {
+		return Err(unexpected(text, pos));
+	}
+	Ok(())
+}
+
+/// Parses a single numeral.
+///
+/// ## Parameters
+///
+/// - `text`: the whole text being parsed.
+/// - `start`: the byte offset at which the numeral begins.
+/// - `radix`: as in [`parse_bits`].
+/// - `elem_bits`: the bit width of a storage element, when the numeral is a
+///   list item. Octal and hexadecimal list items must render exactly one
+///   whole element.
+/// - `push`: as in [`parse_bits`].
+///
+/// ## Returns
+///
+/// The byte offset of the first character after the numeral.
+fn parse_numeral(
+	text: &str,
+	start: usize,
+	radix: Option<usize>,
+	elem_bits: Option<usize>,
+	push: &mut impl FnMut(bool) -> Result<(), usize>,
+) -> Result<usize, ParseBitsError> {
+	let rest = &text[start ..];
+	let prefix = match rest.get(.. 2) {
+		| Some("0b") => Some(1),
+		| Some("0o") => Some(3),
+		| Some("0x") => Some(4),
+		| _ => None,
+	};
+	let (width, begin) = match (radix, prefix) {
+		| (None, Some(found)) => (found, start + 2),
+		| (Some(want), Some(found)) if want == found => (want, start + 2),
+		| (radix, _) => (radix.unwrap_or(1), start),
+	};
+	let base = 1 << width;
+
+	//  Find the extent of the numeral, so that its width is known before any
+	//  of its bits are produced.
+	let mut end = text.len();
+	let mut digits = 0;
+	for (idx, ch) in text[begin ..].char_indices() {
+		if ch == '_' {
+			continue;
+		}
+		if ch.to_digit(base).is_none() {
+			end = begin + idx;
+			break;
+		}
+		digits += 1;
+	}
+	if digits == 0 && (begin != start || elem_bits.is_some()) {
+		return Err(unexpected(text, end));
+	}
+
+	//  Octal and hexadecimal list items render one whole element each. The
+	//  renderers do not record how many bits a partial element holds, so an
+	//  item with any other number of digits cannot be read back faithfully.
+	let mut lead = width;
+	if let Some(elem_bits) = elem_bits.filter(|_| width > 1) {
+		let whole = (elem_bits + width - 1) / width;
+		if digits != whole {
+			return Err(ParseBitsError::PartialElement { position: start });
+		}
+		lead = elem_bits - (whole - 1) * width;
+	}
+
+	for (idx, ch) in text[begin .. end].char_indices() {
+		let position = begin + idx;
+		let digit = match ch.to_digit(base) {
+			| Some(digit) => digit,
+			| None => continue,
+		};
+		if digit >> lead != 0 {
+			return Err(ParseBitsError::Overflow { position });
+		}
+		for shift in (0 .. lead).rev() {
+			push((digit >> shift) & 1 == 1).map_err(|capacity| {
+				ParseBitsError::TooLong { position, capacity }
+			})?;
+		}
+		lead = width;
+	}
+	Ok(end)
+}
+
+/// Finds the first non-whitespace byte at or after `pos`.
+fn skip_space(text: &str, pos: usize) -> usize {
+	text[pos ..]
+		.find(|ch: char| !ch.is_ascii_whitespace())
+		.map_or(text.len(), |idx| pos + idx)
+}
+
+/// Produces the error for an unexpected character, or for the end of the text.
+fn unexpected(text: &str, position: usize) -> ParseBitsError {
+	match text[position ..].chars().next() {
+		| Some(found) => ParseBitsError::InvalidChar { position, found },
+		| None => ParseBitsError::UnexpectedEnd { position },
+	}
+}
+
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/ParseBitsError.md")]
+pub enum ParseBitsError {
+	/// A character that is neither a digit of the numeral’s radix nor
+	/// permitted list punctuation.
+	InvalidChar {
+		/// The byte offset of the character.
+		position: usize,
+		/// The character.
+		found:    char,
+	},
+	/// The text ended inside a list, a `Debug` header, or a prefixed numeral.
+	UnexpectedEnd {
+		/// The length of the text.
+		position: usize,
+	},
+	/// The leading digit of a whole-element list item holds more bits than
+	/// the element has room for.
+	Overflow {
+		/// The byte offset of the digit.
+		position: usize,
+	},
+	/// An octal or hexadecimal list item does not have exactly as many digits
+	/// as the renderers print for one whole storage element.
+	PartialElement {
+		/// The byte offset of the list item.
+		position: usize,
+	},
+	/// The destination is full, and cannot accept any more bits.
+	TooLong {
+		/// The byte offset of the digit whose bits did not fit.
+		position: usize,
+		/// The number of bits that the destination can hold.
+		capacity: usize,
+	},
+}
+
+impl ParseBitsError {
+	/// Gets the byte offset in the source text at which parsing stopped.
+	#[inline]
+	pub fn position(&self) -> usize {
+		match *self {
+			| Self::InvalidChar { position, .. }
+			| Self::UnexpectedEnd { position }
+			| Self::Overflow { position }
+			| Self::PartialElement { position }
+			| Self::TooLong { position, .. } => position,
+		}
+	}
+}
+
+impl Debug for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.write_str("ParseBitsError::")?;
+		match *self {
+			| Self::InvalidChar { position, found } => {
+				write!(fmt, "InvalidChar({:?} at {})", found, position)
+			},
+			| Self::UnexpectedEnd { position } => {
+				write!(fmt, "UnexpectedEnd({})", position)
+			},
+			| Self::Overflow { position } => {
+				write!(fmt, "Overflow({})", position)
+			},
+			| Self::PartialElement { position } => {
+				write!(fmt, "PartialElement({})", position)
+			},
+			| Self::TooLong { position, capacity } => {
+				write!(fmt, "TooLong({} at {})", capacity, position)
+			},
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl Display for ParseBitsError {
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			| Self::InvalidChar { position, found } => write!(
+				fmt,
+				"unexpected character {:?} at position {}",
+				found, position,
+			),
+			| Self::UnexpectedEnd { position } => {
+				write!(fmt, "text ended unexpectedly at position {}", position)
+			},
+			| Self::Overflow { position } => write!(
+				fmt,
+				"digit at position {} holds more bits than its element",
+				position,
+			),
+			| Self::PartialElement { position } => write!(
+				fmt,
+				"list item at position {} does not render a whole element",
+				position,
+			),
+			| Self::TooLong { position, capacity } => write!(
+				fmt,
+				"digit at position {} exceeds the capacity of {} bits",
+				position, capacity,
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBitsError {}
@@ -0,0 +1,304 @@
+#![doc = include_str!("../../doc/slice/runs.md")]
+
+use core::{
+	iter::FusedIterator,
+	ops::Range,
+};
+
+use super::BitSlice;
+use crate::{
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Run-length queries.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Enumerates the maximal runs of identical bits in the bit-slice.
+	///
+	/// Each run is yielded as its bit-value and the range of indices it
+	/// covers. Consecutive runs always have different values, and together
+	/// they cover the entire bit-slice.
+	///
+	/// The end of each run is found with the same element-wise seeking as
+	/// [`.first_one()`] and [`.first_zero()`], so long runs are crossed a whole
+	/// element at a time, rather than a bit at a time.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 0, 1, 1, 1, 0, 1];
+	/// let mut runs = bits.iter_runs();
+	///
+	/// assert_eq!(runs.next(), Some((false, 0 .. 2)));
+	/// assert_eq!(runs.next(), Some((true, 2 .. 5)));
+	/// assert_eq!(runs.next_back(), Some((true, 6 .. 7)));
+	/// assert_eq!(runs.next(), Some((false, 5 .. 6)));
+	/// assert!(runs.next().is_none());
+	/// ```
+	///
+	/// [`.first_one()`]: Self::first_one
+	/// [`.first_zero()`]: Self::first_zero
+	#[inline]
+	pub fn iter_runs(&self) -> IterRuns<T, O> {
+		IterRuns::new(self)
+	}
+
+	/// Enumerates the ranges of indices covered by each maximal run of bits
+	/// set to `1`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1];
+	/// let runs = bits.iter_one_runs().collect::<Vec<_>>();
+	/// assert_eq!(runs, [1 .. 3, 5 .. 6]);
+	/// ```
+	#[inline]
+	pub fn iter_one_runs(&self) -> IterOneRuns<T, O> {
+		IterOneRuns::new(self)
+	}
+
+	/// Enumerates the ranges of indices covered by each maximal run of bits
+	/// cleared to `0`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 0, 1];
+	/// let runs = bits.iter_zero_runs().collect::<Vec<_>>();
+	/// assert_eq!(runs, [0 .. 1, 3 .. 5]);
+	/// ```
+	#[inline]
+	pub fn iter_zero_runs(&self) -> IterZeroRuns<T, O> {
+		IterZeroRuns::new(self)
+	}
+
+	/// Finds the longest run of bits with a given value.
+	///
+	/// Returns the range of indices covered by the run, or `None` if the
+	/// bit-slice has no bits with that value. If several runs are equally
+	/// long, this returns the first of them.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0, 0, 1, 1, 0];
+	/// assert_eq!(bits.longest_run(false), Some(2 .. 5));
+	/// assert_eq!(bits.longest_run(true), Some(0 .. 2));
+	/// assert!(bits![0; 4].longest_run(true).is_none());
+	/// ```
+	#[inline]
+	pub fn longest_run(&self, value: bool) -> Option<Range<usize>> {
+		let mut runs = Runs::new(self);
+		let mut best = runs.next(Some(value))?.1;
+		//  Stop once no remaining run could be longer than the best so far.
+		while runs.inner.len() > best.len() {
+			match runs.next(Some(value)) {
+				| Some((_, run)) if run.len() > best.len() => best = run,
+				| Some(_) => {},
+				| None => break,
+			}
+		}
+		Some(best)
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/IterRuns.md")]
+pub struct IterRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice not yet divided into runs.
+	runs: Runs<'a, T, O>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/IterOneRuns.md")]
+pub struct IterOneRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice not yet divided into runs.
+	runs: Runs<'a, T, O>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[doc = include_str!("../../doc/slice/IterZeroRuns.md")]
+pub struct IterZeroRuns<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice not yet divided into runs.
+	runs: Runs<'a, T, O>,
+}
+
+/// Implements the run iterators atop `Runs`, which seeks runs of a particular
+/// value when given `Some(value)`, and of whatever value comes next when given
+/// `None`.
+macro_rules! runs {
+	($(
+		$iter:ident => $value:expr, |$run:pat_param| $map:expr => $item:ty;
+	)+) => { $(
+		impl<'a, T, O> $iter<'a, T, O>
+		where
+			T: 'a + BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+			fn new(slice: &'a BitSlice<T, O>) -> Self {
+				Self {
+					runs: Runs::new(slice),
+				}
+			}
+		}
+
+		impl<T, O> Default for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn default() -> Self {
+				Self::new(Default::default())
+			}
+		}
+
+		impl<T, O> Iterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			type Item = $item;
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				(0, Some(self.runs.inner.len()))
+			}
+
+			#[inline]
+			fn last(mut self) -> Option<Self::Item> {
+				self.next_back()
+			}
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				self.runs.next($value).map(|$run| $map)
+			}
+		}
+
+		impl<T, O> DoubleEndedIterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+			#[inline]
+			fn next_back(&mut self) -> Option<Self::Item> {
+				self.runs.next_back($value).map(|$run| $map)
+			}
+		}
+
+		impl<T, O> FusedIterator for $iter<'_, T, O>
+		where
+			T: BitStore,
+			O: BitOrder,
+		{
+		}
+	)+ };
+}
+
+runs! {
+	IterRuns => None, |run| run => (bool, Range<usize>);
+	IterOneRuns => Some(true), |(_, run)| run => Range<usize>;
+	IterZeroRuns => Some(false), |(_, run)| run => Range<usize>;
+}
+
+/// The state shared by the run iterators: the bit-slice not yet divided into
+/// runs, and its distance from the front of the original bit-slice.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Runs<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice not yet divided into runs.
+	inner: &'a BitSlice<T, O>,
+	/// The offset from the front of the original bit-slice to `.inner`.
+	front: usize,
+}
+
+impl<'a, T, O> Runs<'a, T, O>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+{
+	/// Prepares to divide a bit-slice into runs.
+	fn new(inner: &'a BitSlice<T, O>) -> Self {
+		Self { inner, front: 0 }
+	}
+
+	/// Removes the first run from the front of the bit-slice.
+	///
+	/// If `value` is `Some`, then this skips to the first run of that value;
+	/// otherwise, it takes the run that starts at the front.
+	fn next(&mut self, value: Option<bool>) -> Option<(bool, Range<usize>)> {
+		let (start, bit) = match value {
+			| Some(true) => (self.inner.first_one()?, true),
+			| Some(false) => (self.inner.first_zero()?, false),
+			| None => (0, *self.inner.first()?),
+		};
+		let rest = unsafe { self.inner.get_unchecked(start ..) };
+		let len = if bit {
+			rest.leading_ones()
+		}
+		else {
+			rest.leading_zeros()
+		};
+		let end = start + len;
+		self.inner = unsafe { self.inner.get_unchecked(end ..) };
+		let out = self.front + start .. self.front + end;
+		self.front += end;
+		Some((bit, out))
+	}
+
+	/// Removes the last run from the back of the bit-slice.
+	///
+	/// If `value` is `Some`, then this skips to the last run of that value;
+	/// otherwise, it takes the run that ends at the back.
+	fn next_back(
+		&mut self,
+		value: Option<bool>,
+	) -> Option<(bool, Range<usize>)> {
+		let (end, bit) = match value {
+			| Some(true) => (self.inner.last_one()? + 1, true),
+			| Some(false) => (self.inner.last_zero()? + 1, false),
+			| None => (self.inner.len(), *self.inner.last()?),
+		};
+		let rest = unsafe { self.inner.get_unchecked(.. end) };
+		let len = if bit {
+			rest.trailing_ones()
+		}
+		else {
+			rest.trailing_zeros()
+		};
+		let start = end - len;
+		self.inner = unsafe { self.inner.get_unchecked(.. start) };
+		Some((bit, self.front + start .. self.front + end))
+	}
+}
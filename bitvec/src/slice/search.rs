@@ -0,0 +1,568 @@
+#![doc = include_str!("../../doc/slice/search.md")]
+
+use core::{
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	iter::FusedIterator,
+};
+
+use super::{
+	metric::Bits,
+	BitSlice,
+};
+use crate::{
+	mem::bits_of,
+	order::BitOrder,
+	store::BitStore,
+};
+
+/// Sub-sequence search.
+impl<T, O> BitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Finds the first occurrence of a sequence within the bit-slice.
+	///
+	/// This returns the index at which the first window of `self` that is
+	/// equal to `needle` begins, or `None` if no such window exists. The
+	/// needle does not need to share type parameters with the bit-slice being
+	/// searched, as the comparison is bit-wise.
+	///
+	/// An empty needle is found at index `0`.
+	///
+	/// ## Original
+	///
+	/// [`str::find`](https://doc.rust-lang.org/std/primitive.str.html#method.find)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 0, 0];
+	/// assert_eq!(bits.find(bits![1, 1, 0]), Some(1));
+	/// assert_eq!(bits.find(bits![u8, Msb0; 1, 0, 0]), Some(5));
+	/// assert!(bits.find(bits![1, 1, 1]).is_none());
+	/// ```
+	#[inline]
+	pub fn find<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let (hay, pat) = (Probe::new(self, false), Probe::new(needle, false));
+		Searcher::new(pat).find(hay, pat, 0)
+	}
+
+	/// Finds the last occurrence of a sequence within the bit-slice.
+	///
+	/// This returns the index at which the last window of `self` that is equal
+	/// to `needle` begins, or `None` if no such window exists. The needle does
+	/// not need to share type parameters with the bit-slice being searched, as
+	/// the comparison is bit-wise.
+	///
+	/// An empty needle is found at index `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`str::rfind`](https://doc.rust-lang.org/std/primitive.str.html#method.rfind)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 0, 0];
+	/// assert_eq!(bits.rfind(bits![1, 1, 0]), Some(4));
+	/// assert_eq!(bits.rfind(bits![]), Some(8));
+	/// assert!(bits.rfind(bits![1, 1, 1]).is_none());
+	/// ```
+	#[inline]
+	pub fn rfind<T2, O2>(&self, needle: &BitSlice<T2, O2>) -> Option<usize>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		//  Searching forward through both sequences reversed finds the last
+		//  match, at its distance from the back of `self`.
+		let (hay, pat) = (Probe::new(self, true), Probe::new(needle, true));
+		Searcher::new(pat)
+			.find(hay, pat, 0)
+			.map(|idx| self.len() - idx - needle.len())
+	}
+
+	/// Iterates over the starting indices of the non-overlapping occurrences
+	/// of a sequence within the bit-slice.
+	///
+	/// Once a match is found, the search resumes after its end. An empty
+	/// needle matches at every index, including `self.len()`.
+	///
+	/// ## Original
+	///
+	/// [`str::match_indices`](https://doc.rust-lang.org/std/primitive.str.html#method.match_indices)
+	///
+	/// ## API Differences
+	///
+	/// The matched region is always equal to `needle`, so this only yields its
+	/// starting index.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 0, 1, 1, 0, 1];
+	/// let found = bits.match_indices(bits![1, 0, 1]).collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 5]);
+	/// ```
+	#[inline]
+	pub fn match_indices<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> MatchIndices<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		MatchIndices::new(self, needle, cmp::max(needle.len(), 1))
+	}
+
+	/// Iterates over the starting indices of every occurrence of a sequence
+	/// within the bit-slice, including those that overlap.
+	///
+	/// Once a match is found, the search resumes at the next index after its
+	/// start.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 0, 1, 1, 0, 1];
+	/// let found = bits
+	///   .match_indices_overlapping(bits![1, 0, 1])
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(found, [0, 2, 5]);
+	/// ```
+	#[inline]
+	pub fn match_indices_overlapping<'a, T2, O2>(
+		&'a self,
+		needle: &'a BitSlice<T2, O2>,
+	) -> MatchIndices<'a, T, O, T2, O2>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		MatchIndices::new(self, needle, 1)
+	}
+}
+
+#[doc = include_str!("../../doc/slice/MatchIndices.md")]
+pub struct MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	/// The bit-slice being searched.
+	haystack: &'a BitSlice<T, O>,
+	/// The sequence being sought.
+	needle:   &'a BitSlice<T2, O2>,
+	/// The search strategy prepared for `needle`.
+	searcher: Searcher,
+	/// The index in `haystack` at which the next search begins.
+	position: usize,
+	/// The distance from the start of a match to the start of the next search.
+	step:     usize,
+}
+
+impl<'a, T, O, T2, O2> MatchIndices<'a, T, O, T2, O2>
+where
+	T: 'a + BitStore,
+	O: BitOrder,
+	T2: 'a + BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+	fn new(
+		haystack: &'a BitSlice<T, O>,
+		needle: &'a BitSlice<T2, O2>,
+		step: usize,
+	) -> Self {
+		Self {
+			haystack,
+			needle,
+			searcher: Searcher::new(Probe::new(needle, false)),
+			position: 0,
+			step,
+		}
+	}
+}
+
+impl<T, O, T2, O2> Clone for MatchIndices<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			searcher: self.searcher.clone(),
+			..*self
+		}
+	}
+}
+
+impl<T, O, T2, O2> Debug for MatchIndices<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("MatchIndices")
+			.field("haystack", &self.haystack)
+			.field("needle", &self.needle)
+			.field("position", &self.position)
+			.finish()
+	}
+}
+
+impl<T, O, T2, O2> Iterator for MatchIndices<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let found = self.searcher.find(
+			Probe::new(self.haystack, false),
+			Probe::new(self.needle, false),
+			self.position,
+		);
+		match found {
+			| Some(idx) => self.position = idx + self.step,
+			| None => self.position = usize::MAX,
+		}
+		found
+	}
+}
+
+impl<T, O, T2, O2> FusedIterator for MatchIndices<'_, T, O, T2, O2>
+where
+	T: BitStore,
+	O: BitOrder,
+	T2: BitStore,
+	O2: BitOrder,
+{
+}
+
+/// A search strategy, chosen by the length of the needle.
+#[derive(Clone, Debug)]
+enum Searcher {
+	/// The empty needle matches everywhere.
+	Empty,
+	/// Needles no wider than a `usize` are found with the shift-and (Bitap)
+	/// algorithm, which tracks every partial match at once in the bits of a
+	/// single word.
+	ShiftAnd {
+		/// Bit `n` is set when bit `n` of the needle is `1`.
+		ones: usize,
+	},
+	/// Longer needles are found with the Crochemore–Perrin two-way algorithm,
+	/// which runs in linear time and constant space.
+	TwoWay {
+		/// The critical position: the start of the needle’s right half.
+		crit:   usize,
+		/// A period of the needle, or a shift that is safe to use when the
+		/// needle has no short period.
+		period: usize,
+		/// Whether the needle has no period short enough to let the search
+		/// remember how much of a previous window already matched.
+		long:   bool,
+	},
+}
+
+impl Searcher {
+	/// Prepares to search for a needle.
+	fn new<T, O>(needle: Probe<T, O>) -> Self
+	where
+		T: BitStore,
+		O: BitOrder,
+	{
+		let len = needle.len();
+		if len == 0 {
+			return Self::Empty;
+		}
+		if len <= bits_of::<usize>() {
+			let ones = (0 .. len)
+				.filter(|&idx| needle.get(idx))
+				.fold(0usize, |ones, idx| ones | 1 << idx);
+			return Self::ShiftAnd { ones };
+		}
+
+		//  The critical factorization is the later of the two maximal
+		//  suffixes, under `0 < 1` and under `1 < 0`.
+		let (crit, period) = cmp::max(
+			maximal_suffix(needle, false),
+			maximal_suffix(needle, true),
+		);
+		//  If the left half also repeats with the right half’s period, then
+		//  that period is the needle’s own.
+		if (0 .. crit).all(|idx| needle.get(idx) == needle.get(idx + period)) {
+			Self::TwoWay {
+				crit,
+				period,
+				long: false,
+			}
+		}
+		else {
+			Self::TwoWay {
+				crit,
+				period: cmp::max(crit, len - crit) + 1,
+				long: true,
+			}
+		}
+	}
+
+	/// Finds the first occurrence of `needle` in `haystack` that begins at or
+	/// after `from`.
+	///
+	/// `needle` must be the sequence given to [`Searcher::new`].
+	fn find<T1, O1, T2, O2>(
+		&self,
+		haystack: Probe<T1, O1>,
+		needle: Probe<T2, O2>,
+		from: usize,
+	) -> Option<usize>
+	where
+		T1: BitStore,
+		O1: BitOrder,
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let (hay_len, len) = (haystack.len(), needle.len());
+		if from > hay_len || hay_len - from < len {
+			return None;
+		}
+		match *self {
+			| Self::Empty => Some(from),
+			| Self::ShiftAnd { ones } => {
+				let last = 1 << (len - 1);
+				//  Bit `n` of `state` is set when the most recent `n + 1` bits
+				//  of the haystack equal the first `n + 1` bits of the needle.
+				let mut state = 0usize;
+				let mut base = from;
+				for (word, width) in haystack.words(from) {
+					for bit in 0 .. width {
+						let mask =
+							if word & (1 << bit) != 0 { ones } else { !ones };
+						state = (state << 1 | 1) & mask;
+						if state & last != 0 {
+							return Some(base + bit + 1 - len);
+						}
+					}
+					base += width;
+				}
+				None
+			},
+			| Self::TwoWay { crit, period, long } => {
+				let mut pos = from;
+				//  The length of the needle prefix already known to match at
+				//  `pos`, carried over from the previous window.
+				let mut memory = 0;
+				'search: while pos + len <= hay_len {
+					//  Match the right half, from left to right.
+					let start = if long { crit } else { cmp::max(crit, memory) };
+					for idx in start .. len {
+						if needle.get(idx) != haystack.get(pos + idx) {
+							pos += idx - crit + 1;
+							memory = 0;
+							continue 'search;
+						}
+					}
+					//  Match the left half, from right to left.
+					let start = if long { 0 } else { memory };
+					for idx in (start .. crit).rev() {
+						if needle.get(idx) != haystack.get(pos + idx) {
+							pos += period;
+							if !long {
+								memory = len - period;
+							}
+							continue 'search;
+						}
+					}
+					return Some(pos);
+				}
+				None
+			},
+		}
+	}
+}
+
+/// Computes the maximal suffix of a needle under the lexicographic ordering in
+/// which `0 < 1`, or in which `1 < 0` if `reverse` is set.
+///
+/// Returns the starting index of the suffix and its period.
+fn maximal_suffix<T, O>(needle: Probe<T, O>, reverse: bool) -> (usize, usize)
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let len = needle.len();
+	let (mut left, mut right, mut offset, mut period) = (0, 1, 0, 1);
+	while right + offset < len {
+		let (a, b) = (needle.get(right + offset), needle.get(left + offset));
+		if a == b {
+			//  Advance through a repetition of the current period.
+			if offset + 1 == period {
+				right += offset + 1;
+				offset = 0;
+			}
+			else {
+				offset += 1;
+			}
+		}
+		else if (!a & b) != reverse {
+			//  The suffix is smaller, so the period is the whole prefix.
+			right += offset + 1;
+			offset = 0;
+			period = right - left;
+		}
+		else {
+			//  The suffix is larger, so start over from it.
+			left = right;
+			right += 1;
+			offset = 0;
+			period = 1;
+		}
+	}
+	(left, period)
+}
+
+/// A bit-slice read either in index order or in reverse.
+struct Probe<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bit-slice being read.
+	bits: &'a BitSlice<T, O>,
+	/// Whether index `0` is the last bit of `bits`, rather than the first.
+	rev:  bool,
+}
+
+impl<'a, T, O> Probe<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Reads a bit-slice forwards or backwards.
+	fn new(bits: &'a BitSlice<T, O>, rev: bool) -> Self {
+		Self { bits, rev }
+	}
+
+	/// The number of bits in the bit-slice.
+	fn len(self) -> usize {
+		self.bits.len()
+	}
+
+	/// Reads a bit. `idx` must be less than `self.len()`.
+	fn get(self, idx: usize) -> bool {
+		let idx = if self.rev { self.len() - 1 - idx } else { idx };
+		unsafe { *self.bits.get_unchecked(idx) }
+	}
+
+	/// Reads the bits from `from` onwards, in `usize`-wide runs.
+	fn words(self, from: usize) -> Words<'a, T, O> {
+		let bits = if self.rev {
+			unsafe { self.bits.get_unchecked(.. self.len() - from) }
+		}
+		else {
+			unsafe { self.bits.get_unchecked(from ..) }
+		};
+		Words {
+			bits,
+			rev: self.rev,
+		}
+	}
+}
+
+impl<T, O> Clone for Probe<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, O> Copy for Probe<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+}
+
+/// Reads a bit-slice in `usize`-wide runs, each with its first bit (in the
+/// direction of travel) in the least significant position.
+struct Words<'a, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The bits not yet read.
+	bits: &'a BitSlice<T, O>,
+	/// Whether the bits are read from the back.
+	rev:  bool,
+}
+
+impl<T, O> Iterator for Words<'_, T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Item = (usize, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let len = self.bits.len();
+		if len == 0 {
+			return None;
+		}
+		let width = cmp::min(len, bits_of::<usize>());
+		let chunk = if self.rev {
+			let (rest, chunk) =
+				unsafe { self.bits.split_at_unchecked(len - width) };
+			self.bits = rest;
+			chunk
+		}
+		else {
+			let (chunk, rest) = unsafe { self.bits.split_at_unchecked(width) };
+			self.bits = rest;
+			chunk
+		};
+		let word = Bits::load(chunk);
+		if self.rev {
+			Some((word.reverse_bits() >> (bits_of::<usize>() - width), width))
+		}
+		else {
+			Some((word, width))
+		}
+	}
+}
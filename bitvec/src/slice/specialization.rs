@@ -1,10 +1,14 @@
 #![doc = include_str!("../../doc/slice/specialization.md")]
 
+use core::ops::Range;
+
 use funty::Integral;
 
+use self::simd::BitOp;
 use super::BitSlice;
 use crate::{
 	devel as dvl,
+	domain::Domain,
 	mem,
 	order::BitOrder,
 	store::BitStore,
@@ -12,10 +16,16 @@ use crate::{
 
 mod lsb0;
 mod msb0;
+pub(super) mod simd;
 
 /// Processor width, used for chunking.
 const WORD_BITS: usize = mem::bits_of::<usize>();
 
+/// Produces a word with only its lowest `bits` bits set.
+fn low_mask(bits: usize) -> usize {
+	(!0usize).checked_shl(bits as u32).map_or(!0, |mask| !mask)
+}
+
 /// Tests whether the masked portion of an integer has a `0` bit in it.
 fn has_zero<T>(val: T, mask: T) -> bool
 where T: Integral {
@@ -28,6 +38,30 @@ where T: Integral {
 	val & mask != T::ZERO
 }
 
+/// Counts the elements at the front of a `Domain` body that are all zero.
+///
+/// This only scans the body when the vector kernels can run on it; otherwise
+/// it reports no elements, and the caller examines the body itself.
+fn zero_prefix<T>(body: &[T]) -> usize
+where T: BitStore {
+	simd::bytes(body).map_or(0, |bytes| {
+		simd::first_nonzero(bytes)
+			.map_or(body.len(), |idx| idx / core::mem::size_of::<T>())
+	})
+}
+
+/// Counts the elements at the back of a `Domain` body that are all zero.
+///
+/// See [`zero_prefix`].
+fn zero_suffix<T>(body: &[T]) -> usize
+where T: BitStore {
+	simd::bytes(body).map_or(0, |bytes| {
+		simd::last_nonzero(bytes).map_or(body.len(), |idx| {
+			body.len() - 1 - idx / core::mem::size_of::<T>()
+		})
+	})
+}
+
 impl<T, O> BitSlice<T, O>
 where
 	T: BitStore,
@@ -78,4 +112,81 @@ where
 			None
 		}
 	}
+
+	/// Finds the element-aligned interior that two bit-slices share.
+	///
+	/// When the vector kernels can run on the storage type and both bit-slices
+	/// begin at the same bit within their first element, this produces the
+	/// range of bits, counted from the front of both, that covers only whole
+	/// elements in each. The bits before and after it lie within a single
+	/// partial element of each bit-slice, and the range stops at the end of
+	/// the shorter one.
+	fn simd_interior(&self, other: &Self) -> Option<Range<usize>> {
+		if !simd::ENABLED || !dvl::is_unsigned::<T::Unalias>() {
+			return None;
+		}
+		let head = self.as_bitspan().head();
+		if head != other.as_bitspan().head() {
+			return None;
+		}
+		let width = mem::bits_of::<T::Mem>();
+		let start = match head.into_inner() as usize {
+			| 0 => 0,
+			| h => width - h,
+		};
+		let elts = self.len().min(other.len()).checked_sub(start)? / width;
+		if elts == 0 {
+			return None;
+		}
+		Some(start .. start + elts * width)
+	}
+
+	/// Tests whether two bit-slices found by [`.simd_interior()`] are equal.
+	///
+	/// [`.simd_interior()`]: Self::simd_interior
+	fn simd_eq(&self, other: &Self) -> bool {
+		match (self.domain(), other.domain()) {
+			| (
+				Domain::Region {
+					head: None,
+					body: this,
+					tail: None,
+				},
+				Domain::Region {
+					head: None,
+					body: that,
+					tail: None,
+				},
+			) => match (simd::bytes(this), simd::bytes(that)) {
+				| (Some(a), Some(b)) => simd::eq(a, b),
+				| _ => unreachable!("vectorized bit-slices must be integers"),
+			},
+			| _ => unreachable!("vectorized bit-slices must be element-aligned"),
+		}
+	}
+
+	/// Applies a Boolean operator across two bit-slices found by
+	/// [`.simd_interior()`].
+	///
+	/// [`.simd_interior()`]: Self::simd_interior
+	fn simd_bitop_assign(&mut self, rhs: &Self, op: BitOp) {
+		match (self.domain_mut(), rhs.domain()) {
+			| (
+				Domain::Region {
+					head: None,
+					body: this,
+					tail: None,
+				},
+				Domain::Region {
+					head: None,
+					body: that,
+					tail: None,
+				},
+			) => match (simd::bytes_mut(this), simd::bytes(that)) {
+				| (Some(a), Some(b)) => simd::bitop_assign(a, b, op),
+				| _ => unreachable!("vectorized bit-slices must be integers"),
+			},
+			| _ => unreachable!("vectorized bit-slices must be element-aligned"),
+		}
+	}
 }
@@ -11,6 +11,10 @@ use wyz::{
 use super::{
 	has_one,
 	has_zero,
+	low_mask,
+	simd::BitOp,
+	zero_prefix,
+	zero_suffix,
 	WORD_BITS,
 };
 use crate::{
@@ -41,6 +45,28 @@ where T: BitStore
 		word_op: fn(usize, usize) -> usize,
 		bool_op: fn(bool, bool) -> bool,
 	) {
+		if let (Some(op), Some(mid)) =
+			(BitOp::classify(word_op), self.simd_interior(rhs))
+		{
+			unsafe {
+				let (head, rest) =
+					self.split_at_unchecked_mut_noalias(mid.start);
+				let (body, tail) =
+					rest.split_at_unchecked_mut_noalias(mid.len());
+				head.sp_bitop_assign(
+					rhs.get_unchecked(.. mid.start),
+					word_op,
+					bool_op,
+				);
+				body.simd_bitop_assign(rhs.get_unchecked(mid.clone()), op);
+				tail.sp_bitop_assign(
+					rhs.get_unchecked(mid.end ..),
+					word_op,
+					bool_op,
+				);
+			}
+			return;
+		}
 		let (mut this, mut that) = (self, rhs);
 		while this.len() >= WORD_BITS && that.len() >= WORD_BITS {
 			unsafe {
@@ -105,13 +131,97 @@ where T: BitStore
 		}
 	}
 
+	/// Accelerates shifting towards the front of the bit-slice with batch
+	/// loads.
+	///
+	/// Each word-sized chunk of `self` is loaded from the chunk `by` bits
+	/// later in the bit-slice, so that every bit is read before the chunk that
+	/// holds it is overwritten. The `by` bits vacated at the back of the
+	/// bit-slice are set to `fill`.
+	pub(crate) fn sp_shift_start(&mut self, by: usize, fill: bool) {
+		let fill = if fill { !0usize } else { 0 };
+		let this = self.as_accessor();
+		let mut from = this[by ..].chunks(WORD_BITS);
+		for to in this.chunks(WORD_BITS) {
+			let value = match from.next() {
+				| Some(bits) => {
+					bits.load_le::<usize>()
+						| fill.checked_shl(bits.len() as u32).unwrap_or(0)
+				},
+				| None => fill,
+			};
+			let to = to as *const BitSlice<T::Access, Lsb0>
+				as *mut BitSlice<T::Access, Lsb0>;
+			unsafe {
+				(*to).store_le::<usize>(value);
+			}
+		}
+	}
+
+	/// Accelerates shifting towards the back of the bit-slice with batch
+	/// loads.
+	///
+	/// This is the mirror of [`.sp_shift_start()`], and walks the bit-slice
+	/// from back to front. The `by` bits vacated at the front of the bit-slice
+	/// are set to `fill`.
+	///
+	/// [`.sp_shift_start()`]: Self::sp_shift_start
+	pub(crate) fn sp_shift_end(&mut self, by: usize, fill: bool) {
+		let fill = if fill { !0usize } else { 0 };
+		let this = self.as_accessor();
+		let mut from = this[.. this.len() - by].rchunks(WORD_BITS);
+		for to in this.rchunks(WORD_BITS) {
+			let value = match from.next() {
+				| Some(bits) => {
+					let gap = to.len() - bits.len();
+					(bits.load_le::<usize>() << gap) | (fill & low_mask(gap))
+				},
+				| None => fill,
+			};
+			let to = to as *const BitSlice<T::Access, Lsb0>
+				as *mut BitSlice<T::Access, Lsb0>;
+			unsafe {
+				(*to).store_le::<usize>(value);
+			}
+		}
+	}
+
 	/// Accelerates equality checking with batch loads.
 	pub(crate) fn sp_eq(&self, other: &Self) -> bool {
-		self.len() == other.len()
-			&& self
-				.chunks(WORD_BITS)
-				.zip(other.chunks(WORD_BITS))
-				.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
+		if self.len() != other.len() {
+			return false;
+		}
+		if let Some(mid) = self.simd_interior(other) {
+			return unsafe {
+				self.get_unchecked(.. mid.start)
+					.sp_eq(other.get_unchecked(.. mid.start))
+					&& self
+						.get_unchecked(mid.clone())
+						.simd_eq(other.get_unchecked(mid.clone()))
+					&& self
+						.get_unchecked(mid.end ..)
+						.sp_eq(other.get_unchecked(mid.end ..))
+			};
+		}
+		self.chunks(WORD_BITS)
+			.zip(other.chunks(WORD_BITS))
+			.all(|(a, b)| a.load_le::<usize>() == b.load_le::<usize>())
+	}
+
+	/// Accelerates pairwise population counts with batch loads.
+	///
+	/// The two bit-slices must have equal lengths. Each `usize` chunk is
+	/// loaded from both, combined with `word_op`, and counted.
+	pub(crate) fn sp_count_ones_with(
+		&self,
+		other: &Self,
+		word_op: fn(usize, usize) -> usize,
+	) -> usize {
+		self.chunks(WORD_BITS)
+			.zip(other.chunks(WORD_BITS))
+			.map(|(a, b)| word_op(a.load_le::<usize>(), b.load_le::<usize>()))
+			.map(|word| word.count_ones() as usize)
+			.sum()
 	}
 
 	/// Seeks the index of the first `1` bit in the bit-slice.
@@ -138,7 +248,9 @@ where T: BitStore
 					}
 				}
 
-				for val in body.iter().map(BitStore::load_value) {
+				let skip = zero_prefix(body);
+				accum += skip * bits_of::<T::Mem>();
+				for val in body[skip ..].iter().map(BitStore::load_value) {
 					accum += val.trailing_zeros() as usize;
 					if has_one(val, !<T::Mem as Integral>::ZERO) {
 						return Some(accum);
@@ -184,7 +296,10 @@ where T: BitStore
 					}
 				}
 
-				for val in body.iter().map(BitStore::load_value).rev() {
+				let skip = zero_suffix(body);
+				out -= skip * bits_of::<T::Mem>();
+				let rest = &body[.. body.len() - skip];
+				for val in rest.iter().map(BitStore::load_value).rev() {
 					out -= val.leading_zeros() as usize;
 					if has_one(val, !<T::Mem as Integral>::ZERO) {
 						return Some(out - 1);
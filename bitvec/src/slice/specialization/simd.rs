@@ -0,0 +1,478 @@
+//! Vectorized kernels for the element-aligned interior of a bit-slice.
+//!
+//! The bodies of a bit-slice’s `Domain` are ordinary slices of whole storage
+//! elements. When those elements are plain unsigned integers, popcount,
+//! Boolean arithmetic, equality, and zero-scanning do not depend on either the
+//! bit-ordering or the element width, and can be run over the raw bytes of the
+//! body with vector instructions.
+//!
+//! The kernels are only used when the `simd` feature is enabled and the target
+//! is `x86_64`. AVX2 is used when the processor supports it (detected at
+//! runtime when the standard library is available), and SSE2, which every
+//! `x86_64` processor has, is used otherwise. Each kernel has a scalar
+//! implementation which handles the bytes left over after the last full
+//! vector, and which stands in for the whole kernel on other targets.
+//!
+//! Aliased, `Cell`, and atomic storage is never viewed as bytes: `bytes()`
+//! and `bytes_mut()` return `None`, and the callers keep their element-wise
+//! behavior.
+
+use core::slice;
+
+use crate::{
+	devel as dvl,
+	store::BitStore,
+};
+
+/// Whether the vector kernels are compiled in for this target.
+pub(crate) const ENABLED: bool =
+	cfg!(all(feature = "simd", target_arch = "x86_64"));
+
+/// Views a slice of storage elements as raw bytes, if the vector kernels are
+/// enabled and the elements are plain unsigned integers.
+#[inline]
+pub(crate) fn bytes<T>(elems: &[T]) -> Option<&[u8]>
+where T: BitStore {
+	if !ENABLED || !dvl::is_unsigned::<T>() {
+		return None;
+	}
+	Some(unsafe {
+		slice::from_raw_parts(
+			elems.as_ptr().cast::<u8>(),
+			core::mem::size_of_val(elems),
+		)
+	})
+}
+
+/// See [`bytes`].
+#[inline]
+pub(crate) fn bytes_mut<T>(elems: &mut [T]) -> Option<&mut [u8]>
+where T: BitStore {
+	if !ENABLED || !dvl::is_unsigned::<T>() {
+		return None;
+	}
+	Some(unsafe {
+		slice::from_raw_parts_mut(
+			elems.as_mut_ptr().cast::<u8>(),
+			core::mem::size_of_val(elems),
+		)
+	})
+}
+
+/// The Boolean operators that have vector kernels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum BitOp {
+	/// `a & b`
+	And,
+	/// `a | b`
+	Or,
+	/// `a ^ b`
+	Xor,
+}
+
+impl BitOp {
+	/// Identifies the operator that a word function implements from its truth
+	/// table.
+	#[inline]
+	pub(crate) fn classify(word_op: fn(usize, usize) -> usize) -> Option<Self> {
+		match word_op(0b1100, 0b1010) {
+			| 0b1000 => Some(Self::And),
+			| 0b1110 => Some(Self::Or),
+			| 0b0110 => Some(Self::Xor),
+			| _ => None,
+		}
+	}
+
+	/// Applies the operator to a pair of bytes.
+	#[inline]
+	fn apply(self, a: u8, b: u8) -> u8 {
+		match self {
+			| Self::And => a & b,
+			| Self::Or => a | b,
+			| Self::Xor => a ^ b,
+		}
+	}
+}
+
+/// Counts the `1` bits in a byte slice.
+#[inline]
+pub(crate) fn count_ones(bytes: &[u8]) -> usize {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	unsafe {
+		let (count, done) = if x86::has_avx2() {
+			x86::count_ones_avx2(bytes)
+		}
+		else {
+			x86::count_ones_sse2(bytes)
+		};
+		count + scalar::count_ones(&bytes[done ..])
+	}
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	scalar::count_ones(bytes)
+}
+
+/// Finds the index of the first non-zero byte in a byte slice.
+#[inline]
+pub(crate) fn first_nonzero(bytes: &[u8]) -> Option<usize> {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	unsafe {
+		let (found, done) = if x86::has_avx2() {
+			x86::first_nonzero_avx2(bytes)
+		}
+		else {
+			x86::first_nonzero_sse2(bytes)
+		};
+		found.or_else(|| {
+			scalar::first_nonzero(&bytes[done ..]).map(|idx| done + idx)
+		})
+	}
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	scalar::first_nonzero(bytes)
+}
+
+/// Finds the index of the last non-zero byte in a byte slice.
+#[inline]
+pub(crate) fn last_nonzero(bytes: &[u8]) -> Option<usize> {
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	unsafe {
+		let (found, rest) = if x86::has_avx2() {
+			x86::last_nonzero_avx2(bytes)
+		}
+		else {
+			x86::last_nonzero_sse2(bytes)
+		};
+		found.or_else(|| scalar::last_nonzero(&bytes[.. rest]))
+	}
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	scalar::last_nonzero(bytes)
+}
+
+/// Tests whether two byte slices of equal length have the same contents.
+#[inline]
+pub(crate) fn eq(a: &[u8], b: &[u8]) -> bool {
+	debug_assert_eq!(a.len(), b.len(), "compared slices must be equal length");
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	unsafe {
+		let (same, done) = if x86::has_avx2() {
+			x86::eq_avx2(a, b)
+		}
+		else {
+			x86::eq_sse2(a, b)
+		};
+		same && a[done ..] == b[done ..]
+	}
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	{
+		a == b
+	}
+}
+
+/// Applies a Boolean operator across two byte slices of equal length, writing
+/// the result into the first.
+#[inline]
+pub(crate) fn bitop_assign(dst: &mut [u8], src: &[u8], op: BitOp) {
+	debug_assert_eq!(dst.len(), src.len(), "operands must be equal length");
+	#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+	unsafe {
+		let done = if x86::has_avx2() {
+			x86::bitop_assign_avx2(dst, src, op)
+		}
+		else {
+			x86::bitop_assign_sse2(dst, src, op)
+		};
+		scalar::bitop_assign(&mut dst[done ..], &src[done ..], op);
+	}
+	#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+	scalar::bitop_assign(dst, src, op)
+}
+
+/// Portable implementations of the kernels.
+mod scalar {
+	use super::BitOp;
+
+	/// Counts the `1` bits in a byte slice, eight bytes at a time.
+	pub(super) fn count_ones(bytes: &[u8]) -> usize {
+		let mut chunks = bytes.chunks_exact(8);
+		let mut count = chunks
+			.by_ref()
+			.map(|chunk| {
+				let mut word = [0; 8];
+				word.copy_from_slice(chunk);
+				u64::from_ne_bytes(word).count_ones() as usize
+			})
+			.sum::<usize>();
+		count += chunks
+			.remainder()
+			.iter()
+			.map(|byte| byte.count_ones() as usize)
+			.sum::<usize>();
+		count
+	}
+
+	/// Finds the index of the first non-zero byte.
+	pub(super) fn first_nonzero(bytes: &[u8]) -> Option<usize> {
+		bytes.iter().position(|&byte| byte != 0)
+	}
+
+	/// Finds the index of the last non-zero byte.
+	pub(super) fn last_nonzero(bytes: &[u8]) -> Option<usize> {
+		bytes.iter().rposition(|&byte| byte != 0)
+	}
+
+	/// Applies a Boolean operator across two byte slices.
+	pub(super) fn bitop_assign(dst: &mut [u8], src: &[u8], op: BitOp) {
+		for (a, &b) in dst.iter_mut().zip(src) {
+			*a = op.apply(*a, b);
+		}
+	}
+}
+
+/// SSE2 and AVX2 implementations of the kernels.
+///
+/// Each kernel processes as many whole vectors as fit in its input, and
+/// reports how far it got so that the caller can finish the remainder with the
+/// scalar kernel.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod x86 {
+	use core::arch::x86_64::*;
+
+	use super::BitOp;
+
+	/// Width of an SSE2 vector, in bytes.
+	const SSE2: usize = 16;
+	/// Width of an AVX2 vector, in bytes.
+	const AVX2: usize = 32;
+
+	/// Tests whether the processor supports AVX2.
+	#[inline]
+	pub(super) fn has_avx2() -> bool {
+		#[cfg(target_feature = "avx2")]
+		{
+			true
+		}
+		#[cfg(all(not(target_feature = "avx2"), feature = "std"))]
+		{
+			std::is_x86_feature_detected!("avx2")
+		}
+		#[cfg(all(not(target_feature = "avx2"), not(feature = "std")))]
+		{
+			false
+		}
+	}
+
+	/// Counts `1` bits with the nibble-lookup method: each half-byte indexes a
+	/// sixteen-entry table of popcounts, and the byte counts are summed into
+	/// 64-bit lanes with `vpsadbw`.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn count_ones_avx2(bytes: &[u8]) -> (usize, usize) {
+		let table = _mm256_setr_epi8(
+			0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4, //
+			0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+		);
+		let low = _mm256_set1_epi8(0x0F);
+		let zero = _mm256_setzero_si256();
+		let mut accum = _mm256_setzero_si256();
+		let mut done = 0;
+		while done + AVX2 <= bytes.len() {
+			let vec = _mm256_loadu_si256(bytes.as_ptr().add(done).cast());
+			let lo = _mm256_and_si256(vec, low);
+			let hi = _mm256_and_si256(_mm256_srli_epi16::<4>(vec), low);
+			let counts = _mm256_add_epi8(
+				_mm256_shuffle_epi8(table, lo),
+				_mm256_shuffle_epi8(table, hi),
+			);
+			accum = _mm256_add_epi64(accum, _mm256_sad_epu8(counts, zero));
+			done += AVX2;
+		}
+		let mut lanes = [0u64; 4];
+		_mm256_storeu_si256(lanes.as_mut_ptr().cast(), accum);
+		(lanes.iter().sum::<u64>() as usize, done)
+	}
+
+	/// Counts `1` bits with a vectorized SWAR reduction, as SSE2 has no byte
+	/// shuffle to drive a lookup table.
+	#[target_feature(enable = "sse2")]
+	pub(super) unsafe fn count_ones_sse2(bytes: &[u8]) -> (usize, usize) {
+		let m1 = _mm_set1_epi8(0x55);
+		let m2 = _mm_set1_epi8(0x33);
+		let m4 = _mm_set1_epi8(0x0F);
+		let zero = _mm_setzero_si128();
+		let mut accum = _mm_setzero_si128();
+		let mut done = 0;
+		while done + SSE2 <= bytes.len() {
+			let mut vec = _mm_loadu_si128(bytes.as_ptr().add(done).cast());
+			vec = _mm_sub_epi8(vec, _mm_and_si128(_mm_srli_epi16::<1>(vec), m1));
+			vec = _mm_add_epi8(
+				_mm_and_si128(vec, m2),
+				_mm_and_si128(_mm_srli_epi16::<2>(vec), m2),
+			);
+			vec = _mm_and_si128(_mm_add_epi8(vec, _mm_srli_epi16::<4>(vec)), m4);
+			accum = _mm_add_epi64(accum, _mm_sad_epu8(vec, zero));
+			done += SSE2;
+		}
+		let mut lanes = [0u64; 2];
+		_mm_storeu_si128(lanes.as_mut_ptr().cast(), accum);
+		(lanes.iter().sum::<u64>() as usize, done)
+	}
+
+	/// Seeks the first vector with a non-zero byte in it.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn first_nonzero_avx2(
+		bytes: &[u8],
+	) -> (Option<usize>, usize) {
+		let zero = _mm256_setzero_si256();
+		let mut done = 0;
+		while done + AVX2 <= bytes.len() {
+			let vec = _mm256_loadu_si256(bytes.as_ptr().add(done).cast());
+			let zeros =
+				_mm256_movemask_epi8(_mm256_cmpeq_epi8(vec, zero)) as u32;
+			if zeros != !0 {
+				return (Some(done + (!zeros).trailing_zeros() as usize), done);
+			}
+			done += AVX2;
+		}
+		(None, done)
+	}
+
+	/// Seeks the first vector with a non-zero byte in it.
+	#[target_feature(enable = "sse2")]
+	pub(super) unsafe fn first_nonzero_sse2(
+		bytes: &[u8],
+	) -> (Option<usize>, usize) {
+		let zero = _mm_setzero_si128();
+		let mut done = 0;
+		while done + SSE2 <= bytes.len() {
+			let vec = _mm_loadu_si128(bytes.as_ptr().add(done).cast());
+			let zeros = _mm_movemask_epi8(_mm_cmpeq_epi8(vec, zero)) as u32;
+			if zeros != 0xFFFF {
+				return (Some(done + (!zeros).trailing_zeros() as usize), done);
+			}
+			done += SSE2;
+		}
+		(None, done)
+	}
+
+	/// Seeks the last vector with a non-zero byte in it, working backwards
+	/// from the end. The second return value is the length of the unsearched
+	/// front of the slice.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn last_nonzero_avx2(
+		bytes: &[u8],
+	) -> (Option<usize>, usize) {
+		let zero = _mm256_setzero_si256();
+		let mut rest = bytes.len();
+		while rest >= AVX2 {
+			let base = rest - AVX2;
+			let vec = _mm256_loadu_si256(bytes.as_ptr().add(base).cast());
+			let zeros =
+				_mm256_movemask_epi8(_mm256_cmpeq_epi8(vec, zero)) as u32;
+			if zeros != !0 {
+				let idx = AVX2 - 1 - (!zeros).leading_zeros() as usize;
+				return (Some(base + idx), rest);
+			}
+			rest = base;
+		}
+		(None, rest)
+	}
+
+	/// Seeks the last vector with a non-zero byte in it, working backwards
+	/// from the end. The second return value is the length of the unsearched
+	/// front of the slice.
+	#[target_feature(enable = "sse2")]
+	pub(super) unsafe fn last_nonzero_sse2(
+		bytes: &[u8],
+	) -> (Option<usize>, usize) {
+		let zero = _mm_setzero_si128();
+		let mut rest = bytes.len();
+		while rest >= SSE2 {
+			let base = rest - SSE2;
+			let vec = _mm_loadu_si128(bytes.as_ptr().add(base).cast());
+			let zeros = _mm_movemask_epi8(_mm_cmpeq_epi8(vec, zero)) as u32;
+			if zeros != 0xFFFF {
+				let idx = 31 - (!zeros & 0xFFFF).leading_zeros() as usize;
+				return (Some(base + idx), rest);
+			}
+			rest = base;
+		}
+		(None, rest)
+	}
+
+	/// Compares two byte slices a vector at a time, stopping at the first
+	/// difference.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn eq_avx2(a: &[u8], b: &[u8]) -> (bool, usize) {
+		let mut done = 0;
+		while done + AVX2 <= a.len() {
+			let x = _mm256_loadu_si256(a.as_ptr().add(done).cast());
+			let y = _mm256_loadu_si256(b.as_ptr().add(done).cast());
+			let diff = _mm256_xor_si256(x, y);
+			if _mm256_testz_si256(diff, diff) == 0 {
+				return (false, done);
+			}
+			done += AVX2;
+		}
+		(true, done)
+	}
+
+	/// Compares two byte slices a vector at a time, stopping at the first
+	/// difference.
+	#[target_feature(enable = "sse2")]
+	pub(super) unsafe fn eq_sse2(a: &[u8], b: &[u8]) -> (bool, usize) {
+		let mut done = 0;
+		while done + SSE2 <= a.len() {
+			let x = _mm_loadu_si128(a.as_ptr().add(done).cast());
+			let y = _mm_loadu_si128(b.as_ptr().add(done).cast());
+			if _mm_movemask_epi8(_mm_cmpeq_epi8(x, y)) != 0xFFFF {
+				return (false, done);
+			}
+			done += SSE2;
+		}
+		(true, done)
+	}
+
+	/// Applies a Boolean operator a vector at a time.
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn bitop_assign_avx2(
+		dst: &mut [u8],
+		src: &[u8],
+		op: BitOp,
+	) -> usize {
+		let mut done = 0;
+		while done + AVX2 <= dst.len() {
+			let ptr = dst.as_mut_ptr().add(done).cast::<__m256i>();
+			let x = _mm256_loadu_si256(ptr);
+			let y = _mm256_loadu_si256(src.as_ptr().add(done).cast());
+			let out = match op {
+				| BitOp::And => _mm256_and_si256(x, y),
+				| BitOp::Or => _mm256_or_si256(x, y),
+				| BitOp::Xor => _mm256_xor_si256(x, y),
+			};
+			_mm256_storeu_si256(ptr, out);
+			done += AVX2;
+		}
+		done
+	}
+
+	/// Applies a Boolean operator a vector at a time.
+	#[target_feature(enable = "sse2")]
+	pub(super) unsafe fn bitop_assign_sse2(
+		dst: &mut [u8],
+		src: &[u8],
+		op: BitOp,
+	) -> usize {
+		let mut done = 0;
+		while done + SSE2 <= dst.len() {
+			let ptr = dst.as_mut_ptr().add(done).cast::<__m128i>();
+			let x = _mm_loadu_si128(ptr);
+			let y = _mm_loadu_si128(src.as_ptr().add(done).cast());
+			let out = match op {
+				| BitOp::And => _mm_and_si128(x, y),
+				| BitOp::Or => _mm_or_si128(x, y),
+				| BitOp::Xor => _mm_xor_si128(x, y),
+			};
+			_mm_storeu_si128(ptr, out);
+			done += SSE2;
+		}
+		done
+	}
+}
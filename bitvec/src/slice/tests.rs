@@ -2,6 +2,11 @@
 
 #![cfg(test)]
 
+#[cfg(feature = "alloc")]
+use alloc::{
+	vec,
+	vec::Vec,
+};
 use core::cell::Cell;
 
 use rand::random;
@@ -12,8 +17,15 @@ use crate::{
 };
 
 mod api;
+mod atomic;
+mod extract;
 mod iter;
+mod metric;
 mod ops;
+mod par;
+mod runs;
+mod search;
+mod simd;
 mod traits;
 
 #[test]
@@ -154,6 +166,17 @@ fn bit_counting() {
 	assert!([!0u8; 1].view_bits::<Msb0>()[1 .. 7].last_zero().is_none());
 	assert!([!0u8; 3].view_bits::<Msb0>()[1 .. 23].last_zero().is_none());
 
+	//  Searches that reach the front of an element-aligned bit-slice must not
+	//  underflow their index counter.
+	assert!([0u8; 2].view_bits::<Msb0>().last_one().is_none());
+	assert!([0u8; 2].view_bits::<Msb0>()[.. 12].last_one().is_none());
+	assert!([!0u8; 2].view_bits::<Msb0>().last_zero().is_none());
+	assert!([!0u8; 2].view_bits::<Msb0>()[.. 12].last_zero().is_none());
+	assert_eq!([1u8, 0].view_bits::<Msb0>().last_one(), Some(7));
+	assert_eq!([!1u8, !0].view_bits::<Msb0>().last_zero(), Some(7));
+	assert_eq!([0x80u8, 0].view_bits::<Msb0>()[.. 12].last_one(), Some(0));
+	assert_eq!([0x7Fu8, !0].view_bits::<Msb0>()[.. 12].last_zero(), Some(0));
+
 	let data = 0b0100_0100u8;
 	assert_eq!(data.view_bits::<Lsb0>()[1 .. 7].first_one(), Some(1));
 	assert_eq!(data.view_bits::<Lsb0>()[1 .. 7].last_one(), Some(5));
@@ -223,6 +246,57 @@ fn shunting() {
 	assert_eq!(bits, bits![0, 1, 1, 1, 0]);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn shunting_fill() {
+	fn check<O>(bits: &mut BitSlice<u16, O>)
+	where O: BitOrder {
+		let model = bits.iter().by_vals().collect::<Vec<_>>();
+		let len = model.len();
+		let (first, last) = (model[0], model[len - 1]);
+		for by in [0, 1, 7, 15, 16, 17, 40, len - 1, len] {
+			let mut start = model[by ..].to_vec();
+			start.resize(len, last);
+			let mut end = vec![first; by];
+			end.extend_from_slice(&model[.. len - by]);
+
+			bits.shift_start_signed(by);
+			assert!(bits.iter().by_vals().eq(start.iter().copied()), "{}", by);
+			bits.iter_mut().zip(&model).for_each(|(mut b, m)| *b = *m);
+			bits.shift_end_signed(by);
+			assert!(bits.iter().by_vals().eq(end.iter().copied()), "{}", by);
+			bits.iter_mut().zip(&model).for_each(|(mut b, m)| *b = *m);
+
+			for carry in [false, true] {
+				let mut ring = model.clone();
+				ring.push(carry);
+				ring.rotate_left(by % (len + 1));
+				let mut c = carry;
+				bits.rotate_start_through_carry(by, &mut c);
+				assert!(bits.iter().by_vals().eq(ring[.. len].iter().copied()));
+				assert_eq!(c, ring[len]);
+				bits.iter_mut().zip(&model).for_each(|(mut b, m)| *b = *m);
+
+				let mut ring = model.clone();
+				ring.insert(0, carry);
+				ring.rotate_right(by % (len + 1));
+				let mut c = carry;
+				bits.rotate_end_through_carry(by, &mut c);
+				assert!(bits.iter().by_vals().eq(ring[1 ..].iter().copied()));
+				assert_eq!(c, ring[0]);
+				bits.iter_mut().zip(&model).for_each(|(mut b, m)| *b = *m);
+			}
+		}
+	}
+
+	let mut data = random::<[u16; 12]>();
+	check(&mut data.view_bits_mut::<Lsb0>()[5 .. 180]);
+	check(&mut data.view_bits_mut::<Msb0>()[3 .. 190]);
+	check(&mut data.view_bits_mut::<Lsb0>()[.. 64]);
+	check(&mut data.view_bits_mut::<Msb0>()[128 ..]);
+	check(&mut data.view_bits_mut::<HiLo>()[9 .. 170]);
+}
+
 #[test]
 fn aliasing() {
 	let bits = bits![Cell<u32>, Lsb0; 0];
@@ -0,0 +1,162 @@
+#![cfg(feature = "std")]
+
+use core::sync::atomic::{
+	AtomicU16,
+	AtomicU8,
+	AtomicUsize,
+	Ordering,
+};
+use std::thread;
+
+use crate::{
+	mem::bits_of,
+	prelude::*,
+};
+
+#[test]
+fn fetch_bits() {
+	let data = [AtomicU16::new(0), AtomicU16::new(0)];
+	let bits = data.view_bits::<Msb0>();
+
+	assert!(!bits.fetch_set(20, Ordering::SeqCst));
+	assert!(bits.fetch_set(20, Ordering::SeqCst));
+	assert_eq!(data[1].load(Ordering::SeqCst), 0x0800);
+	assert!(bits.fetch_toggle(20, Ordering::SeqCst));
+	assert!(!bits.fetch_toggle(0, Ordering::SeqCst));
+	assert!(bits.fetch_clear(0, Ordering::SeqCst));
+	assert!(!bits.fetch_clear(0, Ordering::SeqCst));
+	assert!(bits.not_any());
+
+	let bits = &bits[3 ..];
+	assert!(!bits.fetch_set(0, Ordering::Relaxed));
+	assert_eq!(data[0].load(Ordering::Relaxed), 0x1000);
+}
+
+#[test]
+#[should_panic]
+fn fetch_out_of_bounds() {
+	let data = AtomicU16::new(0);
+	data.view_bits::<Lsb0>()[.. 4].fetch_set(4, Ordering::Relaxed);
+}
+
+#[test]
+fn fields() {
+	let data = AtomicU16::new(0xFFFF);
+	let bits = data.view_bits::<Msb0>();
+	let order = Ordering::AcqRel;
+
+	assert_eq!(
+		bits.compare_exchange_field(2 .. 6, 0xF, 0x3, order),
+		Ok(0xF)
+	);
+	assert_eq!(bits[2 .. 6].load::<u8>(), 0x3);
+	assert_eq!(data.load(Ordering::Relaxed), 0b1100_1111_1111_1111);
+	assert_eq!(
+		bits.compare_exchange_field(2 .. 6, 0xF, 0x0, order),
+		Err(0x3)
+	);
+	//  Bits of `new` that do not fit in the field are discarded.
+	assert_eq!(bits.compare_exchange_field(.. 2, 0x3, 0x6, order), Ok(0x3));
+	assert_eq!(data.load(Ordering::Relaxed), 0b1000_1111_1111_1111);
+
+	let data = AtomicUsize::new(0);
+	let bits = data.view_bits::<Lsb0>();
+	assert_eq!(bits.compare_exchange_field(.., 0, !0, order), Ok(0));
+	assert_eq!(data.load(Ordering::Relaxed), !0);
+}
+
+#[test]
+fn fields_not_contiguous() {
+	use crate::order::HiLo;
+
+	//  `HiLo` swaps nibbles, so `[2 .. 6]` covers bits 6, 7, 0, and 1.
+	let data = AtomicU8::new(0b0011_1100);
+	let bits = data.view_bits::<HiLo>();
+	let order = Ordering::AcqRel;
+
+	assert_eq!(bits.compare_exchange_field(2 .. 6, 0, 0b1001, order), Ok(0));
+	assert_eq!(data.load(Ordering::Relaxed), 0b1011_1101);
+	assert_eq!(bits[2 .. 6].load_le::<u8>(), 0b1001);
+	assert_eq!(
+		bits.compare_exchange_field(2 .. 6, 0, 0b0110, order),
+		Err(0b1001),
+	);
+
+	data.store(0b0111_1110, Ordering::Relaxed);
+	assert_eq!(bits[2 .. 6].load_le::<u8>(), 0b0110);
+	assert_eq!(
+		bits.compare_exchange_field(2 .. 6, 0b0110, 0b1111, order),
+		Ok(0b0110),
+	);
+	assert_eq!(data.load(Ordering::Relaxed), 0b1111_1111);
+}
+
+#[test]
+#[should_panic]
+fn field_crosses_elements() {
+	let data = [AtomicU16::new(0), AtomicU16::new(0)];
+	let bits = data.view_bits::<Lsb0>();
+	let _ = bits.compare_exchange_field(12 .. 20, 0, 1, Ordering::Relaxed);
+}
+
+#[test]
+fn ranges() {
+	let data = [AtomicU16::new(0), AtomicU16::new(0), AtomicU16::new(0)];
+	let bits = data.view_bits::<Lsb0>();
+
+	assert_eq!(bits.fetch_set_range(5 .. 40, Ordering::Relaxed), 35);
+	assert_eq!(bits.fetch_set_range(.. 10, Ordering::Relaxed), 5);
+	assert_eq!(bits.fetch_set_range(3 .. 3, Ordering::Relaxed), 0);
+	assert_eq!(bits.count_ones(), 40);
+	assert_eq!(data[0].load(Ordering::Relaxed), !0);
+	assert_eq!(data[2].load(Ordering::Relaxed), 0x00FF);
+
+	assert_eq!(bits.fetch_clear_range(14 .. 18, Ordering::Relaxed), 4);
+	assert_eq!(bits.fetch_clear_range(12 .., Ordering::Relaxed), 24);
+	assert_eq!(bits.count_ones(), 12);
+	assert_eq!(data[0].load(Ordering::Relaxed), 0x0FFF);
+}
+
+#[test]
+fn contended() {
+	const THREADS: usize = 8;
+	let data = [(); 4].map(|()| AtomicUsize::new(0));
+	let bits = data.view_bits::<Lsb0>();
+	let claims = thread::scope(|scope| {
+		let handles = (0 .. THREADS)
+			.map(|_| {
+				scope.spawn(|| {
+					(0 .. bits.len())
+						.filter(|&idx| !bits.fetch_set(idx, Ordering::AcqRel))
+						.count()
+				})
+			})
+			.collect::<Vec<_>>();
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap())
+			.sum::<usize>()
+	});
+	//  Every bit is claimed by exactly one thread.
+	assert_eq!(claims, 4 * bits_of::<usize>());
+	assert!(bits.all());
+
+	let cleared = thread::scope(|scope| {
+		let handles = (0 .. THREADS)
+			.map(|n| {
+				scope.spawn(move || {
+					bits.fetch_clear_range(
+						n * 3 .. n * 3 + 100,
+						Ordering::AcqRel,
+					)
+				})
+			})
+			.collect::<Vec<_>>();
+		handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap())
+			.sum::<usize>()
+	});
+	assert_eq!(cleared, 121);
+	assert_eq!(bits.count_zeros(), 121);
+}
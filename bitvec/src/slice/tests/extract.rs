@@ -0,0 +1,86 @@
+#![cfg(feature = "alloc")]
+
+use core::cell::Cell;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn extract_deposit() {
+	let bits = bits![mut u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+	let mask = bits![u16, Lsb0; 1, 1, 0, 1, 0, 1, 1, 0, 0, 1];
+	let extracted = bits.extract_by(mask);
+	assert_eq!(extracted, bits![1, 0, 1, 0, 1, 1]);
+
+	bits.deposit_by(mask, bits![0, 1, 0, 1, 0, 0]);
+	assert_eq!(bits, bits![0, 1, 1, 0, 0, 1, 0, 0, 1, 0]);
+	//  Surplus source bits are ignored.
+	bits.deposit_by(mask, bits![1, 0, 1, 0, 1, 1, 0, 0]);
+	assert_eq!(bits, bits![1, 0, 1, 1, 0, 0, 1, 0, 1, 1]);
+
+	assert!(bits.extract_by(bits![0; 10]).is_empty());
+	bits.deposit_by(bits![0; 10], bits![]);
+	assert_eq!(bits.extract_by(bits![1; 10]), bits);
+}
+
+#[test]
+fn element_boundaries() {
+	//  Partial head and tail elements, with a whole element between them. The
+	//  mask selects every odd bit of the underlying elements.
+	let mut data = [0xF0F0_F0F0u32, !0, 0x0000_FFFF];
+	let mask = [0xAAu8; 12];
+	let bits = &mut data.view_bits_mut::<Lsb0>()[4 .. 92];
+	let mask = &mask.view_bits::<Lsb0>()[4 .. 92];
+
+	let extracted = bits.extract_by(mask);
+	assert_eq!(extracted.len(), 44);
+	assert_eq!(extracted[.. 14], bits![
+		1, 1, 0, 0, 1, 1, 0, 0, 1, 1, 0, 0, 1, 1
+	]);
+	assert!(extracted[14 .. 38].all());
+	assert!(extracted[38 ..].not_any());
+
+	bits.deposit_by(mask, bits![u64, Msb0; 1; 44]);
+	assert_eq!(data, [0xFAFA_FAF0, !0, 0x0AAA_FFFF]);
+
+	data.view_bits_mut::<Lsb0>()[4 .. 92].deposit_by(mask, &extracted);
+	assert_eq!(data, [0xF0F0_F0F0, !0, 0x0000_FFFF]);
+}
+
+#[test]
+fn aliased_and_reordered() {
+	//  `HiLo` places index `n` at position `n ^ 4`.
+	let mut data = [0x0Fu8; 2];
+	let mask = [0xF0u8; 2];
+	let mask = mask.view_bits::<Lsb0>();
+	assert!(data.view_bits::<HiLo>().extract_by(mask).all());
+	data.view_bits_mut::<HiLo>().deposit_by(mask, bits![0; 8]);
+	assert_eq!(data, [0; 2]);
+
+	let mut cells = [Cell::new(0b1100_1010u8)];
+	let mask = bits![1, 1, 1, 1, 0, 0, 0, 0];
+	assert_eq!(cells.view_bits::<Lsb0>().extract_by(mask), bits![0, 1, 0, 1]);
+	cells.view_bits_mut::<Lsb0>().deposit_by(mask, bits![1, 1, 1, 1]);
+	assert_eq!(cells[0].get(), 0b1100_1111);
+
+	let mut data = [0xFF00u16, 0x00FF];
+	let (left, right) = data.view_bits_mut::<Msb0>().split_at_mut(16);
+	assert!(right.extract_by(&*left).not_any());
+	right.deposit_by(&*left, bits![1; 8]);
+	left.deposit_by(&*right, bits![0; 16]);
+	assert_eq!(data, [0, !0]);
+}
+
+#[test]
+#[should_panic = "requires equal lengths"]
+fn mask_length() {
+	bits![0; 4].extract_by(bits![1; 3]);
+}
+
+#[test]
+#[should_panic = "deposit source has 2 bits"]
+fn deposit_short() {
+	bits![mut 0; 4].deposit_by(bits![1, 0, 1, 1], bits![1, 1]);
+}
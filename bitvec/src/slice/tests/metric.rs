@@ -0,0 +1,76 @@
+use core::cell::Cell;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn pairwise_counts() {
+	let a = bits![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1, 1];
+	let b = bits![u16, Lsb0; 0, 0, 1, 1, 1, 0, 1, 1];
+
+	assert_eq!(a.count_ones_and(b), 3);
+	assert_eq!(a.count_ones_or(b), 8);
+	assert_eq!(a.count_ones_xor(b), 5);
+	assert_eq!(a.hamming_distance(b), 5);
+	assert_eq!(a.jaccard(b), 3.0 / 8.0);
+
+	//  The overhang of the longer bit-slice is counted against `0` bits, in
+	//  either argument position.
+	assert_eq!(b.count_ones_and(a), 3);
+	assert_eq!(b.count_ones_or(a), 8);
+	assert_eq!(b.hamming_distance(a), 5);
+}
+
+#[test]
+fn element_boundaries() {
+	//  Partial head and tail elements, with a whole element between them.
+	let a = [0xF0F0_F0F0u32, !0, 0x0000_FFFF];
+	let b = [!0u8; 12];
+	let a = &a.view_bits::<Lsb0>()[4 .. 92];
+	let b = &b.view_bits::<Msb0>()[4 .. 92];
+	assert_eq!(a.count_ones(), 64);
+	assert_eq!(a.count_ones_and(b), 64);
+	assert_eq!(a.count_ones_or(b), 88);
+	assert_eq!(b.hamming_distance(a), 24);
+	assert_eq!(a.jaccard(b), 64.0 / 88.0);
+
+	//  `HiLo` places index `n` at position `n ^ 4`.
+	let hilo = [0x0Fu8; 4];
+	let lsb0 = [0xF0u8; 4];
+	let (hilo, lsb0) = (hilo.view_bits::<HiLo>(), lsb0.view_bits::<Lsb0>());
+	assert_eq!(hilo.count_ones_and(lsb0), 16);
+	assert_eq!(hilo.hamming_distance(lsb0), 0);
+
+	let cells = [Cell::new(!0u8), Cell::new(0)];
+	let cells = cells.view_bits::<Lsb0>();
+	assert_eq!(cells.count_ones_and(bits![1; 8]), 8);
+	assert_eq!(cells.hamming_distance(bits![1; 8]), 0);
+
+	let mut data = [0x00A5u16; 2];
+	let (left, right) = data.view_bits_mut::<Msb0>().split_at_mut(16);
+	assert_eq!(left.count_ones_and(right), 4);
+	assert_eq!(right.hamming_distance(left), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn matches_operators() {
+	let a = [0x1234u16, 0xABCD, 0x5A5A, 0xF00F, 0x0FF0];
+	let b = [0xC3u8, 0x96, 0x71, 0xE8, 0x2D, 0xB4, 0x5F, 0x0A, 0x99];
+	let a = &a.view_bits::<Lsb0>()[3 .. 68];
+	let b = &b.view_bits::<Msb0>()[5 .. 70];
+
+	assert_eq!(a.count_ones_and(b), (a & b).count_ones());
+	assert_eq!(a.count_ones_or(b), (a | b).count_ones());
+	assert_eq!(a.count_ones_xor(b), (a ^ b).count_ones());
+}
+
+#[test]
+fn jaccard_edges() {
+	assert_eq!(bits![].jaccard(bits![]), 1.0);
+	assert_eq!(bits![0; 10].jaccard(bits![0; 3]), 1.0);
+	assert_eq!(bits![1; 10].jaccard(bits![0; 3]), 0.0);
+	assert_eq!(bits![1, 1, 0, 0].jaccard(bits![0, 1, 1, 0]), 1.0 / 3.0);
+}
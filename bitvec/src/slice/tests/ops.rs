@@ -108,6 +108,57 @@ fn bit_not() {
 	assert_eq!(c, [0xFF_FF_00_00, 0xFF_00_00_FF, 0x00_00_FF_FF]);
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn non_assigning() {
+	let a = random::<[u16; 5]>();
+	let b = random::<[u32; 2]>();
+	let a = &a.view_bits::<Msb0>()[3 ..];
+	let b = &b.view_bits::<Lsb0>()[5 ..];
+	let len = b.len();
+	assert!(len < a.len());
+
+	let pairs = || a.iter().by_vals().zip(b.iter().by_vals());
+	let and = a & b;
+	let or = a | b;
+	let xor = a ^ b;
+	assert_eq!(and.len(), len);
+	assert_eq!(or.len(), len);
+	assert_eq!(xor.len(), len);
+	assert!(and.iter().by_vals().eq(pairs().map(|(a, b)| a & b)));
+	assert!(or.iter().by_vals().eq(pairs().map(|(a, b)| a | b)));
+	assert!(xor.iter().by_vals().eq(pairs().map(|(a, b)| a ^ b)));
+
+	assert!(a
+		.and_not(b)
+		.iter()
+		.by_vals()
+		.eq(pairs().map(|(a, b)| a & !b)));
+	assert!(a
+		.nand(b)
+		.iter()
+		.by_vals()
+		.eq(pairs().map(|(a, b)| !(a & b))));
+	assert!(a.nor(b).iter().by_vals().eq(pairs().map(|(a, b)| !(a | b))));
+	assert!(a
+		.xnor(b)
+		.iter()
+		.by_vals()
+		.eq(pairs().map(|(a, b)| !(a ^ b))));
+	assert_eq!(b & a, and);
+
+	let not = !a;
+	assert!(not.iter().by_vals().eq(a.iter().by_vals().map(|bit| !bit)));
+
+	let b = b.zero_extended(a.len());
+	let and = a & b.as_bitslice();
+	let or = a | b.as_bitslice();
+	assert_eq!(and.len(), a.len());
+	assert_eq!(and[.. len], (a & &b[.. len])[..]);
+	assert!(and[len ..].not_any());
+	assert_eq!(or[len ..], a[len ..]);
+}
+
 #[test]
 fn indexing() {
 	let bits = bits![mut 0, 1, 0, 0, 1];
@@ -0,0 +1,88 @@
+#![cfg(feature = "rayon")]
+
+use rand::random;
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+#[test]
+fn par_seek() {
+	let mut bv = BitVec::<u16, Msb0>::repeat(false, 200_003);
+	for _ in 0 .. 500 {
+		let idx = random::<usize>() % bv.len();
+		bv.set(idx, true);
+	}
+	for start in [0, 1, 13, 4096] {
+		let bits = &bv[start ..];
+		assert_eq!(bits.par_count_ones(), bits.count_ones());
+		assert_eq!(
+			bits.par_iter_ones().collect::<Vec<_>>(),
+			bits.iter_ones().collect::<Vec<_>>(),
+		);
+	}
+	assert_eq!(bits![].par_count_ones(), 0);
+	assert_eq!(bits![0, 1, 1].par_iter_ones().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn par_chunks() {
+	let bv = (0 .. 50_001)
+		.map(|_| random::<bool>())
+		.collect::<BitVec<u8>>();
+	for width in [1, 7, 64, 10_000] {
+		let serial = bv.chunks(width).collect::<Vec<_>>();
+		let parallel = bv.par_chunks(width).collect::<Vec<_>>();
+		assert_eq!(parallel.len(), bv.par_chunks(width).len());
+		assert_eq!(serial, parallel);
+	}
+
+	let mut bv = BitVec::<u32, Lsb0>::repeat(false, 50_001);
+	bv[5 ..]
+		.par_chunks_mut(7)
+		.enumerate()
+		.for_each(|(idx, chunk)| {
+			chunk.set(0, true);
+			chunk.set(chunk.len() - 1, idx % 2 == 0);
+		});
+	for (idx, chunk) in bv[5 ..].chunks(7).enumerate() {
+		assert!(chunk[0]);
+		assert_eq!(chunk[chunk.len() - 1], idx % 2 == 0 || chunk.len() == 1);
+	}
+	assert!(bv[.. 5].not_any());
+}
+
+#[test]
+fn par_bitops() {
+	let len = 100_003;
+	let a = (0 .. len)
+		.map(|_| random::<bool>())
+		.collect::<BitVec<u64, Lsb0>>();
+	let b = (0 .. len - 9_000)
+		.map(|_| random::<bool>())
+		.collect::<BitVec<u8, Msb0>>();
+
+	for start in [0, 3, 65] {
+		let mut serial = a.clone();
+		let mut parallel = a.clone();
+		serial[start ..] &= &b[..];
+		parallel[start ..].par_bitand_assign(&b[..]);
+		assert_eq!(serial, parallel);
+
+		let mut serial = a.clone();
+		let mut parallel = a.clone();
+		serial[start ..] |= &b[..];
+		parallel[start ..].par_bitor_assign(&b[..]);
+		assert_eq!(serial, parallel);
+
+		let mut serial = a.clone();
+		let mut parallel = a.clone();
+		serial[start ..] ^= &b[..];
+		parallel[start ..].par_bitxor_assign(&b[..]);
+		assert_eq!(serial, parallel);
+
+		let mut parallel = a.clone();
+		parallel[start ..].par_fill(true);
+		assert_eq!(parallel[.. start], a[.. start]);
+		assert!(parallel[start ..].all());
+	}
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn runs() {
+	let bits = bits![u8, Msb0; 1, 1, 0, 0, 0, 1, 0, 1, 1, 1];
+	assert_eq!(bits.iter_runs().collect::<Vec<_>>(), [
+		(true, 0 .. 2),
+		(false, 2 .. 5),
+		(true, 5 .. 6),
+		(false, 6 .. 7),
+		(true, 7 .. 10),
+	]);
+	assert_eq!(bits.iter_runs().next_back(), Some((true, 7 .. 10)));
+	assert_eq!(bits.iter_one_runs().collect::<Vec<_>>(), [
+		0 .. 2,
+		5 .. 6,
+		7 .. 10,
+	]);
+	assert!(bits.iter_zero_runs().rev().eq([6 .. 7, 2 .. 5]));
+	assert_eq!(bits.longest_run(true), Some(7 .. 10));
+	assert_eq!(bits.longest_run(false), Some(2 .. 5));
+
+	//  Ties go to the earliest run.
+	let bits = bits![1, 1, 0, 0, 1, 1, 0, 0];
+	assert_eq!(bits.longest_run(true), Some(0 .. 2));
+	assert_eq!(bits.longest_run(false), Some(2 .. 4));
+
+	let bits = bits![u16, Lsb0; 1; 200];
+	assert!(bits.iter_runs().eq([(true, 0 .. 200)]));
+	assert!(bits.iter_zero_runs().next().is_none());
+	assert_eq!(bits.longest_run(true), Some(0 .. 200));
+	assert!(bits.longest_run(false).is_none());
+}
+
+#[test]
+fn element_boundaries() {
+	let mut data = [0u64; 4];
+	let bits = data.view_bits_mut::<Msb0>();
+	bits[10 .. 150].fill(true);
+	bits.set(151, true);
+	assert_eq!(bits.iter_runs().collect::<Vec<_>>(), [
+		(false, 0 .. 10),
+		(true, 10 .. 150),
+		(false, 150 .. 151),
+		(true, 151 .. 152),
+		(false, 152 .. 256),
+	]);
+	assert_eq!(bits.longest_run(false), Some(152 .. 256));
+	assert_eq!(BitVec::<u8, Lsb0>::from_runs(bits.iter_runs()), bits);
+
+	let bits = &bits[64 .. 192];
+	assert!(bits.iter_runs().rev().eq([
+		(false, 88 .. 128),
+		(true, 87 .. 88),
+		(false, 86 .. 87),
+		(true, 0 .. 86),
+	]));
+	assert_eq!(bits.longest_run(true), Some(0 .. 86));
+	assert_eq!(BitVec::<u16, Msb0>::from_runs(bits.iter_runs()), bits);
+
+	//  `HiLo` places index `n` at position `n ^ 4`.
+	let data = [0x0Fu8; 2];
+	let bits = data.view_bits::<HiLo>();
+	assert!(bits.iter_one_runs().eq([4 .. 8, 12 .. 16]));
+	assert!(bits.iter_zero_runs().eq([0 .. 4, 8 .. 12]));
+
+	let cells = [Cell::new(0x00F0u16)];
+	let bits = cells.view_bits::<Lsb0>();
+	assert!(bits.iter_runs().eq([
+		(false, 0 .. 4),
+		(true, 4 .. 8),
+		(false, 8 .. 16),
+	]));
+}
+
+#[test]
+fn interleaved() {
+	let bits = bits![0, 0, 1, 0, 1, 1, 1, 0, 0, 1];
+	let mut runs = bits.iter_runs();
+	assert_eq!(runs.next_back(), Some((true, 9 .. 10)));
+	assert_eq!(runs.next(), Some((false, 0 .. 2)));
+	assert_eq!(runs.next_back(), Some((false, 7 .. 9)));
+	//  The iterators are `Copy`, so counting a copy leaves `runs` in place.
+	let copy = runs;
+	assert_eq!(copy.count(), 3);
+	assert_eq!(runs.next(), Some((true, 2 .. 3)));
+	assert_eq!(runs.last(), Some((true, 4 .. 7)));
+
+	let mut ones = bits.iter_one_runs();
+	assert_eq!(ones.next_back(), Some(9 .. 10));
+	assert_eq!(ones.next(), Some(2 .. 3));
+	assert_eq!(ones.next_back(), Some(4 .. 7));
+	assert!(ones.next().is_none());
+	assert!(ones.next_back().is_none());
+
+	assert!(bits![].iter_runs().next().is_none());
+	assert!(bits![].longest_run(true).is_none());
+}
+
+#[test]
+fn from_runs() {
+	let bv = BitVec::<u16, Lsb0>::from_runs([
+		(true, 2 .. 5),
+		(true, 20 .. 21),
+		(false, 3 .. 4),
+	]);
+	assert_eq!(bv.len(), 21);
+	assert_eq!(bv.iter_ones().collect::<Vec<_>>(), [2, 4, 20]);
+	assert!(BitVec::<u8, Msb0>::from_runs(None).is_empty());
+}
@@ -0,0 +1,109 @@
+use core::cell::Cell;
+
+use crate::{
+	order::HiLo,
+	prelude::*,
+};
+
+#[test]
+fn short_needles() {
+	let hay = bits![u8, Msb0; 0, 1, 1, 0, 1, 1, 0, 1, 1, 0];
+
+	let needle = bits![u16, Lsb0; 1, 1, 0];
+	assert_eq!(hay.find(needle), Some(1));
+	assert_eq!(hay.rfind(needle), Some(7));
+	assert!(hay.match_indices(needle).eq([1, 4, 7]));
+
+	let needle = bits![1, 1, 0, 1, 1];
+	assert_eq!(hay.find(needle), Some(1));
+	assert_eq!(hay.rfind(needle), Some(4));
+	assert!(hay.match_indices(needle).eq([1]));
+	assert!(hay.match_indices_overlapping(needle).eq([1, 4]));
+
+	assert!(hay.find(bits![0, 0]).is_none());
+	assert!(hay.rfind(bits![0, 0]).is_none());
+	assert!(hay.match_indices(bits![0, 0]).next().is_none());
+	assert!(bits![0; 3].find(bits![0; 4]).is_none());
+}
+
+#[test]
+fn wide_needles() {
+	//  A 70-bit needle, planted twice across element boundaries in a haystack
+	//  of a different storage type and ordering.
+	let pattern = [0xDEAD_BEEF_0BAD_F00Du64, 0xA5 << 56];
+	let needle = &pattern.view_bits::<Msb0>()[.. 70];
+
+	let mut data = [0u32; 8];
+	let hay = data.view_bits_mut::<Lsb0>();
+	hay[29 .. 99].clone_from_bitslice(needle);
+	hay[150 .. 220].clone_from_bitslice(needle);
+
+	assert_eq!(hay.find(needle), Some(29));
+	assert_eq!(hay.rfind(needle), Some(150));
+	assert!(hay.match_indices(needle).eq([29, 150]));
+	assert_eq!(hay.find(&needle[.. 69]), Some(29));
+	assert_eq!(hay.rfind(&needle[1 ..]), Some(151));
+
+	hay.set(160, !hay[160]);
+	assert_eq!(hay.rfind(needle), Some(29));
+}
+
+#[test]
+fn periodic_needles() {
+	//  A run of zeros matches everywhere it fits around the lone `1` bit.
+	let mut data = [0u16; 7];
+	let hay = &mut data.view_bits_mut::<Lsb0>()[.. 100];
+	hay.set(50, true);
+	let zeros = bits![0; 40];
+	assert_eq!(hay.find(zeros), Some(0));
+	assert_eq!(hay.rfind(zeros), Some(60));
+	assert!(hay.match_indices(zeros).eq([0, 51]));
+	assert!(
+		hay.match_indices_overlapping(zeros)
+			.eq((0 ..= 10).chain(51 ..= 60))
+	);
+
+	//  A needle longer than its period matches only at multiples of it.
+	let data = [0x5Au8; 8];
+	let hay = data.view_bits::<Lsb0>();
+	let needle = &hay[3 .. 20];
+	assert_eq!(hay.find(needle), Some(3));
+	assert_eq!(hay.rfind(needle), Some(43));
+	assert!(hay.match_indices(needle).eq([3, 27]));
+	assert!(
+		hay.match_indices_overlapping(needle)
+			.eq([3, 11, 19, 27, 35, 43])
+	);
+
+	let hay = data.view_bits::<HiLo>();
+	assert_eq!(hay.find(&hay[5 .. 30]), Some(5));
+	assert_eq!(hay.rfind(&hay[5 .. 30]), Some(37));
+}
+
+#[test]
+fn aliased_haystacks() {
+	let cells = [Cell::new(0x0Fu8), Cell::new(0xF0)];
+	let hay = cells.view_bits::<Msb0>();
+	assert_eq!(hay.find(bits![1; 8]), Some(4));
+	assert_eq!(hay.rfind(bits![0, 1]), Some(3));
+	assert_eq!(hay.rfind(bits![1, 0]), Some(11));
+
+	let mut data = [0x00FFu16, 0xFF00];
+	let (left, right) = data.view_bits_mut::<Lsb0>().split_at_mut(16);
+	assert!(left.find(&right[4 .. 12]).is_none());
+	assert_eq!(left.find(&right[.. 8]), Some(8));
+	assert_eq!(right.find(&left[.. 4]), Some(8));
+	assert_eq!(right.rfind(&left[.. 4]), Some(12));
+}
+
+#[test]
+fn empty_needle() {
+	let bits = bits![0, 1, 1];
+	let empty = bits![];
+	assert_eq!(bits.find(empty), Some(0));
+	assert_eq!(bits.rfind(empty), Some(3));
+	assert!(bits.match_indices(empty).eq([0, 1, 2, 3]));
+	assert_eq!(empty.find(empty), Some(0));
+	assert!(empty.find(bits).is_none());
+	assert!(empty.rfind(bits).is_none());
+}
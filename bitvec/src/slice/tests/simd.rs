@@ -0,0 +1,139 @@
+//! These tests run the same comparisons with and without the `simd` feature,
+//! so that the vector kernels and the element-wise code they replace are both
+//! held to the bit-by-bit behavior.
+
+use rand::random;
+
+use crate::{
+	mem::{
+		bits_of,
+		BitRegister,
+	},
+	prelude::*,
+};
+
+/// Produces a set of sub-slice ranges that start and end on, and off, element
+/// edges.
+fn ranges<T>(len: usize) -> impl Iterator<Item = (usize, usize)>
+where T: BitRegister {
+	let width = bits_of::<T>();
+	[0, 1, 7, width - 1, width, width + 3]
+		.into_iter()
+		.flat_map(move |start| {
+			[0, 1, 5, width + 1]
+				.into_iter()
+				.map(move |cut| (start, len - cut))
+		})
+}
+
+fn check<T, O>()
+where
+	T: BitRegister + BitStore,
+	O: BitOrder,
+{
+	let zero = <T as BitStore>::ZERO;
+	let mut dense = [zero; 160];
+	let mut sparse = [zero; 160];
+	let len = dense.view_bits::<O>().len();
+	for idx in 0 .. len {
+		dense.view_bits_mut::<O>().set(idx, random());
+	}
+	for idx in [3, len / 3, len - 70, len - 2] {
+		sparse.view_bits_mut::<O>().set(idx, true);
+	}
+
+	for (start, end) in ranges::<T>(len) {
+		for data in [&dense, &sparse, &[zero; 160]] {
+			let bits = &data.view_bits::<O>()[start .. end];
+			assert_eq!(
+				bits.count_ones(),
+				bits.iter().by_vals().filter(|&bit| bit).count(),
+			);
+			assert_eq!(bits.first_one(), bits.iter().by_vals().position(|b| b));
+			assert_eq!(bits.last_one(), bits.iter().by_vals().rposition(|b| b));
+			assert!(bits.iter_ones().eq(bits
+				.iter()
+				.by_vals()
+				.enumerate()
+				.filter_map(|(idx, bit)| if bit { Some(idx) } else { None })));
+
+			let mut other = *data;
+			let twin = &mut other.view_bits_mut::<O>()[start .. end];
+			assert_eq!(bits, twin);
+			for flip in [0, twin.len() / 2, twin.len() - 1] {
+				let bit = twin[flip];
+				twin.set(flip, !bit);
+				assert_ne!(bits, twin);
+				twin.set(flip, bit);
+			}
+		}
+
+		let lhs = &dense.view_bits::<O>()[start .. end];
+		let rhs = &sparse.view_bits::<O>()[start .. end];
+		let mut and = dense;
+		let mut or = dense;
+		let mut xor = dense;
+		and.view_bits_mut::<O>()[start .. end] &= rhs;
+		or.view_bits_mut::<O>()[start .. end] |= rhs;
+		xor.view_bits_mut::<O>()[start .. end] ^= rhs;
+		for (idx, (l, r)) in
+			lhs.iter().by_vals().zip(rhs.iter().by_vals()).enumerate()
+		{
+			assert_eq!(and.view_bits::<O>()[start + idx], l & r);
+			assert_eq!(or.view_bits::<O>()[start + idx], l | r);
+			assert_eq!(xor.view_bits::<O>()[start + idx], l ^ r);
+		}
+		//  Bits outside the operated range are untouched.
+		assert_eq!(
+			and.view_bits::<O>()[.. start],
+			dense.view_bits::<O>()[.. start]
+		);
+		assert_eq!(or.view_bits::<O>()[end ..], dense.view_bits::<O>()[end ..]);
+
+		//  A shorter right-hand operand is zero-extended.
+		let short = &sparse.view_bits::<O>()[start .. end - 40];
+		let mut and = dense;
+		and.view_bits_mut::<O>()[start .. end] &= short;
+		assert!(and.view_bits::<O>()[end - 40 .. end].not_any());
+	}
+}
+
+#[test]
+fn simd_agreement() {
+	check::<u8, Lsb0>();
+	check::<u8, Msb0>();
+	check::<u16, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u32, Lsb0>();
+	check::<u32, Msb0>();
+	check::<usize, Lsb0>();
+	check::<usize, Msb0>();
+
+	#[cfg(target_pointer_width = "64")]
+	{
+		check::<u64, Lsb0>();
+		check::<u64, Msb0>();
+	}
+}
+
+#[test]
+fn simd_mismatched_heads() {
+	let mut data = [0u32; 64];
+	for elem in data.iter_mut() {
+		*elem = random();
+	}
+	let bits = data.view_bits::<Lsb0>();
+	let (a, b) = (&bits[3 .. 1003], &bits[35 .. 1035]);
+	assert_eq!(a == b, a.iter().by_vals().eq(b.iter().by_vals()));
+	let (a, b) = (&bits[3 .. 1003], &bits[4 .. 1004]);
+	assert_eq!(a == b, a.iter().by_vals().eq(b.iter().by_vals()));
+
+	let mut copy = data;
+	copy.view_bits_mut::<Lsb0>()[3 .. 1003] ^= &bits[9 .. 1009];
+	for idx in 0 .. 1000 {
+		assert_eq!(
+			copy.view_bits::<Lsb0>()[3 + idx],
+			bits[3 + idx] ^ bits[9 + idx],
+		);
+	}
+}
@@ -346,4 +346,165 @@ mod format {
 ]"
 		);
 	}
+
+	#[test]
+	fn parse() {
+		use crate::slice::ParseBitsError;
+
+		let data = [0u8, 0x0F, !0, 0xA5];
+		let bits = data.view_bits::<Msb0>();
+		let bv = bits.to_bitvec();
+
+		for text in [
+			format!("{:?}", bv),
+			format!("{:#?}", bv),
+			format!("{}", bv),
+			format!("{:b}", bits),
+			format!("{:#b}", bits),
+			format!("{:#X}", bits),
+			format!("{:#o}", bits),
+		] {
+			assert_eq!(
+				text.parse::<BitVec<u8, Msb0>>().unwrap(),
+				bits,
+				"{}",
+				text
+			);
+			assert_eq!(
+				text.parse::<BitBox<u8, Msb0>>().unwrap(),
+				bits,
+				"{}",
+				text
+			);
+			assert_eq!(
+				text.parse::<BitArray<[u8; 4], Msb0>>().unwrap(),
+				bits,
+				"{}",
+				text,
+			);
+		}
+		assert_eq!(
+			format!("{:b}", &bits[4 .. 20])
+				.parse::<BitVec<u8, Msb0>>()
+				.unwrap(),
+			bits[4 .. 20],
+		);
+
+		assert_eq!(
+			BitVec::<u8, Msb0>::parse_radix(&format!("{:x}", bits), 4).unwrap(),
+			bits,
+		);
+		assert_eq!(
+			BitVec::<u8, Msb0>::parse_radix(&format!("{:o}", bits), 3).unwrap(),
+			bits,
+		);
+		//  Items rendered from `u8` elements are not whole `u16` elements.
+		assert_eq!(
+			BitVec::<u16, Lsb0>::parse_radix(&format!("{:o}", bits), 3)
+				.unwrap_err(),
+			ParseBitsError::PartialElement { position: 1 },
+		);
+
+		//  Octal and hexadecimal renderings of partial elements either fail, or
+		//  are read back as whole elements.
+		let bv = bitvec![u8, Msb0; 1, 0, 1, 1, 1];
+		assert_eq!(format!("{:#x}", bv), "[\n    0x17,\n]");
+		assert_eq!(format!("{:#o}", bv), "[\n    0o27,\n]");
+		assert_eq!(
+			format!("{:#x}", bv).parse::<BitVec<u8, Msb0>>().unwrap(),
+			bits![0, 0, 0, 1, 0, 1, 1, 1],
+		);
+		assert_eq!(
+			format!("{:#o}", bv).parse::<BitVec<u8, Msb0>>().unwrap_err(),
+			ParseBitsError::PartialElement { position: 6 },
+		);
+		for (range, hex, oct) in [
+			(4 .. 20, Err(1), Err(1)),
+			(9 .. 15, Ok(8), Err(1)),
+			(0 .. 20, Err(9), Err(11)),
+			(3 .. 32, Ok(32), Err(1)),
+			(8 .. 16, Ok(8), Ok(8)),
+		] {
+			let part = &bits[range];
+			let text = format!("{:x}", part);
+			assert_eq!(
+				BitVec::<u8, Msb0>::parse_radix(&text, 4)
+					.map(|bv| bv.len())
+					.map_err(|err| err.position()),
+				hex,
+				"{}",
+				text,
+			);
+			let text = format!("{:o}", part);
+			assert_eq!(
+				BitVec::<u8, Msb0>::parse_radix(&text, 3)
+					.map(|bv| bv.len())
+					.map_err(|err| err.position()),
+				oct,
+				"{}",
+				text,
+			);
+		}
+		for bits in [&bits[8 .. 24], &bits[16 ..], bits] {
+			for text in [format!("{:#x}", bits), format!("{:#o}", bits)] {
+				assert_eq!(
+					text.parse::<BitVec<u8, Msb0>>().unwrap(),
+					bits,
+					"{}",
+					text,
+				);
+			}
+		}
+		assert_eq!(
+			BitVec::<u8, Lsb0>::parse_radix(" 0b_1_0 ", 1).unwrap(),
+			bits![1, 0],
+		);
+		assert_eq!(BitVec::<u8, Lsb0>::parse_radix("0b1", 4).unwrap(), bits![
+			0, 0, 0, 0, 1, 0, 1, 1, 0, 0, 0, 1
+		],);
+		assert!("".parse::<BitVec>().unwrap().is_empty());
+		assert!("[]".parse::<BitVec>().unwrap().is_empty());
+		assert_eq!(
+			"[1,0,]".parse::<BitArray<u8>>().unwrap(),
+			bitarr![u8, Lsb0; 1, 0, 0, 0, 0, 0, 0, 0],
+		);
+
+		assert_eq!(
+			"[1, 0".parse::<BitVec>().unwrap_err(),
+			ParseBitsError::UnexpectedEnd { position: 5 },
+		);
+		assert_eq!(
+			"BitVec<u8, Lsb0> { bits: 1".parse::<BitVec>().unwrap_err(),
+			ParseBitsError::UnexpectedEnd { position: 26 },
+		);
+		assert_eq!(
+			"0x".parse::<BitVec>().unwrap_err(),
+			ParseBitsError::UnexpectedEnd { position: 2 },
+		);
+		assert_eq!(
+			"[1, , 0]".parse::<BitVec>().unwrap_err(),
+			ParseBitsError::InvalidChar {
+				position: 4,
+				found:    ',',
+			},
+		);
+		assert_eq!(
+			"[1 0]".parse::<BitVec>().unwrap_err(),
+			ParseBitsError::InvalidChar {
+				position: 3,
+				found:    '0',
+			},
+		);
+		assert_eq!(
+			"[0o400]".parse::<BitVec<u8>>().unwrap_err(),
+			ParseBitsError::Overflow { position: 3 },
+		);
+		assert_eq!(
+			"0b111_111_111".parse::<BitArray<u8>>().unwrap_err(),
+			ParseBitsError::TooLong {
+				position: 12,
+				capacity: 8,
+			},
+		);
+	}
 }
@@ -0,0 +1,453 @@
+#![doc = include_str!("../doc/slot.md")]
+
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	marker::PhantomData,
+	ops::Range,
+	sync::atomic::Ordering,
+};
+
+use funty::Integral;
+use radium::Radium;
+
+use crate::{
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+mod tests;
+
+#[doc = include_str!("../doc/slot/BitAllocator.md")]
+pub struct BitAllocator<B, T, O = Lsb0>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem> + Sync,
+	O: BitOrder,
+{
+	/// The allocation bitmap. A `1` bit marks an allocated slot.
+	bits: B,
+	/// Marks the bit-slice type parameters.
+	_typ: PhantomData<fn(&BitSlice<T, O>)>,
+}
+
+#[doc = include_str!("../doc/slot/AllocHint.md")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AllocHint {
+	/// The index at which the next search begins.
+	next: usize,
+}
+
+/// Constructors and accessors.
+impl<B, T, O> BitAllocator<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem> + Sync,
+	O: BitOrder,
+{
+	/// Creates an allocator over a bitmap.
+	///
+	/// Bits that are already `1` are treated as allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU8;
+	///
+	/// let data = [AtomicU8::new(0b0000_0111), AtomicU8::new(0)];
+	/// let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(slots.allocated(), 3);
+	/// assert_eq!(slots.alloc(), Some(3));
+	/// ```
+	#[inline]
+	pub fn new(bits: B) -> Self {
+		Self {
+			bits,
+			_typ: PhantomData,
+		}
+	}
+
+	/// Views the allocation bitmap.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		self.bits.borrow()
+	}
+
+	/// Returns the allocation bitmap.
+	#[inline]
+	pub fn into_inner(self) -> B {
+		self.bits
+	}
+
+	/// Gets the number of slots that the allocator manages.
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		self.as_bitslice().len()
+	}
+
+	/// Counts the slots that are currently allocated.
+	///
+	/// The count is not a snapshot of one moment, as other threads may
+	/// allocate and free slots while it is taken.
+	#[inline]
+	pub fn allocated(&self) -> usize {
+		self.as_bitslice().count_ones()
+	}
+
+	/// Tests whether a slot is currently allocated.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	#[inline]
+	pub fn is_allocated(&self, index: usize) -> bool {
+		self.as_bitslice()[index]
+	}
+}
+
+/// Allocation.
+impl<B, T, O> BitAllocator<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem> + Sync,
+	O: BitOrder,
+{
+	/// Allocates the lowest free slot.
+	///
+	/// ## Returns
+	///
+	/// The index of the claimed slot, or `None` if every slot is allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU16;
+	///
+	/// let data = AtomicU16::new(0);
+	/// let slots = BitAllocator::new(&data.view_bits::<Lsb0>()[.. 2]);
+	/// assert_eq!(slots.alloc(), Some(0));
+	/// assert_eq!(slots.alloc(), Some(1));
+	/// assert_eq!(slots.alloc(), None);
+	/// ```
+	#[inline]
+	pub fn alloc(&self) -> Option<usize> {
+		self.alloc_near(0)
+	}
+
+	/// Allocates the first free slot at or after an index.
+	///
+	/// The search wraps around to the start of the bitmap, so this only fails
+	/// if every slot is allocated. If `index` is out of bounds, the search
+	/// begins at `0`.
+	///
+	/// ## Returns
+	///
+	/// The index of the claimed slot, or `None` if every slot is allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU32;
+	///
+	/// let data = AtomicU32::new(0);
+	/// let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(slots.alloc_near(30), Some(30));
+	/// assert_eq!(slots.alloc_near(30), Some(31));
+	/// assert_eq!(slots.alloc_near(30), Some(0));
+	/// ```
+	#[inline]
+	pub fn alloc_near(&self, index: usize) -> Option<usize> {
+		let start = self.search_start(index);
+		self.claim_first(start, self.capacity())
+			.or_else(|| self.claim_first(0, start))
+	}
+
+	/// Allocates a free slot, starting the search where the hint’s previous
+	/// search left off.
+	///
+	/// This is [`.alloc_near()`] with the search beginning at the hint. When
+	/// a slot is claimed, the hint is moved past it. Threads that keep their
+	/// own hint spread their searches across the bitmap, rather than all
+	/// contending for the lowest free slot.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::{AllocHint, BitAllocator};
+	/// use core::sync::atomic::AtomicU64;
+	///
+	/// let data = AtomicU64::new(0);
+	/// let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	/// let mut hint = AllocHint::at(10);
+	/// assert_eq!(slots.alloc_hinted(&mut hint), Some(10));
+	/// assert_eq!(slots.alloc_hinted(&mut hint), Some(11));
+	/// assert_eq!(hint.next(), 12);
+	/// ```
+	///
+	/// [`.alloc_near()`]: Self::alloc_near
+	#[inline]
+	pub fn alloc_hinted(&self, hint: &mut AllocHint) -> Option<usize> {
+		let index = self.alloc_near(hint.next)?;
+		hint.next = index + 1;
+		Some(index)
+	}
+
+	/// Allocates the lowest run of `count` contiguous free slots.
+	///
+	/// The run is claimed atomically: either every slot in it is allocated to
+	/// the caller, or none of them are. A `count` of zero produces an empty
+	/// range and claims nothing.
+	///
+	/// ## Returns
+	///
+	/// The indices of the claimed slots, or `None` if there is no run of free
+	/// slots long enough.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU8;
+	///
+	/// let data = [AtomicU8::new(0b0001_0000), AtomicU8::new(0)];
+	/// let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	/// assert_eq!(slots.alloc_run(6), Some(5 .. 11));
+	/// assert_eq!(slots.alloc_run(4), Some(0 .. 4));
+	/// assert_eq!(slots.alloc_run(6), None);
+	/// ```
+	#[inline]
+	pub fn alloc_run(&self, count: usize) -> Option<Range<usize>> {
+		self.alloc_run_near(0, count)
+	}
+
+	/// Allocates the first run of `count` contiguous free slots that begins
+	/// at or after an index.
+	///
+	/// As with [`.alloc_near()`], the search wraps around to the start of the
+	/// bitmap, but runs do not: a run is never split across the end and the
+	/// start of the bitmap.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU16;
+	///
+	/// let data = AtomicU16::new(0);
+	/// let slots = BitAllocator::new(data.view_bits::<Msb0>());
+	/// assert_eq!(slots.alloc_run_near(4, 8), Some(4 .. 12));
+	/// assert_eq!(slots.alloc_run_near(12, 4), Some(12 .. 16));
+	/// assert_eq!(slots.alloc_run_near(12, 4), Some(0 .. 4));
+	/// ```
+	///
+	/// [`.alloc_near()`]: Self::alloc_near
+	#[inline]
+	pub fn alloc_run_near(
+		&self,
+		index: usize,
+		count: usize,
+	) -> Option<Range<usize>> {
+		let start = self.search_start(index);
+		if count == 0 {
+			return Some(start .. start);
+		}
+		self.claim_first_run(start, self.capacity(), count)
+			.or_else(|| self.claim_first_run(0, start, count))
+	}
+
+	/// Allocates a run of `count` contiguous free slots, starting the search
+	/// where the hint’s previous search left off.
+	///
+	/// See [`.alloc_hinted()`] and [`.alloc_run_near()`].
+	///
+	/// [`.alloc_hinted()`]: Self::alloc_hinted
+	/// [`.alloc_run_near()`]: Self::alloc_run_near
+	#[inline]
+	pub fn alloc_run_hinted(
+		&self,
+		hint: &mut AllocHint,
+		count: usize,
+	) -> Option<Range<usize>> {
+		let run = self.alloc_run_near(hint.next, count)?;
+		hint.next = run.end;
+		Some(run)
+	}
+
+	/// Frees an allocated slot.
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds, or if the slot is not
+	/// allocated.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use bitvec::slot::BitAllocator;
+	/// use core::sync::atomic::AtomicU8;
+	///
+	/// let data = AtomicU8::new(0);
+	/// let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	/// let slot = slots.alloc().unwrap();
+	/// slots.free(slot);
+	/// assert!(!slots.is_allocated(slot));
+	/// ```
+	#[inline]
+	pub fn free(&self, index: usize) {
+		assert!(
+			self.as_bitslice().fetch_clear(index, Ordering::Release),
+			"slot {} is not allocated",
+			index,
+		);
+	}
+
+	/// Frees a run of allocated slots.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` is out of bounds, or if any slot in it is not
+	/// allocated. No slots are freed when it panics.
+	#[inline]
+	pub fn free_run(&self, range: Range<usize>) {
+		let bits = &self.as_bitslice()[range.clone()];
+		assert!(
+			bits.all(),
+			"{} slots in {:?} are not allocated",
+			bits.count_zeros(),
+			range,
+		);
+		bits.fetch_clear_range(.., Ordering::Release);
+	}
+
+	/// Clamps a caller’s starting index to the bitmap.
+	fn search_start(&self, index: usize) -> usize {
+		if index < self.capacity() {
+			index
+		}
+		else {
+			0
+		}
+	}
+
+	/// Claims the first free slot in `from .. to`.
+	fn claim_first(&self, mut from: usize, to: usize) -> Option<usize> {
+		let bits = self.as_bitslice();
+		while from < to {
+			let index = from + bits[from .. to].first_zero()?;
+			//  Another thread may have claimed the slot since it was seen.
+			if !bits.fetch_set(index, Ordering::Acquire) {
+				return Some(index);
+			}
+			from = index + 1;
+		}
+		None
+	}
+
+	/// Claims the first run of `count` free slots that begins in `from .. to`.
+	fn claim_first_run(
+		&self,
+		mut from: usize,
+		to: usize,
+		count: usize,
+	) -> Option<Range<usize>> {
+		let bits = self.as_bitslice();
+		while from < to {
+			let start = from + bits[from .. to].first_zero()?;
+			let end =
+				start.checked_add(count).filter(|&end| end <= bits.len())?;
+			match bits[start .. end].last_one() {
+				| Some(taken) => from = start + taken + 1,
+				| None if self.try_claim(start .. end) => {
+					return Some(start .. end)
+				},
+				| None => from = start + 1,
+			}
+		}
+		None
+	}
+
+	/// Attempts to claim every slot in a range.
+	///
+	/// Each element is claimed with a compare-exchange that requires all of
+	/// the range’s bits in it to be free. If any element fails, the elements
+	/// that were already claimed are released and the claim fails.
+	fn try_claim(&self, range: Range<usize>) -> bool {
+		let bits = &self.as_bitslice()[range];
+		let zero = <T::Mem as Integral>::ZERO;
+		for (claimed, (elem, mask)) in bits.masked_elements().enumerate() {
+			let claim =
+				elem.fetch_update(Ordering::Acquire, Ordering::Relaxed, |val| {
+					if val & mask == zero {
+						Some(val | mask)
+					}
+					else {
+						None
+					}
+				});
+			if claim.is_err() {
+				for (elem, mask) in bits.masked_elements().take(claimed) {
+					elem.fetch_and(!mask, Ordering::Relaxed);
+				}
+				return false;
+			}
+		}
+		true
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<B, T, O> Debug for BitAllocator<B, T, O>
+where
+	B: Borrow<BitSlice<T, O>>,
+	T: BitStore + Radium<Item = <T as BitStore>::Mem> + Sync,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("BitAllocator")
+			.field("capacity", &self.capacity())
+			.field("allocated", &self.allocated())
+			.finish()
+	}
+}
+
+impl AllocHint {
+	/// Creates a hint that begins searching at the start of the bitmap.
+	#[inline]
+	pub const fn new() -> Self {
+		Self::at(0)
+	}
+
+	/// Creates a hint that begins searching at an index.
+	///
+	/// Threads can be given hints that are spread across the bitmap so that
+	/// they do not all begin searching at the same place.
+	#[inline]
+	pub const fn at(index: usize) -> Self {
+		Self { next: index }
+	}
+
+	/// Gets the index at which the next hinted search begins.
+	#[inline]
+	pub const fn next(&self) -> usize {
+		self.next
+	}
+}
@@ -0,0 +1,153 @@
+//! Unit tests for the slot allocator.
+
+#![cfg(test)]
+
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicU64;
+use core::sync::atomic::{
+	AtomicU16,
+	AtomicU8,
+	Ordering,
+};
+#[cfg(feature = "std")]
+use std::thread;
+
+use super::{
+	AllocHint,
+	BitAllocator,
+};
+use crate::prelude::*;
+
+#[test]
+fn single() {
+	let data = [AtomicU8::new(0b1010_0101), AtomicU8::new(0)];
+	let slots = BitAllocator::new(&data.view_bits::<Msb0>()[.. 12]);
+	assert_eq!(slots.capacity(), 12);
+	assert_eq!(slots.allocated(), 4);
+
+	assert_eq!(slots.alloc(), Some(1));
+	assert_eq!(slots.alloc_near(5), Some(6));
+	assert_eq!(slots.alloc_near(100), Some(3));
+	assert_eq!(slots.alloc_near(11), Some(11));
+	assert_eq!(slots.alloc_near(11), Some(4));
+	for idx in 8 .. 11 {
+		assert_eq!(slots.alloc(), Some(idx));
+	}
+	assert!(slots.alloc().is_none());
+	assert!(slots.alloc_near(6).is_none());
+	assert_eq!(data[1].load(Ordering::Relaxed), 0xF0);
+
+	slots.free(6);
+	assert!(!slots.is_allocated(6));
+	let mut hint = AllocHint::new();
+	assert_eq!(slots.alloc_hinted(&mut hint), Some(6));
+	assert_eq!(hint.next(), 7);
+	assert!(slots.alloc_hinted(&mut hint).is_none());
+	assert_eq!(hint.next(), 7);
+
+	let empty = BitAllocator::new(BitSlice::<AtomicU8, Lsb0>::empty());
+	assert!(empty.alloc().is_none());
+	assert_eq!(empty.alloc_run(0), Some(0 .. 0));
+}
+
+#[test]
+#[should_panic = "slot 3 is not allocated"]
+fn double_free() {
+	let data = AtomicU16::new(0);
+	let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	slots.free(3);
+}
+
+#[test]
+fn runs() {
+	let data = [AtomicU16::new(0), AtomicU16::new(0), AtomicU16::new(0)];
+	let bits = data.view_bits::<Lsb0>();
+	bits.fetch_set(5, Ordering::Relaxed);
+	bits.fetch_set(30, Ordering::Relaxed);
+	let slots = BitAllocator::new(bits);
+
+	assert_eq!(slots.alloc_run(5), Some(0 .. 5));
+	//  Runs may cross element boundaries.
+	assert_eq!(slots.alloc_run(20), Some(6 .. 26));
+	assert_eq!(slots.alloc_run(4), Some(26 .. 30));
+	assert_eq!(slots.alloc_run(20), None);
+	assert_eq!(slots.alloc_run_near(40, 10), Some(31 .. 41));
+	let mut hint = AllocHint::at(45);
+	assert_eq!(slots.alloc_run_hinted(&mut hint, 3), Some(45 .. 48));
+	assert_eq!(hint.next(), 48);
+	//  The hint is past the end, so the search wraps to the start.
+	assert!(slots.alloc_run_hinted(&mut hint, 5).is_none());
+	assert_eq!(hint.next(), 48);
+	assert_eq!(slots.alloc_run_hinted(&mut hint, 4), Some(41 .. 45));
+	assert_eq!(hint.next(), 45);
+	assert!(slots.as_bitslice().all());
+
+	slots.free_run(41 .. 45);
+	assert_eq!(slots.allocated(), 44);
+
+	slots.free_run(6 .. 26);
+	assert_eq!(slots.allocated(), 24);
+	assert_eq!(slots.alloc_run_near(40, 15), Some(6 .. 21));
+}
+
+#[test]
+#[should_panic = "3 slots in 3..8 are not allocated"]
+fn run_double_free() {
+	let data = AtomicU8::new(0b0001_1100);
+	let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	slots.free_run(3 .. 8);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn run_double_free_leaves_bitmap() {
+	use std::panic::{
+		self,
+		AssertUnwindSafe,
+	};
+
+	let data = AtomicU8::new(0b0001_1100);
+	let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+	let freed = AssertUnwindSafe(|| slots.free_run(3 .. 8));
+	assert!(panic::catch_unwind(freed).is_err());
+	assert_eq!(data.load(Ordering::Relaxed), 0b0001_1100);
+	assert_eq!(slots.allocated(), 3);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn contended() {
+	const THREADS: usize = 8;
+	const ROUNDS: usize = 200;
+	let data = [(); 8].map(|()| AtomicU64::new(0));
+	let slots = BitAllocator::new(data.view_bits::<Lsb0>());
+
+	//  Every thread repeatedly claims single slots and runs, checks that no
+	//  other thread holds them, and frees them.
+	let owners = [(); 512].map(|()| AtomicU8::new(0));
+	thread::scope(|scope| {
+		for id in 1 ..= THREADS as u8 {
+			let (slots, owners) = (&slots, &owners);
+			scope.spawn(move || {
+				let mut hint = AllocHint::at(id as usize * 64);
+				for round in 0 .. ROUNDS {
+					let run = if round % 2 == 0 {
+						let idx = slots.alloc_hinted(&mut hint).unwrap();
+						idx .. idx + 1
+					}
+					else {
+						slots.alloc_run(round % 13 + 1).unwrap()
+					};
+					for idx in run.clone() {
+						assert_eq!(owners[idx].swap(id, Ordering::Relaxed), 0);
+					}
+					for idx in run.clone() {
+						owners[idx].store(0, Ordering::Relaxed);
+					}
+					slots.free_run(run);
+				}
+			});
+		}
+	});
+	assert_eq!(slots.allocated(), 0);
+}
@@ -0,0 +1,307 @@
+#![doc = include_str!("../doc/strategy.md")]
+#![cfg(feature = "proptest")]
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+
+use proptest::{
+	arbitrary::{
+		any,
+		Arbitrary,
+	},
+	collection::{
+		vec,
+		SizeRange,
+	},
+	strategy::{
+		BoxedStrategy,
+		Strategy,
+	},
+};
+
+use crate::{
+	array::BitArray,
+	boxed::BitBox,
+	mem::bits_of,
+	order::BitOrder,
+	slice::BitSlice,
+	store::BitStore,
+	vec::BitVec,
+	view::BitViewSized,
+};
+
+mod tests;
+
+/// Produces bit-vectors whose lengths are drawn from `len`, and whose first
+/// live bit may be at any position in the first element.
+///
+/// The bit-vectors are built by [`OffsetBitSlice::into_bitvec`], so they keep
+/// the head offset of the generated bit-slice. Their dead bits are cleared.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::strategy::bitvec_strategy;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(bv in bitvec_strategy::<u16, Msb0>(0 .. 100))| {
+///   prop_assert!(bv.len() < 100);
+///   prop_assert_eq!(bv.count_ones(), bv.iter().by_vals().filter(|b| *b).count());
+/// });
+/// ```
+#[inline]
+pub fn bitvec_strategy<T, O>(
+	len: impl Into<SizeRange>,
+) -> impl Strategy<Value = BitVec<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	bitslice_with_offsets(len).prop_map(OffsetBitSlice::into_bitvec)
+}
+
+/// Produces bit-slices whose lengths are drawn from `len`, viewed over random
+/// storage with random head and tail offsets.
+///
+/// The head offset is any bit of the first element, and the storage extends
+/// exactly to the end of the element holding the last live bit. The dead bits
+/// on either side of the view are randomized, so that code which reads them by
+/// mistake is likely to produce wrong answers.
+///
+/// Shrinking moves the head towards the start of the first element, shortens
+/// the view, and clears bits.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+/// use bitvec::strategy::bitslice_with_offsets;
+/// use core::cell::Cell;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(mut bits in bitslice_with_offsets::<Cell<u8>, Lsb0>(1 ..= 20))| {
+///   prop_assert!(bits.head() < 8);
+///   let dead = bits.storage().count_ones() - bits.count_ones();
+///   bits.fill(true);
+///   prop_assert_eq!(bits.storage().count_ones(), dead + bits.len());
+/// });
+/// ```
+#[inline]
+pub fn bitslice_with_offsets<T, O>(
+	len: impl Into<SizeRange>,
+) -> impl Strategy<Value = OffsetBitSlice<T, O>>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let width = bits_of::<T::Mem>();
+	(
+		0 .. width,
+		vec(any::<bool>(), len),
+		vec(any::<bool>(), 2 * (width - 1)),
+	)
+		.prop_map(|(head, live, dead)| OffsetBitSlice::new(head, &live, &dead))
+}
+
+/// A bit-slice viewed over a larger, randomly-filled buffer.
+///
+/// This is produced by [`bitslice_with_offsets`]. It dereferences to the
+/// bit-slice being tested; the surrounding storage is available through
+/// [`.storage()`], and the offset of the view within it through [`.head()`].
+///
+/// [`.head()`]: Self::head
+/// [`.storage()`]: Self::storage
+pub struct OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// The backing buffer, covering whole elements.
+	storage: BitVec<T, O>,
+	/// The index of the first live bit in `storage`.
+	head:    usize,
+	/// The number of live bits.
+	len:     usize,
+}
+
+impl<T, O> OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Assembles a view over storage made of `dead[.. head]`, then `live`,
+	/// then enough of the remaining `dead` bits to fill the last element.
+	fn new(head: usize, live: &[bool], dead: &[bool]) -> Self {
+		let width = bits_of::<T::Mem>();
+		let tail = (width - (head + live.len()) % width) % width;
+		let storage = dead[.. head]
+			.iter()
+			.chain(live)
+			.chain(&dead[head ..][.. tail])
+			.copied()
+			.collect();
+		Self {
+			storage,
+			head,
+			len: live.len(),
+		}
+	}
+
+	/// Gets the index of the first live bit within the first element.
+	#[inline]
+	pub fn head(&self) -> usize {
+		self.head
+	}
+
+	/// Views the entire backing buffer, including the dead bits on either
+	/// side of the bit-slice.
+	#[inline]
+	pub fn storage(&self) -> &BitSlice<T, O> {
+		self.storage.as_bitslice()
+	}
+
+	/// Views the bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<T, O> {
+		&self.storage[self.head ..][.. self.len]
+	}
+
+	/// Views the bit-slice mutably. The surrounding dead bits are not
+	/// reachable through it.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<T, O> {
+		&mut self.storage[self.head ..][.. self.len]
+	}
+
+	/// Copies the bit-slice into a bit-vector, keeping its head offset.
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<T, O> {
+		BitVec::from_bitslice(self.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Clone for OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		Self {
+			storage: self.storage.clone(),
+			head:    self.head,
+			len:     self.len,
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Debug for OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("OffsetBitSlice")
+			.field("head", &self.head)
+			.field("bits", &self.as_bitslice())
+			.finish_non_exhaustive()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> Deref for OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Target = BitSlice<T, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O> DerefMut for OffsetBitSlice<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+/// `any::<BitArray<A, O>>()` fills the entire array with random bits.
+impl<A, O> Arbitrary for BitArray<A, O>
+where
+	A: 'static + BitViewSized,
+	O: 'static + BitOrder,
+{
+	type Parameters = ();
+	type Strategy = BoxedStrategy<Self>;
+
+	#[inline]
+	fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+		vec(any::<bool>(), bits_of::<A>())
+			.prop_map(|bits| {
+				let mut out = Self::ZERO;
+				for (idx, bit) in bits.into_iter().enumerate() {
+					out.set(idx, bit);
+				}
+				out
+			})
+			.boxed()
+	}
+}
+
+/// `any_with::<BitVec<T, O>>(len)` is [`bitvec_strategy(len)`].
+///
+/// [`bitvec_strategy(len)`]: bitvec_strategy
+impl<T, O> Arbitrary for BitVec<T, O>
+where
+	T: 'static + BitStore,
+	O: 'static + BitOrder,
+{
+	type Parameters = SizeRange;
+	type Strategy = BoxedStrategy<Self>;
+
+	#[inline]
+	fn arbitrary_with(len: Self::Parameters) -> Self::Strategy {
+		bitvec_strategy(len).boxed()
+	}
+}
+
+/// `any_with::<BitBox<T, O>>(len)` is [`bitvec_strategy(len)`], frozen.
+///
+/// [`bitvec_strategy(len)`]: bitvec_strategy
+impl<T, O> Arbitrary for BitBox<T, O>
+where
+	T: 'static + BitStore,
+	O: 'static + BitOrder,
+{
+	type Parameters = SizeRange;
+	type Strategy = BoxedStrategy<Self>;
+
+	#[inline]
+	fn arbitrary_with(len: Self::Parameters) -> Self::Strategy {
+		bitvec_strategy(len)
+			.prop_map(BitVec::into_boxed_bitslice)
+			.boxed()
+	}
+}
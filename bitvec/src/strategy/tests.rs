@@ -0,0 +1,101 @@
+//! Unit tests for the `proptest` strategies.
+
+#![cfg(test)]
+
+use core::{
+	cell::Cell,
+	sync::atomic::{
+		AtomicU32,
+		AtomicU8,
+	},
+};
+
+use proptest::{
+	prelude::*,
+	test_runner::TestRunner,
+};
+use radium::types::RadiumU16;
+
+use super::*;
+use crate::prelude::*;
+
+/// Checks that generated views sit at their recorded offsets, that their
+/// storage covers whole elements, and that bulk operations on them agree with
+/// bit-by-bit iteration and do not disturb the surrounding dead bits.
+fn check<T, O>()
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	let width = bits_of::<T::Mem>();
+	let heads = Cell::new(0u64);
+	TestRunner::deterministic()
+		.run(&bitslice_with_offsets::<T, O>(0 .. 200), |mut bits| {
+			heads.set(heads.get() | 1 << bits.head());
+			prop_assert_eq!(bits.storage().len() % width, 0);
+			prop_assert_eq!(
+				bits.as_bitptr().bit().into_inner() as usize,
+				bits.head()
+			);
+			prop_assert!(
+				bits.storage().len() < bits.head() + bits.len() + width
+			);
+
+			let ones = bits.iter().by_vals().filter(|&b| b).count();
+			prop_assert_eq!(bits.count_ones(), ones);
+			prop_assert_eq!(
+				bits.first_one(),
+				bits.iter().by_vals().position(|b| b),
+			);
+			prop_assert_eq!(
+				bits.last_zero(),
+				bits.iter().by_vals().rposition(|b| !b),
+			);
+
+			let dead = bits.storage().count_ones() - ones;
+			bits.fill(true);
+			prop_assert_eq!(bits.storage().count_ones(), dead + bits.len());
+
+			let bv = bits.into_bitvec();
+			prop_assert!(bv.all());
+			Ok(())
+		})
+		.unwrap();
+	//  Both aligned and misaligned heads were produced.
+	assert_ne!(heads.get() & 1, 0);
+	assert_ne!(heads.get() & !1, 0);
+
+	TestRunner::default()
+		.run(&bitvec_strategy::<T, O>(5 ..= 10), |bv| {
+			prop_assert!((5 ..= 10).contains(&bv.len()));
+			prop_assert!((bv.as_bitptr().bit().into_inner() as usize) < width);
+			Ok(())
+		})
+		.unwrap();
+}
+
+#[test]
+fn offsets() {
+	check::<u8, Lsb0>();
+	check::<u16, Msb0>();
+	check::<u32, Lsb0>();
+	check::<u64, Msb0>();
+	check::<usize, Lsb0>();
+	check::<Cell<u16>, Msb0>();
+	check::<AtomicU8, Lsb0>();
+	check::<AtomicU32, Msb0>();
+	check::<RadiumU16, Lsb0>();
+}
+
+proptest! {
+	#[test]
+	fn arbitrary_impls(
+		arr in any::<BitArray<[u16; 3], Msb0>>(),
+		bv in any_with::<BitVec<Cell<u8>, Lsb0>>((0 .. 30).into()),
+		bb in any_with::<BitBox<u32, Msb0>>((3 .. 4).into()),
+	) {
+		prop_assert_eq!(arr.len(), 48);
+		prop_assert!(bv.len() < 30);
+		prop_assert_eq!(bb.len(), 3);
+	}
+}
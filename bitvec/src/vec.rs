@@ -3,25 +3,40 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::vec;
-use alloc::vec::Vec;
+use alloc::{
+	collections::TryReserveError,
+	vec::Vec,
+};
 use core::{
 	mem::{
 		self,
 		ManuallyDrop,
 	},
+	ops::Range,
 	ptr,
 	slice,
 };
 
+use allocator_api2::{
+	alloc::{
+		Allocator,
+		Global,
+	},
+	boxed::Box as AllocBox,
+	vec::Vec as AllocVec,
+};
 use tap::Pipe;
 use wyz::comu::{
 	Const,
 	Mut,
 };
 
-pub use self::iter::{
-	Drain,
-	Splice,
+pub use self::{
+	iter::{
+		Drain,
+		Splice,
+	},
+	small::SmallBitVec,
 };
 pub use crate::boxed::IntoIter;
 use crate::{
@@ -38,7 +53,11 @@ use crate::{
 		BitSpan,
 		BitSpanError,
 	},
-	slice::BitSlice,
+	slice::{
+		parse_bits,
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	view::BitView,
 };
@@ -46,20 +65,24 @@ use crate::{
 mod api;
 mod iter;
 mod ops;
+pub mod small;
 mod tests;
 mod traits;
 
 #[repr(C)]
 #[doc = include_str!("../doc/vec/BitVec.md")]
-pub struct BitVec<T = usize, O = Lsb0>
+pub struct BitVec<T = usize, O = Lsb0, A = Global>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// Span description of the live bits in the allocation.
 	bitspan:  BitSpan<Mut, T, O>,
 	/// Allocation capacity, measured in `T` elements.
 	capacity: usize,
+	/// The allocator that owns the buffer.
+	alloc:    A,
 }
 
 /// Constructors.
@@ -72,6 +95,7 @@ where
 	pub const EMPTY: Self = Self {
 		bitspan:  BitSpan::EMPTY,
 		capacity: 0,
+		alloc:    Global,
 	};
 
 	/// Creates a new bit-vector by repeating a bit for the desired length.
@@ -96,6 +120,36 @@ where
 		out
 	}
 
+	/// Attempts to create a new bit-vector by repeating a bit for the desired
+	/// length.
+	///
+	/// This is the fallible counterpart to [`::repeat()`]: it fails, rather
+	/// than aborting, if the allocator cannot provide the memory, or if `len`
+	/// exceeds what a bit-vector can represent.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let ones = BitVec::<u16, Lsb0>::try_repeat(true, 50).unwrap();
+	/// assert_eq!(ones.count_ones(), 50);
+	/// assert!(BitVec::<u8, Msb0>::try_repeat(false, usize::MAX).is_err());
+	/// ```
+	///
+	/// [`::repeat()`]: Self::repeat
+	#[inline]
+	pub fn try_repeat(bit: bool, len: usize) -> Result<Self, TryReserveError> {
+		let mut out = Self::try_with_capacity(len)?;
+		unsafe {
+			out.set_len(len);
+			out.as_raw_mut_slice().fill_with(|| {
+				BitStore::new(if bit { !<T::Mem>::ZERO } else { <T::Mem>::ZERO })
+			});
+		}
+		Ok(out)
+	}
+
 	/// Copies the contents of a bit-slice into a new heap allocation.
 	///
 	/// This copies the raw underlying elements into a new allocation, and sets
@@ -119,12 +173,36 @@ where
 	/// [`.force_align()`]: Self::force_align
 	#[inline]
 	pub fn from_bitslice(slice: &BitSlice<T, O>) -> Self {
+		Self::from_bitslice_in(slice, Global)
+	}
+
+	/// Attempts to copy the contents of a bit-slice into a new heap
+	/// allocation.
+	///
+	/// This behaves exactly as [`::from_bitslice()`] does, except that it
+	/// fails, rather than aborting, if the allocator cannot provide the
+	/// memory.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let bv = BitVec::try_from_bitslice(bits).unwrap();
+	/// assert_eq!(bv, bits);
+	/// ```
+	///
+	/// [`::from_bitslice()`]: Self::from_bitslice
+	#[inline]
+	pub fn try_from_bitslice(
+		slice: &BitSlice<T, O>,
+	) -> Result<Self, TryReserveError> {
 		let bitspan = slice.as_bitspan();
 
-		let mut vec = bitspan
-			.elements()
-			.pipe(Vec::with_capacity)
-			.pipe(ManuallyDrop::new);
+		let mut vec = Vec::new();
+		vec.try_reserve_exact(bitspan.elements())?;
+		let mut vec = ManuallyDrop::new(vec);
 		vec.extend(slice.domain());
 
 		let bitspan = unsafe {
@@ -135,7 +213,11 @@ where
 			)
 		};
 		let capacity = vec.capacity();
-		Self { bitspan, capacity }
+		Ok(Self {
+			bitspan,
+			capacity,
+			alloc: Global,
+		})
 	}
 
 	/// Constructs a new bit-vector from a single element.
@@ -246,10 +328,209 @@ where
 
 		BitPtr::from_mut_slice(vec.as_mut_slice())
 			.span(vec.len() * bits_of::<T::Mem>())
-			.map(|bitspan| Self { bitspan, capacity })
+			.map(|bitspan| Self {
+				bitspan,
+				capacity,
+				alloc: Global,
+			})
 			.map_err(|_| ManuallyDrop::into_inner(vec))
 	}
 
+	/// Parses text into a bit-vector, reading numerals in a chosen radix.
+	///
+	/// The text may be a single numeral, such as `1f_0a`, or a bracketed list
+	/// of numerals in the form written by the bit-slice formatters. See
+	/// [`ParseBitsError`] for a full description of the accepted text. The
+	/// [`FromStr`] implementation is equivalent to this function, except that
+	/// it reads each numeral’s radix from its prefix, rather than from
+	/// `radix_bits`.
+	///
+	/// ## Parameters
+	///
+	/// - `text`: the text to parse.
+	/// - `radix_bits`: the bit width of each digit (log2 of its radix): `1` for
+	///   binary, `3` for octal, `4` for hexadecimal.
+	///
+	/// ## Panics
+	///
+	/// This panics if `radix_bits` is not in `1 ..= 5`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Msb0>::parse_radix("0x1_a", 4).unwrap();
+	/// assert_eq!(bv, bits![0, 0, 0, 1, 1, 0, 1, 0]);
+	///
+	/// let bv = bitvec![u8, Msb0; 0, 1, 1, 0, 1, 0, 0, 1, 0, 1];
+	/// let text = format!("{:x}", bv);
+	/// assert_eq!(text, "[69, 1]");
+	/// //  The partial last element cannot be read back.
+	/// assert!(BitVec::<u8, Msb0>::parse_radix(&text, 4).is_err());
+	/// assert_eq!(
+	///   BitVec::<u8, Msb0>::parse_radix("[69, 40]", 4).unwrap(),
+	///   bits![0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0],
+	/// );
+	///
+	/// let bv = BitVec::<u8, Msb0>::parse_radix("17", 3).unwrap();
+	/// assert_eq!(bv, bits![0, 0, 1, 1, 1, 1]);
+	/// ```
+	///
+	/// [`FromStr`]: core::str::FromStr
+	#[inline]
+	pub fn parse_radix(
+		text: &str,
+		radix_bits: usize,
+	) -> Result<Self, ParseBitsError> {
+		Self::parse_text(text, Some(radix_bits))
+	}
+
+	/// Parses text into a bit-vector.
+	///
+	/// This drives both [`.parse_radix()`] and the [`FromStr`]
+	/// implementation, which differ only in how they select a radix.
+	///
+	/// [`.parse_radix()`]: Self::parse_radix
+	/// [`FromStr`]: core::str::FromStr
+	#[inline]
+	fn parse_text(
+		text: &str,
+		radix_bits: Option<usize>,
+	) -> Result<Self, ParseBitsError> {
+		let mut out = Self::new();
+		parse_bits(text, radix_bits, bits_of::<T::Mem>(), |bit| {
+			out.push(bit);
+			Ok(())
+		})?;
+		Ok(out)
+	}
+
+	/// Constructs a bit-vector from a sequence of runs of identical bits.
+	///
+	/// This is the inverse of [`BitSlice::iter_runs`]: each item is a bit-value
+	/// and the range of indices that it fills. The bit-vector is as long as the
+	/// furthest end of any range. Indices that no range covers are `0`, and
+	/// where ranges overlap, later runs overwrite earlier ones.
+	///
+	/// This means that the output of [`BitSlice::iter_one_runs`] can also be
+	/// rebuilt, by pairing each of its ranges with `true`, up to the last `1`
+	/// bit.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![u16, Lsb0; 0, 0, 1, 1, 1, 0, 1, 0];
+	/// let bv = BitVec::<u8, Msb0>::from_runs(bits.iter_runs());
+	/// assert_eq!(bv, bits);
+	///
+	/// let ones = bits.iter_one_runs().map(|run| (true, run));
+	/// assert_eq!(BitVec::<u8, Msb0>::from_runs(ones), bits[.. 7]);
+	/// ```
+	#[inline]
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, Range<usize>)> {
+		let mut out = Self::new();
+		for (bit, run) in runs {
+			if run.end > out.len() {
+				out.resize(run.end, false);
+			}
+			out[run].fill(bit);
+		}
+		out
+	}
+
+	/// Attempts to append the contents of a bit-slice to a bit-vector.
+	///
+	/// This is the fallible counterpart to [`.extend_from_bitslice()`]. It
+	/// fails, rather than aborting, if the allocator cannot provide the memory,
+	/// or if the combined length exceeds what a bit-vector can represent. On
+	/// failure, `self` is unchanged.
+	///
+	/// ## Original
+	///
+	/// [`Vec::extend_from_slice`](alloc::vec::Vec::extend_from_slice)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1];
+	/// bv.try_extend_from_bitslice(bits![0, 1, 0, 0, 1]).unwrap();
+	/// assert_eq!(bv, bits![0, 1, 0, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`.extend_from_bitslice()`]: Self::extend_from_bitslice
+	#[inline]
+	pub fn try_extend_from_bitslice<T2, O2>(
+		&mut self,
+		other: &BitSlice<T2, O2>,
+	) -> Result<(), TryReserveError>
+	where
+		T2: BitStore,
+		O2: BitOrder,
+	{
+		let len = self.len();
+		let olen = other.len();
+		self.try_reserve(olen)?;
+		unsafe {
+			self.set_len(len + olen);
+			self.get_unchecked_mut(len ..).clone_from_bitslice(other);
+		}
+		Ok(())
+	}
+}
+
+/// Allocator-aware constructors and extenders.
+impl<T, O, A> BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	/// Copies the contents of a bit-slice into a new allocation, made by the
+	/// given allocator.
+	///
+	/// This behaves exactly as [`::from_bitslice()`] does, except that the
+	/// buffer is owned by `alloc` rather than by the global allocator.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 0, 0, 1];
+	/// let bv = BitVec::from_bitslice_in(bits, Global);
+	/// assert_eq!(bv, bits);
+	/// ```
+	///
+	/// [`::from_bitslice()`]: BitVec::from_bitslice
+	#[inline]
+	pub fn from_bitslice_in(slice: &BitSlice<T, O>, alloc: A) -> Self {
+		let bitspan = slice.as_bitspan();
+
+		let mut vec = AllocVec::with_capacity_in(bitspan.elements(), alloc);
+		vec.extend(slice.domain());
+		let (addr, _, capacity, alloc) = vec.into_raw_parts_with_alloc();
+
+		let bitspan = unsafe {
+			BitSpan::new_unchecked(
+				addr.cast::<T>().into_address(),
+				bitspan.head(),
+				bitspan.len(),
+			)
+		};
+		Self {
+			bitspan,
+			capacity,
+			alloc,
+		}
+	}
+
 	/// Appends the contents of a bit-slice to a bit-vector.
 	///
 	/// This can extend from a bit-slice of any type parameters; it is not
@@ -305,10 +586,11 @@ where
 }
 
 /// Converters.
-impl<T, O> BitVec<T, O>
+impl<T, O, A> BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// Explicitly views the bit-vector as a bit-slice.
 	#[inline]
@@ -388,16 +670,40 @@ where
 	/// let bb = bv.into_boxed_bitslice();
 	/// ```
 	#[inline]
-	pub fn into_boxed_bitslice(self) -> BitBox<T, O> {
+	pub fn into_boxed_bitslice(self) -> BitBox<T, O, A> {
 		let mut bitspan = self.bitspan;
-		let mut boxed =
-			self.into_vec().into_boxed_slice().pipe(ManuallyDrop::new);
+		let (raw, alloc) = self
+			.into_alloc_vec()
+			.into_boxed_slice()
+			.pipe(AllocBox::into_raw_with_allocator);
 		unsafe {
-			bitspan.set_address(boxed.as_mut_ptr().into_address());
-			BitBox::from_raw(bitspan.into_bitslice_ptr_mut())
+			bitspan.set_address(raw.cast::<T>().into_address());
+			BitBox::from_raw_in(bitspan.into_bitslice_ptr_mut(), alloc)
 		}
 	}
 
+	/// Converts a bit-vector into an allocator-aware `Vec` of its underlying
+	/// storage, keeping its allocator.
+	#[inline]
+	fn into_alloc_vec(self) -> AllocVec<T, A> {
+		let this = ManuallyDrop::new(self);
+		unsafe {
+			AllocVec::from_raw_parts_in(
+				this.bitspan.address().to_mut(),
+				this.bitspan.elements(),
+				this.capacity,
+				ptr::read(&this.alloc),
+			)
+		}
+	}
+}
+
+/// Conversions into standard-library collections.
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
 	/// Converts a bit-vector into a `Vec` of its underlying storage.
 	///
 	/// The produced vector contains all elements that contained live bits. Dead
@@ -433,10 +739,11 @@ where
 }
 
 /// Utilities.
-impl<T, O> BitVec<T, O>
+impl<T, O, A> BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	/// Overwrites each element (visible in [`.as_raw_mut_slice()`]) with a new
 	/// bit-pattern.
@@ -566,6 +873,97 @@ where
 		self.bitspan = bitspan;
 	}
 
+	/// Replaces every non-overlapping occurrence of one sequence with another.
+	///
+	/// Occurrences of `from` are found as by [`.match_indices()`], and each is
+	/// replaced by a copy of `to`. The bit-vector grows or shrinks as needed,
+	/// and bits between the matches are moved in place rather than copied into
+	/// a new buffer. Neither sequence needs to share type parameters with the
+	/// bit-vector.
+	///
+	/// Returns the number of replacements made. As with
+	/// [`.match_indices()`], an empty `from` matches at every index, so `to` is
+	/// inserted before each bit and at the end.
+	///
+	/// ## Original
+	///
+	/// [`str::replace`](https://doc.rust-lang.org/std/primitive.str.html#method.replace)
+	///
+	/// ## API Differences
+	///
+	/// This modifies the bit-vector in place, rather than producing a new one.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 1, 1];
+	/// assert_eq!(bv.replace_all(bits![1, 1], bits![0]), 2);
+	/// assert_eq!(bv, bits![0, 0, 0, 0, 1]);
+	///
+	/// assert_eq!(bv.replace_all(bits![0, 0], bits![1, 0, 1]), 2);
+	/// assert_eq!(bv, bits![1, 0, 1, 1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`.match_indices()`]: crate::slice::BitSlice::match_indices
+	#[inline]
+	pub fn replace_all<T2, O2, T3, O3>(
+		&mut self,
+		from: &BitSlice<T2, O2>,
+		to: &BitSlice<T3, O3>,
+	) -> usize
+	where
+		T2: BitStore,
+		O2: BitOrder,
+		T3: BitStore,
+		O3: BitOrder,
+	{
+		let matches = self.match_indices(from).collect::<Vec<_>>();
+		let (len, old, new) = (self.len(), from.len(), to.len());
+
+		if new <= old {
+			//  Shrinking: move each run of unmatched bits towards the front.
+			let (mut read, mut write) = (0, 0);
+			for &at in &matches {
+				self.move_bits(read .. at, write);
+				write += at - read;
+				self[write .. write + new].clone_from_bitslice(to);
+				write += new;
+				read = at + old;
+			}
+			self.move_bits(read .. len, write);
+			self.truncate(write + len - read);
+		}
+		else {
+			//  Growing: make room at the end, then move each run of unmatched
+			//  bits towards the back, starting with the last.
+			let grown = len + matches.len() * (new - old);
+			self.resize(grown, false);
+			let (mut read, mut write) = (len, grown);
+			for &at in matches.iter().rev() {
+				write -= read - (at + old);
+				self.move_bits(at + old .. read, write);
+				write -= new;
+				self[write .. write + new].clone_from_bitslice(to);
+				read = at;
+			}
+		}
+		matches.len()
+	}
+
+	/// Moves a range of bits to a new starting index, skipping empty ranges.
+	///
+	/// Both the source and destination ranges must be within the bit-vector.
+	#[inline]
+	fn move_bits(&mut self, src: Range<usize>, dest: usize) {
+		if !src.is_empty() && src.start != dest {
+			unsafe {
+				self.copy_within_unchecked(src, dest);
+			}
+		}
+	}
+
 	/// Sets the starting-bit index of the span descriptor.
 	///
 	/// ## Safety
@@ -621,7 +1019,7 @@ where
 	fn do_reservation(
 		&mut self,
 		additional: usize,
-		func: impl FnOnce(&mut Vec<T>, usize),
+		func: impl FnOnce(&mut AllocVec<T, &A>, usize),
 	) {
 		let len = self.len();
 		let new_len = len.saturating_add(additional);
@@ -651,10 +1049,87 @@ where
 	/// and capacity.
 	#[inline]
 	fn with_vec<F, R>(&mut self, func: F) -> R
+	where F: FnOnce(&mut ManuallyDrop<AllocVec<T, &A>>) -> R {
+		let mut vec = unsafe {
+			AllocVec::from_raw_parts_in(
+				self.bitspan.address().to_mut(),
+				self.bitspan.elements(),
+				self.capacity,
+				&self.alloc,
+			)
+		}
+		.pipe(ManuallyDrop::new);
+		let out = func(&mut vec);
+
+		let (addr, capacity) = (vec.as_mut_ptr(), vec.capacity());
+		unsafe {
+			self.bitspan.set_address(addr.into_address());
+		}
+		self.capacity = capacity;
+		out
+	}
+}
+
+/// Fallible allocation.
+///
+/// These report failure with the standard library’s `TryReserveError`, which
+/// only its own `Vec` can produce, and so they are only available on
+/// bit-vectors that use the global allocator.
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Fallibly reserves some memory through the underlying vector.
+	///
+	/// This behaves as [`.do_reservation()`] does, except that it reports
+	/// both an unencodable length and an allocation failure as an error,
+	/// rather than panicking or aborting. On failure, the bit-vector is
+	/// unchanged.
+	///
+	/// [`.do_reservation()`]: Self::do_reservation
+	#[inline]
+	fn try_do_reservation(
+		&mut self,
+		additional: usize,
+		func: impl FnOnce(&mut Vec<T>, usize) -> Result<(), TryReserveError>,
+	) -> Result<(), TryReserveError> {
+		let (head, elts) = (self.bitspan.head(), self.bitspan.elements());
+		//  An unencodable length requests more elements than any `Vec` can
+		//  hold, so that the `Vec` reports it as a capacity overflow.
+		let extra_elts = self
+			.len()
+			.checked_add(additional)
+			.filter(|&len| BitSpan::<Const, T, O>::len_encodable(len))
+			.map(|len| crate::mem::elts::<T>(head.into_inner() as usize + len))
+			.map_or(usize::MAX, |new_elts| new_elts - elts);
+
+		self.with_std_vec(|vec| {
+			func(&mut **vec, extra_elts)?;
+			//  The reservation succeeded, so this does not reallocate.
+			vec.resize_with(elts + extra_elts, || <T as BitStore>::ZERO);
+			Ok(())
+		})
+	}
+
+	/// Briefly constructs a standard-library `Vec` controlling the buffer.
+	///
+	/// This behaves as [`.with_vec()`] does, but produces the `Vec` type whose
+	/// fallible methods report the standard `TryReserveError`.
+	///
+	/// [`.with_vec()`]: Self::with_vec
+	#[inline]
+	fn with_std_vec<F, R>(&mut self, func: F) -> R
 	where F: FnOnce(&mut ManuallyDrop<Vec<T>>) -> R {
-		let mut vec = unsafe { ptr::read(self) }
-			.into_vec()
-			.pipe(ManuallyDrop::new);
+		//  `Global` forwards to the same global allocator that `Vec` uses.
+		let mut vec = unsafe {
+			Vec::from_raw_parts(
+				self.bitspan.address().to_mut(),
+				self.bitspan.elements(),
+				self.capacity,
+			)
+		}
+		.pipe(ManuallyDrop::new);
 		let out = func(&mut vec);
 
 		unsafe {
@@ -1,11 +1,22 @@
 //! Port of the `Vec<bool>` inherent API.
 
-use alloc::vec::Vec;
+use alloc::{
+	collections::TryReserveError,
+	vec::Vec,
+};
 use core::{
 	mem::ManuallyDrop,
 	ops::RangeBounds,
+	ptr,
 };
 
+use allocator_api2::{
+	alloc::{
+		Allocator,
+		Global,
+	},
+	vec::Vec as AllocVec,
+};
 use tap::Pipe;
 use wyz::{
 	comu::{
@@ -34,7 +45,7 @@ use crate::{
 	store::BitStore,
 };
 
-/// Port of the `Vec<T>` inherent API.
+/// Port of the `Vec<T>` inherent API, for the global allocator.
 impl<T, O> BitVec<T, O>
 where
 	T: BitStore,
@@ -101,14 +112,55 @@ where
 	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
 	#[inline]
 	pub fn with_capacity(capacity: usize) -> Self {
-		Self::assert_len_encodable(capacity);
-		let mut vec = capacity
-			.pipe(crate::mem::elts::<T>)
-			.pipe(Vec::<T>::with_capacity)
-			.pipe(ManuallyDrop::new);
+		Self::with_capacity_in(capacity, Global)
+	}
+
+	/// Attempts to allocate a new, empty, bit-vector with space for at least
+	/// `capacity` bits before reallocating.
+	///
+	/// ## Original
+	///
+	/// [`Vec::try_with_capacity`](alloc::vec::Vec::try_with_capacity)
+	///
+	/// ## Errors
+	///
+	/// This fails if the requested capacity is longer than what the bit-vector
+	/// can represent (see [`BitSlice::MAX_BITS`]), or if the allocator reports
+	/// a failure.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Lsb0>::try_with_capacity(100).unwrap();
+	/// assert!(bv.is_empty());
+	/// assert!(bv.capacity() >= 100);
+	///
+	/// assert!(BitVec::<u8, Lsb0>::try_with_capacity(usize::MAX).is_err());
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	#[inline]
+	pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+		//  An unencodable capacity requests more elements than any `Vec` can
+		//  hold, so that the `Vec` reports it as a capacity overflow.
+		let elts = if BitSpan::<Const, T, O>::len_encodable(capacity) {
+			crate::mem::elts::<T>(capacity)
+		}
+		else {
+			usize::MAX
+		};
+		let mut vec = Vec::<T>::new();
+		vec.try_reserve_exact(elts)?;
+		let mut vec = ManuallyDrop::new(vec);
 		let (addr, capacity) = (vec.as_mut_ptr(), vec.capacity());
 		let bitspan = BitSpan::uninhabited(unsafe { addr.into_address() });
-		Self { bitspan, capacity }
+		Ok(Self {
+			bitspan,
+			capacity,
+			alloc: Global,
+		})
 	}
 
 	/// Constructs a bit-vector handle from its constituent fields.
@@ -148,13 +200,7 @@ where
 		length: usize,
 		capacity: usize,
 	) -> Self {
-		let bitspan = bitptr.span_unchecked(length);
-		Self {
-			bitspan,
-			capacity: mem::elts::<T>(
-				capacity.saturating_add(bitspan.head().into_inner() as usize),
-			),
-		}
+		Self::from_raw_parts_in(bitptr, length, capacity, Global)
 	}
 
 	/// Decomposes a bit-vector into its constituent member fields.
@@ -182,6 +228,162 @@ where
 			this.capacity(),
 		)
 	}
+}
+
+/// Port of the `Vec<T, A>` inherent API.
+impl<T, O, A> BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	/// Constructs a new, empty, bit-vector that will allocate from `alloc`.
+	///
+	/// This does not allocate until bits are [`.push()`]ed into it, or space is
+	/// explicitly [`.reserve()`]d.
+	///
+	/// ## Original
+	///
+	/// [`Vec::new_in`](alloc::vec::Vec::new_in)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::<u8, Msb0, _>::new_in(Global);
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`.push()`]: Self::push
+	/// [`.reserve()`]: Self::reserve
+	#[inline]
+	pub const fn new_in(alloc: A) -> Self {
+		Self {
+			bitspan: BitSpan::EMPTY,
+			capacity: 0,
+			alloc,
+		}
+	}
+
+	/// Allocates a new, empty, bit-vector from `alloc`, with space for at least
+	/// `capacity` bits before reallocating.
+	///
+	/// ## Original
+	///
+	/// [`Vec::with_capacity_in`](alloc::vec::Vec::with_capacity_in)
+	///
+	/// ## Panics
+	///
+	/// This panics if the requested capacity is longer than what the bit-vector
+	/// can represent. See [`BitSlice::MAX_BITS`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = BitVec::<u16, Lsb0, _>::with_capacity_in(40, Global);
+	/// assert!(bv.is_empty());
+	/// assert!(bv.capacity() >= 40);
+	/// ```
+	///
+	/// [`BitSlice::MAX_BITS`]: crate::slice::BitSlice::MAX_BITS
+	#[inline]
+	pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+		Self::assert_len_encodable(capacity);
+		let (addr, _, capacity, alloc) = capacity
+			.pipe(crate::mem::elts::<T>)
+			.pipe(|elts| AllocVec::<T, A>::with_capacity_in(elts, alloc))
+			.into_raw_parts_with_alloc();
+		let bitspan = BitSpan::uninhabited(unsafe { addr.into_address() });
+		Self {
+			bitspan,
+			capacity,
+			alloc,
+		}
+	}
+
+	/// Constructs a bit-vector handle from its constituent fields and the
+	/// allocator that owns its buffer.
+	///
+	/// ## Original
+	///
+	/// [`Vec::from_raw_parts_in`](alloc::vec::Vec::from_raw_parts_in)
+	///
+	/// ## Safety
+	///
+	/// As with [`::from_raw_parts()`], the only acceptable argument values are
+	/// those produced by a single call to [`.into_raw_parts_with_alloc()`].
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use allocator_api2::alloc::Global;
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::from_bitslice_in(bits![0, 1, 0, 0, 1], Global);
+	/// let (bitptr, len, capa, alloc) = bv.into_raw_parts_with_alloc();
+	/// let bv2 = unsafe {
+	///   BitVec::from_raw_parts_in(bitptr, len, capa, alloc)
+	/// };
+	/// assert_eq!(bv2, bits![0, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`::from_raw_parts()`]: BitVec::from_raw_parts
+	/// [`.into_raw_parts_with_alloc()`]: Self::into_raw_parts_with_alloc
+	#[inline]
+	pub unsafe fn from_raw_parts_in(
+		bitptr: BitPtr<Mut, T, O>,
+		length: usize,
+		capacity: usize,
+		alloc: A,
+	) -> Self {
+		let bitspan = bitptr.span_unchecked(length);
+		Self {
+			bitspan,
+			capacity: mem::elts::<T>(
+				capacity.saturating_add(bitspan.head().into_inner() as usize),
+			),
+			alloc,
+		}
+	}
+
+	/// Decomposes a bit-vector into its constituent member fields and its
+	/// allocator.
+	///
+	/// This disarms the destructor. In order to prevent a memory leak, you must
+	/// pass **these exact values** back into [`::from_raw_parts_in()`].
+	///
+	/// ## Original
+	///
+	/// [`Vec::into_raw_parts_with_alloc`](alloc::vec::Vec::into_raw_parts_with_alloc)
+	///
+	/// [`::from_raw_parts_in()`]: Self::from_raw_parts_in
+	#[inline]
+	pub fn into_raw_parts_with_alloc(
+		self,
+	) -> (BitPtr<Mut, T, O>, usize, usize, A) {
+		let this = ManuallyDrop::new(self);
+		(
+			this.bitspan.to_bitptr(),
+			this.bitspan.len(),
+			this.capacity(),
+			unsafe { ptr::read(&this.alloc) },
+		)
+	}
+
+	/// Gets a reference to the allocator that owns the bit-vector’s buffer.
+	///
+	/// ## Original
+	///
+	/// [`Vec::allocator`](alloc::vec::Vec::allocator)
+	#[inline]
+	pub fn allocator(&self) -> &A {
+		&self.alloc
+	}
 
 	/// Gets the allocation capacity, measured in bits.
 	///
@@ -245,7 +447,7 @@ where
 	#[inline]
 	pub fn reserve(&mut self, additional: usize) {
 		Self::assert_len_encodable(self.len() + additional);
-		self.do_reservation(additional, Vec::<T>::reserve);
+		self.do_reservation(additional, |vec, elts| vec.reserve(elts));
 	}
 
 	/// Ensures that the bit-vector has allocation capacity for *at least*
@@ -282,7 +484,7 @@ where
 	/// [`.reserve()`]: Self::reserve
 	#[inline]
 	pub fn reserve_exact(&mut self, additional: usize) {
-		self.do_reservation(additional, Vec::<T>::reserve_exact);
+		self.do_reservation(additional, |vec, elts| vec.reserve_exact(elts));
 	}
 
 	/// Releases excess capacity back to the allocator.
@@ -314,7 +516,7 @@ where
 	#[cfg(not(tarpaulin_include))]
 	#[deprecated = "prefer `.into_boxed_bitslice() instead"]
 	#[allow(missing_docs, clippy::missing_docs_in_private_items)]
-	pub fn into_boxed_slice(self) -> BitBox<T, O> {
+	pub fn into_boxed_slice(self) -> BitBox<T, O, A> {
 		self.into_boxed_bitslice()
 	}
 
@@ -693,10 +895,11 @@ where
 	/// assert!(bv2.is_empty());
 	/// ```
 	#[inline]
-	pub fn append<T2, O2>(&mut self, other: &mut BitVec<T2, O2>)
+	pub fn append<T2, O2, A2>(&mut self, other: &mut BitVec<T2, O2, A2>)
 	where
 		T2: BitStore,
 		O2: BitOrder,
+		A2: Allocator,
 	{
 		self.extend_from_bitslice(other);
 		other.clear();
@@ -733,7 +936,7 @@ where
 	/// assert!(bv.is_empty());
 	/// ```
 	#[inline]
-	pub fn drain<R>(&mut self, range: R) -> Drain<T, O>
+	pub fn drain<R>(&mut self, range: R) -> Drain<T, O, A>
 	where R: RangeBounds<usize> {
 		Drain::new(self, range)
 	}
@@ -790,35 +993,6 @@ where
 		self.bitspan.len() == 0
 	}
 
-	/// Splits the bit-vector in half at an index, moving `self[at ..]` out into
-	/// a new bit-vector.
-	///
-	/// ## Original
-	///
-	/// [`Vec::split_off`](alloc::vec::Vec::split_off)
-	///
-	/// ## Examples
-	///
-	/// ```rust
-	/// use bitvec::prelude::*;
-	///
-	/// let mut bv = bitvec![0, 1, 0, 0, 1];
-	/// let bv2 = bv.split_off(2);
-	/// assert_eq!((&*bv, &*bv2), (bits![0, 1], bits![0, 0, 1]));
-	/// ```
-	#[inline]
-	pub fn split_off(&mut self, at: usize) -> Self {
-		let len = self.len();
-		self.assert_in_bounds(at, 0 ..= len);
-		let (this, that) = unsafe {
-			self.bitspan
-				.into_bitslice_mut()
-				.split_at_unchecked_mut_noalias(at)
-		};
-		self.bitspan = this.as_mut_bitspan();
-		Self::from_bitslice(that)
-	}
-
 	/// Resizes the bit-vector to a new length, using a function to produce each
 	/// inserted bit.
 	///
@@ -892,7 +1066,8 @@ where
 	/// [`BitBox::leak`]: crate::boxed::BitBox::leak
 	#[inline]
 	#[cfg(not(tarpaulin_include))]
-	pub fn leak<'a>(self) -> &'a mut BitSlice<T, O> {
+	pub fn leak<'a>(self) -> &'a mut BitSlice<T, O>
+	where A: 'a {
 		self.into_boxed_bitslice().pipe(BitBox::leak)
 	}
 
@@ -1021,7 +1196,7 @@ where
 		&mut self,
 		range: R,
 		replace_with: I,
-	) -> Splice<T, O, I::IntoIter>
+	) -> Splice<T, O, I::IntoIter, A>
 	where
 		R: RangeBounds<usize>,
 		I: IntoIterator<Item = bool>,
@@ -1029,3 +1204,155 @@ where
 		Splice::new(self.drain(range), replace_with)
 	}
 }
+
+/// Port of the `Vec<T, A>` inherent API that requires a clonable allocator.
+impl<T, O, A> BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator + Clone,
+{
+	/// Splits the bit-vector in half at an index, moving `self[at ..]` out into
+	/// a new bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::split_off`](alloc::vec::Vec::split_off)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 1, 0, 0, 1];
+	/// let bv2 = bv.split_off(2);
+	/// assert_eq!((&*bv, &*bv2), (bits![0, 1], bits![0, 0, 1]));
+	/// ```
+	#[inline]
+	pub fn split_off(&mut self, at: usize) -> Self {
+		let len = self.len();
+		self.assert_in_bounds(at, 0 ..= len);
+		let (this, that) = unsafe {
+			self.bitspan
+				.into_bitslice_mut()
+				.split_at_unchecked_mut_noalias(at)
+		};
+		self.bitspan = this.as_mut_bitspan();
+		Self::from_bitslice_in(that, self.alloc.clone())
+	}
+}
+
+/// Fallible allocation, ported from the `Vec<T>` inherent API.
+impl<T, O> BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	/// Tries to reserve capacity for *at least* `additional` more bits to be
+	/// appended to the bit-vector.
+	///
+	/// As with [`.reserve()`], the memory for `self[.. self.len() +
+	/// additional]` is initialized once this succeeds. On failure, the
+	/// bit-vector is unchanged.
+	///
+	/// ## Original
+	///
+	/// [`Vec::try_reserve`](alloc::vec::Vec::try_reserve)
+	///
+	/// ## Errors
+	///
+	/// This fails if the new length would exceed the bit-vector’s maximum, or
+	/// if the allocator reports a failure.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv: BitVec = BitVec::new();
+	/// bv.try_reserve(800).unwrap();
+	/// assert!(bv.capacity() >= 800);
+	///
+	/// assert!(bv.try_reserve(usize::MAX).is_err());
+	/// ```
+	///
+	/// [`.reserve()`]: Self::reserve
+	#[inline]
+	pub fn try_reserve(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_do_reservation(additional, Vec::<T>::try_reserve)
+	}
+
+	/// Tries to reserve the minimum capacity for `additional` more bits to be
+	/// appended to the bit-vector.
+	///
+	/// This differs from [`.try_reserve()`] in the same way that
+	/// [`.reserve_exact()`] differs from [`.reserve()`].
+	///
+	/// ## Original
+	///
+	/// [`Vec::try_reserve_exact`](alloc::vec::Vec::try_reserve_exact)
+	///
+	/// ## Errors
+	///
+	/// This fails if the new length would exceed the bit-vector’s maximum, or
+	/// if the allocator reports a failure.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv: BitVec = BitVec::with_capacity(80);
+	/// bv.try_reserve_exact(800).unwrap();
+	/// assert!(bv.capacity() >= 800);
+	/// ```
+	///
+	/// [`.reserve()`]: Self::reserve
+	/// [`.reserve_exact()`]: Self::reserve_exact
+	/// [`.try_reserve()`]: Self::try_reserve
+	#[inline]
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), TryReserveError> {
+		self.try_do_reservation(additional, Vec::<T>::try_reserve_exact)
+	}
+
+	/// Attempts to append a single bit to a bit-vector.
+	///
+	/// This is the fallible counterpart to [`.push()`]: it fails, rather than
+	/// panicking or aborting, if the bit-vector is full or the allocator
+	/// cannot grow it. On failure, the bit-vector is unchanged.
+	///
+	/// ## Original
+	///
+	/// [`Vec::push_within_capacity`](alloc::vec::Vec::push_within_capacity)
+	///
+	/// ## API Differences
+	///
+	/// The standard library has no fallible `push` that may reallocate. This
+	/// grows the allocation as `.push()` does, but reports failure instead of
+	/// aborting.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![0, 0];
+	/// bv.try_push(true).unwrap();
+	/// assert_eq!(bv.as_bitslice(), bits![0, 0, 1]);
+	/// ```
+	///
+	/// [`.push()`]: Self::push
+	#[inline]
+	pub fn try_push(&mut self, value: bool) -> Result<(), TryReserveError> {
+		//  Once this succeeds, `.push()` neither reallocates nor panics.
+		self.try_reserve(1)?;
+		self.push(value);
+		Ok(())
+	}
+}
@@ -15,6 +15,10 @@ use core::{
 	ops::Range,
 };
 
+use allocator_api2::alloc::{
+	Allocator,
+	Global,
+};
 use tap::{
 	Pipe,
 	Tap,
@@ -43,10 +47,11 @@ use crate::{
 };
 
 #[doc = include_str!("../../doc/vec/iter/Extend_bool.md")]
-impl<T, O> Extend<bool> for BitVec<T, O>
+impl<T, O, A> Extend<bool> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn extend<I>(&mut self, iter: I)
@@ -79,10 +84,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> Extend<&'a bool> for BitVec<T, O>
+impl<'a, T, O, A> Extend<&'a bool> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn extend<I>(&mut self, iter: I)
@@ -93,13 +99,15 @@ where
 
 #[cfg(not(tarpaulin_include))]
 #[doc = include_str!("../../doc/vec/iter/Extend_BitRef.md")]
-impl<'a, M, T1, T2, O1, O2> Extend<BitRef<'a, M, T2, O2>> for BitVec<T1, O1>
+impl<'a, M, T1, T2, O1, O2, A> Extend<BitRef<'a, M, T2, O2>>
+	for BitVec<T1, O1, A>
 where
 	M: Mutability,
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn extend<I>(&mut self, iter: I)
@@ -108,10 +116,11 @@ where
 	}
 }
 
-impl<T, O> Extend<T> for BitVec<T, O>
+impl<T, O, A> Extend<T> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn extend<I>(&mut self, iter: I)
@@ -126,10 +135,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T, O> Extend<&'a T> for BitVec<T, O>
+impl<'a, T, O, A> Extend<&'a T> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn extend<I>(&mut self, iter: I)
@@ -217,13 +227,14 @@ where
 }
 
 #[doc = include_str!("../../doc/vec/iter/IntoIterator.md")]
-impl<T, O> IntoIterator for BitVec<T, O>
+impl<T, O, A> IntoIterator for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
-	type IntoIter = <BitBox<T, O> as IntoIterator>::IntoIter;
-	type Item = <BitBox<T, O> as IntoIterator>::Item;
+	type IntoIter = <BitBox<T, O, A> as IntoIterator>::IntoIter;
+	type Item = <BitBox<T, O, A> as IntoIterator>::Item;
 
 	#[inline]
 	fn into_iter(self) -> Self::IntoIter {
@@ -233,10 +244,11 @@ where
 
 #[cfg(not(tarpaulin_include))]
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#impl-IntoIterator-1)
-impl<'a, T, O> IntoIterator for &'a BitVec<T, O>
+impl<'a, T, O, A> IntoIterator for &'a BitVec<T, O, A>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
+	A: Allocator,
 {
 	type IntoIter = <&'a BitSlice<T, O> as IntoIterator>::IntoIter;
 	type Item = <&'a BitSlice<T, O> as IntoIterator>::Item;
@@ -249,10 +261,11 @@ where
 
 #[cfg(not(tarpaulin_include))]
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Vec.html#impl-IntoIterator-2)
-impl<'a, T, O> IntoIterator for &'a mut BitVec<T, O>
+impl<'a, T, O, A> IntoIterator for &'a mut BitVec<T, O, A>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
+	A: Allocator,
 {
 	type IntoIter = <&'a mut BitSlice<T, O> as IntoIterator>::IntoIter;
 	type Item = <&'a mut BitSlice<T, O> as IntoIterator>::Item;
@@ -264,13 +277,14 @@ where
 }
 
 #[doc = include_str!("../../doc/vec/iter/Drain.md")]
-pub struct Drain<'a, T, O>
+pub struct Drain<'a, T, O, A = Global>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
+	A: Allocator,
 {
 	/// Exclusive reference to the handle that created the drain.
-	source: &'a mut BitVec<T, O>,
+	source: &'a mut BitVec<T, O, A>,
 	/// The range of the source bit-vector’s buffer that is being drained.
 	drain:  BitPtrRange<Mut, T, O>,
 	/// The range of the source bit-vector’s preserved back section. This runs
@@ -279,13 +293,14 @@ where
 	tail:   Range<usize>,
 }
 
-impl<'a, T, O> Drain<'a, T, O>
+impl<'a, T, O, A> Drain<'a, T, O, A>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
+	A: Allocator,
 {
 	/// Produces a new drain over a region of a bit-vector.
-	pub(super) fn new<R>(source: &'a mut BitVec<T, O>, range: R) -> Self
+	pub(super) fn new<R>(source: &'a mut BitVec<T, O, A>, range: R) -> Self
 	where R: RangeExt<usize> {
 		let len = source.len();
 		let region = range.normalize(None, len);
@@ -408,10 +423,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-AsRef%3C%5BT%5D%3E)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for Drain<'_, T, O>
+impl<T, O, A> AsRef<BitSlice<T, O>> for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_ref(&self) -> &BitSlice<T, O> {
@@ -420,10 +436,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Debug for Drain<'_, T, O>
+impl<T, O, A> Debug for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
 		fmt.debug_tuple("Drain").field(&self.as_bitslice()).finish()
@@ -432,10 +449,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Iterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Iterator for Drain<'_, T, O>
+impl<T, O, A> Iterator for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Item = bool;
 
@@ -454,10 +472,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-DoubleEndedIterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> DoubleEndedIterator for Drain<'_, T, O>
+impl<T, O, A> DoubleEndedIterator for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
@@ -472,10 +491,11 @@ where
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-ExactSizeIterator)
 #[cfg(not(tarpaulin_include))]
-impl<T, O> ExactSizeIterator for Drain<'_, T, O>
+impl<T, O, A> ExactSizeIterator for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn len(&self) -> usize {
@@ -484,37 +504,41 @@ where
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-FusedIterator)
-impl<T, O> FusedIterator for Drain<'_, T, O>
+impl<T, O, A> FusedIterator for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Send)
 // #[allow(clippy::non_send_fields_in_send_ty)]
-unsafe impl<T, O> Send for Drain<'_, T, O>
+unsafe impl<T, O, A> Send for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	for<'a> &'a mut BitSlice<T, O>: Send,
+	A: Allocator + Send,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Sync)
-unsafe impl<T, O> Sync for Drain<'_, T, O>
+unsafe impl<T, O, A> Sync for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	BitSlice<T, O>: Sync,
+	A: Allocator + Sync,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Drop)
-impl<T, O> Drop for Drain<'_, T, O>
+impl<T, O, A> Drop for Drain<'_, T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn drop(&mut self) {
@@ -546,27 +570,29 @@ enum FillStatus {
 
 #[derive(Debug)]
 #[doc = include_str!("../../doc/vec/iter/Splice.md")]
-pub struct Splice<'a, T, O, I>
+pub struct Splice<'a, T, O, I, A = Global>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	/// The region of the bit-vector being drained.
-	drain:  Drain<'a, T, O>,
+	drain:  Drain<'a, T, O, A>,
 	/// The bitstream that replaces drained bits.
 	splice: I,
 }
 
-impl<'a, T, O, I> Splice<'a, T, O, I>
+impl<'a, T, O, I, A> Splice<'a, T, O, I, A>
 where
 	O: BitOrder,
 	T: 'a + BitStore,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	/// Constructs a splice out of a drain and a replacement source.
 	pub(super) fn new(
-		drain: Drain<'a, T, O>,
+		drain: Drain<'a, T, O, A>,
 		splice: impl IntoIterator<IntoIter = I, Item = bool>,
 	) -> Self {
 		let splice = splice.into_iter();
@@ -574,11 +600,12 @@ where
 	}
 }
 
-impl<T, O, I> Iterator for Splice<'_, T, O, I>
+impl<T, O, I, A> Iterator for Splice<'_, T, O, I, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	type Item = bool;
 
@@ -598,11 +625,12 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, I> DoubleEndedIterator for Splice<'_, T, O, I>
+impl<T, O, I, A> DoubleEndedIterator for Splice<'_, T, O, I, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
@@ -616,11 +644,12 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, I> ExactSizeIterator for Splice<'_, T, O, I>
+impl<T, O, I, A> ExactSizeIterator for Splice<'_, T, O, I, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	#[inline]
 	fn len(&self) -> usize {
@@ -628,20 +657,22 @@ where
 	}
 }
 
-impl<T, O, I> FusedIterator for Splice<'_, T, O, I>
+impl<T, O, I, A> FusedIterator for Splice<'_, T, O, I, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 }
 
 /// [Original](https://doc.rust-lang.org/alloc/vec/struct.Drain.html#impl-Drop)
-impl<T, O, I> Drop for Splice<'_, T, O, I>
+impl<T, O, I, A> Drop for Splice<'_, T, O, I, A>
 where
 	T: BitStore,
 	O: BitOrder,
 	I: Iterator<Item = bool>,
+	A: Allocator,
 {
 	#[inline]
 	fn drop(&mut self) {
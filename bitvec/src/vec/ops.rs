@@ -14,9 +14,14 @@ use core::{
 		Index,
 		IndexMut,
 		Not,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
 	},
 };
 
+use allocator_api2::alloc::Allocator;
 use wyz::comu::Mut;
 
 use super::BitVec;
@@ -28,34 +33,37 @@ use crate::{
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitAndAssign<BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitand_assign(&mut self, rhs: BitVec<T, O, A>) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitAndAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitAndAssign<&BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitand_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitand_assign(&mut self, rhs: &BitVec<T, O, A>) {
 		*self &= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitAnd<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitAnd<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitAndAssign<Rhs>,
 {
 	type Output = Self;
@@ -68,10 +76,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitAndAssign<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitAndAssign<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitAndAssign<Rhs>,
 {
 	#[inline]
@@ -81,34 +90,37 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitOrAssign<BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitor_assign(&mut self, rhs: BitVec<T, O, A>) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitOrAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitOrAssign<&BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitor_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitor_assign(&mut self, rhs: &BitVec<T, O, A>) {
 		*self |= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitOr<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitOr<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitOrAssign<Rhs>,
 {
 	type Output = Self;
@@ -121,10 +133,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitOrAssign<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitOrAssign<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitOrAssign<Rhs>,
 {
 	#[inline]
@@ -134,34 +147,37 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitXorAssign<BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: BitVec<T, O>) {
+	fn bitxor_assign(&mut self, rhs: BitVec<T, O, A>) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BitXorAssign<&BitVec<T, O>> for BitSlice<T, O>
+impl<T, O, A> BitXorAssign<&BitVec<T, O, A>> for BitSlice<T, O>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn bitxor_assign(&mut self, rhs: &BitVec<T, O>) {
+	fn bitxor_assign(&mut self, rhs: &BitVec<T, O, A>) {
 		*self ^= rhs.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitXor<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitXor<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitXorAssign<Rhs>,
 {
 	type Output = Self;
@@ -174,10 +190,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> BitXorAssign<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> BitXorAssign<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: BitXorAssign<Rhs>,
 {
 	#[inline]
@@ -186,10 +203,11 @@ where
 	}
 }
 
-impl<T, O> Deref for BitVec<T, O>
+impl<T, O, A> Deref for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Target = BitSlice<T, O>;
 
@@ -199,10 +217,11 @@ where
 	}
 }
 
-impl<T, O> DerefMut for BitVec<T, O>
+impl<T, O, A> DerefMut for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn deref_mut(&mut self) -> &mut Self::Target {
@@ -210,10 +229,11 @@ where
 	}
 }
 
-impl<T, O> Drop for BitVec<T, O>
+impl<T, O, A> Drop for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn drop(&mut self) {
@@ -224,10 +244,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> Index<Idx> for BitVec<T, O>
+impl<T, O, A, Idx> Index<Idx> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: Index<Idx>,
 {
 	type Output = <BitSlice<T, O> as Index<Idx>>::Output;
@@ -239,10 +260,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Idx> IndexMut<Idx> for BitVec<T, O>
+impl<T, O, A, Idx> IndexMut<Idx> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	BitSlice<T, O>: IndexMut<Idx>,
 {
 	#[inline]
@@ -255,10 +277,11 @@ where
 on the value of bits in the buffer that are outside the domain of
 [`BitVec::as_mut_bitslice`].
 **/
-impl<T, O> Not for BitVec<T, O>
+impl<T, O, A> Not for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	type Output = Self;
 
@@ -270,3 +293,61 @@ where
 		self
 	}
 }
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, A> Shl<usize> for BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shl(mut self, by: usize) -> Self::Output {
+		self <<= by;
+		self
+	}
+}
+
+impl<T, O, A> ShlAssign<usize> for BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_register.md")]
+	fn shl_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_register_up(by);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, O, A> Shr<usize> for BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	type Output = Self;
+
+	#[inline]
+	fn shr(mut self, by: usize) -> Self::Output {
+		self >>= by;
+		self
+	}
+}
+
+impl<T, O, A> ShrAssign<usize> for BitVec<T, O, A>
+where
+	T: BitStore,
+	O: BitOrder,
+	A: Allocator,
+{
+	#[inline]
+	#[doc = include_str!("../../doc/slice/shift_register.md")]
+	fn shr_assign(&mut self, by: usize) {
+		self.as_mut_bitslice().shift_register_down(by);
+	}
+}
@@ -0,0 +1,1212 @@
+#![doc = include_str!("../../doc/vec/small.md")]
+
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Formatter,
+	},
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::{
+		self,
+		FromIterator,
+		FusedIterator,
+	},
+	ops::{
+		Deref,
+		DerefMut,
+		Range,
+		RangeBounds,
+	},
+};
+
+use wyz::range::RangeExt;
+
+use super::BitVec;
+use crate::{
+	array::{
+		BitArray,
+		BitArrayVec,
+	},
+	mem,
+	order::{
+		BitOrder,
+		Lsb0,
+	},
+	slice::BitSlice,
+	store::BitStore,
+	view::BitViewSized,
+};
+
+mod tests;
+
+#[doc = include_str!("../../doc/vec/SmallBitVec.md")]
+pub struct SmallBitVec<A = [usize; 2], O = Lsb0>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The current storage of the bits.
+	inner: Inner<A, O>,
+}
+
+/// Storage for a small bit-vector.
+enum Inner<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The bits fit in the inline buffer.
+	Inline(BitArrayVec<A, O>),
+	/// The bits have outgrown the inline buffer, and moved to the heap.
+	Heap(BitVec<A::Store, O>),
+}
+
+/// Constructors and conversions.
+impl<A, O> SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Constructs a new, empty, small bit-vector, without allocating.
+	///
+	/// This is a `const fn`, and so can be used to initialize `static` and
+	/// `const` items.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let sbv = SmallBitVec::<[u32; 2], Msb0>::new();
+	/// assert!(sbv.is_empty());
+	/// assert!(!sbv.spilled());
+	/// assert_eq!(sbv.capacity(), 64);
+	/// ```
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			inner: Inner::Inline(BitArrayVec::new()),
+		}
+	}
+
+	/// Constructs a new, empty, small bit-vector with room for at least
+	/// `capacity` bits.
+	///
+	/// This only allocates if `capacity` exceeds the inline capacity.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let sbv = SmallBitVec::<u8, Lsb0>::with_capacity(8);
+	/// assert!(!sbv.spilled());
+	/// let sbv = SmallBitVec::<u8, Lsb0>::with_capacity(9);
+	/// assert!(sbv.spilled());
+	/// ```
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		if capacity <= mem::bits_of::<A>() {
+			return Self::new();
+		}
+		Self {
+			inner: Inner::Heap(BitVec::with_capacity(capacity)),
+		}
+	}
+
+	/// Copies the contents of a bit-slice into a new small bit-vector.
+	///
+	/// This only allocates if the bit-slice is longer than the inline
+	/// capacity. The source may use any storage type and ordering.
+	#[inline]
+	pub fn from_bitslice<T, O2>(bits: &BitSlice<T, O2>) -> Self
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		let mut out = Self::with_capacity(bits.len());
+		out.extend_from_bitslice(bits);
+		out
+	}
+
+	/// Converts the small bit-vector into an ordinary bit-vector.
+	///
+	/// This does not allocate if the bits have already spilled to the heap.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::new();
+	/// sbv.extend_from_bitslice(bits![0, 1, 1]);
+	/// let bv: BitVec<u8, Lsb0> = sbv.into_bitvec();
+	/// assert_eq!(bv, bits![0, 1, 1]);
+	/// ```
+	#[inline]
+	pub fn into_bitvec(self) -> BitVec<A::Store, O> {
+		match self.inner {
+			| Inner::Inline(bits) => {
+				let mut out = BitVec::with_capacity(bits.len());
+				out.extend_from_bitslice(bits.as_bitslice());
+				out
+			},
+			| Inner::Heap(bv) => bv,
+		}
+	}
+}
+
+/// Port of the `Vec<T>` inherent API.
+impl<A, O> SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Gets the number of bits that can be held without spilling to the heap.
+	#[inline]
+	pub const fn inline_capacity(&self) -> usize {
+		mem::bits_of::<A>()
+	}
+
+	/// Tests whether the bits have outgrown the inline buffer and moved into
+	/// a heap allocation.
+	#[inline]
+	pub fn spilled(&self) -> bool {
+		matches!(self.inner, Inner::Heap(_))
+	}
+
+	/// Gets the number of bits that can be held without reallocating.
+	///
+	/// ## Original
+	///
+	/// [`Vec::capacity`](alloc::vec::Vec::capacity)
+	#[inline]
+	pub fn capacity(&self) -> usize {
+		match &self.inner {
+			| Inner::Inline(bits) => bits.capacity(),
+			| Inner::Heap(bv) => bv.capacity(),
+		}
+	}
+
+	/// Gets the number of live bits in the small bit-vector.
+	#[inline]
+	pub fn len(&self) -> usize {
+		match &self.inner {
+			| Inner::Inline(bits) => bits.len(),
+			| Inner::Heap(bv) => bv.len(),
+		}
+	}
+
+	/// Tests whether the small bit-vector is empty.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Explicitly views the small bit-vector as a bit-slice.
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		match &self.inner {
+			| Inner::Inline(bits) => bits.as_bitslice(),
+			| Inner::Heap(bv) => bv.as_bitslice(),
+		}
+	}
+
+	/// Explicitly views the small bit-vector as a mutable bit-slice.
+	#[inline]
+	pub fn as_mut_bitslice(&mut self) -> &mut BitSlice<A::Store, O> {
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.as_mut_bitslice(),
+			| Inner::Heap(bv) => bv.as_mut_bitslice(),
+		}
+	}
+
+	/// Ensures that at least `additional` more bits can be held without
+	/// reallocating.
+	///
+	/// While the bits are inline, this spills them to the heap only if the
+	/// inline buffer cannot hold `additional` more bits.
+	///
+	/// ## Original
+	///
+	/// [`Vec::reserve`](alloc::vec::Vec::reserve)
+	///
+	/// ## Panics
+	///
+	/// This panics if the new capacity exceeds the bit-vector’s maximum.
+	#[inline]
+	pub fn reserve(&mut self, additional: usize) {
+		match &mut self.inner {
+			| Inner::Inline(bits) if bits.remaining_capacity() < additional => {
+				let capacity = bits
+					.len()
+					.checked_add(additional)
+					.expect("bit-vector capacity exceeded");
+				let mut bv = BitVec::with_capacity(capacity);
+				bv.extend_from_bitslice(bits.as_bitslice());
+				self.inner = Inner::Heap(bv);
+			},
+			| Inner::Inline(_) => {},
+			| Inner::Heap(bv) => bv.reserve(additional),
+		}
+	}
+
+	/// Releases excess capacity.
+	///
+	/// If the bits have spilled, but now fit in the inline buffer, they are
+	/// moved back into it and the heap allocation is freed.
+	///
+	/// ## Original
+	///
+	/// [`Vec::shrink_to_fit`](alloc::vec::Vec::shrink_to_fit)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::from_bitslice(bits![1; 20]);
+	/// assert!(sbv.spilled());
+	/// sbv.truncate(5);
+	/// sbv.shrink_to_fit();
+	/// assert!(!sbv.spilled());
+	/// assert_eq!(sbv, bits![1; 5]);
+	/// ```
+	#[inline]
+	pub fn shrink_to_fit(&mut self) {
+		if let Inner::Heap(bv) = &mut self.inner {
+			if bv.len() <= mem::bits_of::<A>() {
+				let mut bits = BitArrayVec::new();
+				bits.extend_from_bitslice(bv.as_bitslice());
+				self.inner = Inner::Inline(bits);
+			}
+			else {
+				bv.shrink_to_fit();
+			}
+		}
+	}
+
+	/// Shortens the small bit-vector, keeping the first `new_len` bits.
+	///
+	/// This has no effect if `new_len` is not less than `self.len()`. It
+	/// never moves the bits back into the inline buffer.
+	///
+	/// ## Original
+	///
+	/// [`Vec::truncate`](alloc::vec::Vec::truncate)
+	#[inline]
+	pub fn truncate(&mut self, new_len: usize) {
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.truncate(new_len),
+			| Inner::Heap(bv) => bv.truncate(new_len),
+		}
+	}
+
+	/// Takes a bit out of the small bit-vector, replacing it with the last
+	/// bit.
+	///
+	/// ## Original
+	///
+	/// [`Vec::swap_remove`](alloc::vec::Vec::swap_remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	#[inline]
+	pub fn swap_remove(&mut self, index: usize) -> bool {
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.swap_remove(index),
+			| Inner::Heap(bv) => bv.swap_remove(index),
+		}
+	}
+
+	/// Inserts a bit at a given position, shifting all bits after it one spot
+	/// to the right.
+	///
+	/// ## Original
+	///
+	/// [`Vec::insert`](alloc::vec::Vec::insert)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is greater than `self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::from_bitslice(bits![0; 8]);
+	/// sbv.insert(3, true);
+	/// assert!(sbv.spilled());
+	/// assert_eq!(sbv.first_one(), Some(3));
+	/// ```
+	#[inline]
+	pub fn insert(&mut self, index: usize, value: bool) {
+		self.as_bitslice().assert_in_bounds(index, 0 ..= self.len());
+		self.reserve(1);
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.insert(index, value),
+			| Inner::Heap(bv) => bv.insert(index, value),
+		}
+	}
+
+	/// Removes a bit at a given position, shifting all bits after it one spot
+	/// to the left.
+	///
+	/// ## Original
+	///
+	/// [`Vec::remove`](alloc::vec::Vec::remove)
+	///
+	/// ## Panics
+	///
+	/// This panics if `index` is out of bounds.
+	#[inline]
+	pub fn remove(&mut self, index: usize) -> bool {
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.remove(index),
+			| Inner::Heap(bv) => bv.remove(index),
+		}
+	}
+
+	/// Retains only the bits that the predicate allows.
+	///
+	/// ## Original
+	///
+	/// [`Vec::retain`](alloc::vec::Vec::retain)
+	///
+	/// ## API Differences
+	///
+	/// As with [`BitVec::retain`], the predicate receives both the index of
+	/// the bit and its value.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Msb0>::from_bitslice(bits![0, 1, 0, 0, 1]);
+	/// sbv.retain(|idx, _| idx % 2 == 0);
+	/// assert_eq!(sbv, bits![0, 0, 1]);
+	/// ```
+	#[inline]
+	pub fn retain<F>(&mut self, mut func: F)
+	where F: FnMut(usize, &bool) -> bool {
+		match &mut self.inner {
+			| Inner::Inline(bits) => {
+				let mut kept = 0;
+				for idx in 0 .. bits.len() {
+					let bit = bits[idx];
+					if func(idx, &bit) {
+						bits.set(kept, bit);
+						kept += 1;
+					}
+				}
+				bits.truncate(kept);
+			},
+			| Inner::Heap(bv) => bv.retain(func),
+		}
+	}
+
+	/// Appends a bit to the end of the small bit-vector.
+	///
+	/// This spills the bits to the heap if the inline buffer is full.
+	///
+	/// ## Original
+	///
+	/// [`Vec::push`](alloc::vec::Vec::push)
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::new();
+	/// for _ in 0 .. 8 {
+	///   sbv.push(true);
+	/// }
+	/// assert!(!sbv.spilled());
+	/// sbv.push(false);
+	/// assert!(sbv.spilled());
+	/// assert_eq!(sbv.len(), 9);
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: bool) {
+		self.reserve(1);
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.push(value),
+			| Inner::Heap(bv) => bv.push(value),
+		}
+	}
+
+	/// Removes the last bit from the small bit-vector, if present.
+	///
+	/// ## Original
+	///
+	/// [`Vec::pop`](alloc::vec::Vec::pop)
+	#[inline]
+	pub fn pop(&mut self) -> Option<bool> {
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.pop(),
+			| Inner::Heap(bv) => bv.pop(),
+		}
+	}
+
+	/// Iterates over a portion of the small bit-vector, *removing* all
+	/// yielded bits from it.
+	///
+	/// ## Original
+	///
+	/// [`Vec::drain`](alloc::vec::Vec::drain)
+	///
+	/// ## API Differences
+	///
+	/// While the bits are inline, the drained region is removed immediately,
+	/// and the iterator yields from a copy of it.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` departs `0 .. self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::from_bitslice(bits![0, 1, 0, 0, 1]);
+	/// let drained = sbv.drain(1 ..= 3).collect::<BitVec>();
+	/// assert_eq!(sbv, bits![0, 1]);
+	/// assert_eq!(drained, bits![1, 0, 0]);
+	/// ```
+	#[inline]
+	pub fn drain<R>(&mut self, range: R) -> Drain<'_, A, O>
+	where R: RangeBounds<usize> {
+		let inner = match &mut self.inner {
+			| Inner::Inline(bits) => Either::Inline(Inline::drain(bits, range)),
+			| Inner::Heap(bv) => Either::Heap(bv.drain(range)),
+		};
+		Drain { inner }
+	}
+
+	/// Empties the small bit-vector.
+	///
+	/// This does not move spilled bits back into the inline buffer.
+	///
+	/// ## Original
+	///
+	/// [`Vec::clear`](alloc::vec::Vec::clear)
+	#[inline]
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+
+	/// Splits the small bit-vector in half at an index, moving `self[at ..]`
+	/// out into a new small bit-vector.
+	///
+	/// ## Original
+	///
+	/// [`Vec::split_off`](alloc::vec::Vec::split_off)
+	///
+	/// ## Panics
+	///
+	/// This panics if `at` is greater than `self.len()`.
+	#[inline]
+	pub fn split_off(&mut self, at: usize) -> Self {
+		let out = Self::from_bitslice(&self[at ..]);
+		self.truncate(at);
+		out
+	}
+
+	/// Resizes the small bit-vector to a new length. New bits are initialized
+	/// to `value`.
+	///
+	/// ## Original
+	///
+	/// [`Vec::resize`](alloc::vec::Vec::resize)
+	#[inline]
+	pub fn resize(&mut self, new_len: usize, value: bool) {
+		let len = self.len();
+		if new_len <= len {
+			return self.truncate(new_len);
+		}
+		self.reserve(new_len - len);
+		match &mut self.inner {
+			| Inner::Inline(bits) => {
+				bits.extend(iter::repeat(value).take(new_len - len))
+			},
+			| Inner::Heap(bv) => bv.resize(new_len, value),
+		}
+	}
+
+	/// Copies the contents of a bit-slice onto the end of the small
+	/// bit-vector. The source may use any storage type and ordering.
+	///
+	/// ## Original
+	///
+	/// [`Vec::extend_from_slice`](alloc::vec::Vec::extend_from_slice)
+	#[inline]
+	pub fn extend_from_bitslice<T, O2>(&mut self, other: &BitSlice<T, O2>)
+	where
+		T: BitStore,
+		O2: BitOrder,
+	{
+		self.reserve(other.len());
+		match &mut self.inner {
+			| Inner::Inline(bits) => bits.extend_from_bitslice(other),
+			| Inner::Heap(bv) => bv.extend_from_bitslice(other),
+		}
+	}
+
+	/// Replaces a region of the small bit-vector with the bits of an iterator,
+	/// yielding the bits that were removed.
+	///
+	/// ## Original
+	///
+	/// [`Vec::splice`](alloc::vec::Vec::splice)
+	///
+	/// ## API Differences
+	///
+	/// While the bits are inline, the replacement happens immediately: the
+	/// removed bits are copied out, and `replace_with` is fully consumed
+	/// before this returns. It spills to the heap if the result does not fit
+	/// inline.
+	///
+	/// ## Panics
+	///
+	/// This panics if `range` departs `0 .. self.len()`.
+	///
+	/// ## Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut sbv = SmallBitVec::<u8, Lsb0>::from_bitslice(bits![0, 1, 1]);
+	/// let yank = sbv.splice(.. 2, bits![static 1; 8].iter().by_vals());
+	/// assert_eq!(yank.collect::<BitVec>(), bits![0, 1]);
+	/// assert_eq!(sbv, bits![1; 9]);
+	/// assert!(sbv.spilled());
+	/// ```
+	#[inline]
+	pub fn splice<R, I>(
+		&mut self,
+		range: R,
+		replace_with: I,
+	) -> Splice<'_, A, O, I::IntoIter>
+	where
+		R: RangeBounds<usize>,
+		I: IntoIterator<Item = bool>,
+	{
+		if let Inner::Inline(bits) = &mut self.inner {
+			let removed = Inline::drain(bits, range);
+			let tail = Inline::drain(bits, removed.start ..);
+			self.extend(replace_with);
+			self.extend_from_bitslice(tail.as_bitslice());
+			return Splice {
+				inner: Either::Inline(removed),
+			};
+		}
+		match &mut self.inner {
+			| Inner::Heap(bv) => Splice {
+				inner: Either::Heap(bv.splice(range, replace_with)),
+			},
+			| Inner::Inline(_) => unreachable!("inline splices return early"),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsRef<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_ref(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> AsMut<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn as_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Borrow<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow(&self) -> &BitSlice<A::Store, O> {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> BorrowMut<BitSlice<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut BitSlice<A::Store, O> {
+		self.as_mut_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Clone for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		let inner = match &self.inner {
+			| Inner::Inline(bits) => Inner::Inline(bits.clone()),
+			| Inner::Heap(bv) => Inner::Heap(bv.clone()),
+		};
+		Self { inner }
+	}
+}
+
+impl<A, O> Eq for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Ord for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.as_bitslice().cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<O1, A, O2, T> PartialEq<SmallBitVec<A, O2>> for BitSlice<T, O1>
+where
+	O1: BitOrder,
+	O2: BitOrder,
+	A: BitViewSized,
+	T: BitStore,
+{
+	#[inline]
+	fn eq(&self, other: &SmallBitVec<A, O2>) -> bool {
+		self == other.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialEq<Rhs> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialEq<Rhs>,
+{
+	#[inline]
+	fn eq(&self, other: &Rhs) -> bool {
+		self.as_bitslice() == other
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, T, O> PartialOrd<SmallBitVec<A, O>> for BitSlice<T, O>
+where
+	A: BitViewSized,
+	T: BitStore,
+	O: BitOrder,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &SmallBitVec<A, O>) -> Option<cmp::Ordering> {
+		self.partial_cmp(other.as_bitslice())
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, Rhs> PartialOrd<Rhs> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	Rhs: ?Sized,
+	BitSlice<A::Store, O>: PartialOrd<Rhs>,
+{
+	#[inline]
+	fn partial_cmp(&self, other: &Rhs) -> Option<cmp::Ordering> {
+		self.as_bitslice().partial_cmp(other)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Default for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<A, O> Debug for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_struct("SmallBitVec")
+			.field("spilled", &self.spilled())
+			.field("capacity", &self.capacity())
+			.finish()?;
+		fmt.write_str(" ")?;
+		Display::fmt(self, fmt)
+	}
+}
+
+easy_fmt! {
+	impl Binary
+	impl Display
+	impl LowerHex
+	impl Octal
+	impl UpperHex
+	for SmallBitVec<A>
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Hash for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn hash<H>(&self, hasher: &mut H)
+	where H: Hasher {
+		self.as_bitslice().hash(hasher);
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Deref for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Target = BitSlice<A::Store, O>;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.as_bitslice()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> DerefMut for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_bitslice()
+	}
+}
+
+impl<A, O> Extend<bool> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = bool> {
+		let iter = iter.into_iter();
+		self.reserve(iter.size_hint().0);
+		iter.for_each(|bit| self.push(bit));
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> Extend<&'a bool> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn extend<I>(&mut self, iter: I)
+	where I: IntoIterator<Item = &'a bool> {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> FromIterator<bool> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from_iter<I>(iter: I) -> Self
+	where I: IntoIterator<Item = bool> {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O, T, O2> From<&BitSlice<T, O2>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	T: BitStore,
+	O2: BitOrder,
+{
+	#[inline]
+	fn from(bits: &BitSlice<T, O2>) -> Self {
+		Self::from_bitslice(bits)
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArray<A, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArray<A, O>) -> Self {
+		BitArrayVec::from(bits).into()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitArrayVec<A, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bits: BitArrayVec<A, O>) -> Self {
+		Self {
+			inner: Inner::Inline(bits),
+		}
+	}
+}
+
+/// Takes ownership of a bit-vector’s allocation, without copying it inline.
+///
+/// Use [`.shrink_to_fit()`] to move the bits into the inline buffer if they
+/// fit.
+///
+/// [`.shrink_to_fit()`]: SmallBitVec::shrink_to_fit
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<BitVec<A::Store, O>> for SmallBitVec<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(bv: BitVec<A::Store, O>) -> Self {
+		Self {
+			inner: Inner::Heap(bv),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> From<SmallBitVec<A, O>> for BitVec<A::Store, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn from(sbv: SmallBitVec<A, O>) -> Self {
+		sbv.into_bitvec()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> IntoIterator for &'a SmallBitVec<A, O>
+where
+	O: BitOrder,
+	A: 'a + BitViewSized,
+{
+	type IntoIter = <&'a BitSlice<A::Store, O> as IntoIterator>::IntoIter;
+	type Item = <&'a BitSlice<A::Store, O> as IntoIterator>::Item;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_bitslice().into_iter()
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, A, O> IntoIterator for &'a mut SmallBitVec<A, O>
+where
+	O: BitOrder,
+	A: 'a + BitViewSized,
+{
+	type IntoIter = <&'a mut BitSlice<A::Store, O> as IntoIterator>::IntoIter;
+	type Item = <&'a mut BitSlice<A::Store, O> as IntoIterator>::Item;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_bitslice().into_iter()
+	}
+}
+
+/// Draining iterator over a region of a small bit-vector.
+///
+/// This is produced by [`SmallBitVec::drain`].
+///
+/// ## Original
+///
+/// [`vec::Drain`](alloc::vec::Drain)
+pub struct Drain<'a, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The bits being drained.
+	inner: Either<super::Drain<'a, A::Store, O>, A, O>,
+}
+
+impl<A, O> Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Views the bits that have not yet been yielded.
+	///
+	/// ## Original
+	///
+	/// [`Drain::as_slice`](alloc::vec::Drain::as_slice)
+	#[inline]
+	pub fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		match &self.inner {
+			| Either::Inline(bits) => bits.as_bitslice(),
+			| Either::Heap(drain) => drain.as_bitslice(),
+		}
+	}
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<A, O> Debug for Drain<'_, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("Drain").field(&self.as_bitslice()).finish()
+	}
+}
+
+/// Splicing iterator over a region of a small bit-vector.
+///
+/// This is produced by [`SmallBitVec::splice`].
+///
+/// ## Original
+///
+/// [`vec::Splice`](alloc::vec::Splice)
+pub struct Splice<'a, A, O, I>
+where
+	A: BitViewSized,
+	O: BitOrder,
+	I: Iterator<Item = bool>,
+{
+	/// The bits being removed.
+	inner: Either<super::Splice<'a, A::Store, O, I>, A, O>,
+}
+
+/// Implements the iteration traits on a wrapper of `Either`.
+macro_rules! delegate_iter {
+	($($t:ident $(<$i:ident>)?),+ $(,)?) => { $(
+		impl<A, O $(, $i)?> Iterator for $t<'_, A, O $(, $i)?>
+		where
+			A: BitViewSized,
+			O: BitOrder,
+			$($i: Iterator<Item = bool>,)?
+		{
+			type Item = bool;
+
+			easy_iter!();
+
+			#[inline]
+			fn next(&mut self) -> Option<Self::Item> {
+				self.inner.next()
+			}
+		}
+
+		impl<A, O $(, $i)?> DoubleEndedIterator for $t<'_, A, O $(, $i)?>
+		where
+			A: BitViewSized,
+			O: BitOrder,
+			$($i: Iterator<Item = bool>,)?
+		{
+			#[inline]
+			fn next_back(&mut self) -> Option<Self::Item> {
+				self.inner.next_back()
+			}
+		}
+
+		impl<A, O $(, $i)?> ExactSizeIterator for $t<'_, A, O $(, $i)?>
+		where
+			A: BitViewSized,
+			O: BitOrder,
+			$($i: Iterator<Item = bool>,)?
+		{
+			#[inline]
+			fn len(&self) -> usize {
+				self.inner.len()
+			}
+		}
+
+		impl<A, O $(, $i)?> FusedIterator for $t<'_, A, O $(, $i)?>
+		where
+			A: BitViewSized,
+			O: BitOrder,
+			$($i: Iterator<Item = bool>,)?
+		{
+		}
+	)+ };
+}
+
+delegate_iter!(Drain, Splice<I>);
+
+/// Either a heap iterator, or a copy of bits drained from the inline buffer.
+enum Either<H, A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Bits removed from the inline buffer.
+	Inline(Inline<A, O>),
+	/// A draining iterator over a heap bit-vector.
+	Heap(H),
+}
+
+impl<H, A, O> Either<H, A, O>
+where
+	H: DoubleEndedIterator<Item = bool> + ExactSizeIterator,
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Yields the next bit from the front.
+	fn next(&mut self) -> Option<bool> {
+		match self {
+			| Self::Inline(bits) => bits.next(),
+			| Self::Heap(iter) => iter.next(),
+		}
+	}
+
+	/// Yields the next bit from the back.
+	fn next_back(&mut self) -> Option<bool> {
+		match self {
+			| Self::Inline(bits) => bits.next_back(),
+			| Self::Heap(iter) => iter.next_back(),
+		}
+	}
+
+	/// Counts the bits that remain to be yielded.
+	fn len(&self) -> usize {
+		match self {
+			| Self::Inline(bits) => bits.range.len(),
+			| Self::Heap(iter) => iter.len(),
+		}
+	}
+}
+
+/// A copy of bits removed from an inline buffer.
+struct Inline<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// The removed bits.
+	bits:  BitArrayVec<A, O>,
+	/// The region of `bits` that has not yet been yielded.
+	range: Range<usize>,
+	/// The index in the source buffer at which the bits were removed.
+	start: usize,
+}
+
+impl<A, O> Inline<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	/// Copies a region out of an inline buffer, and closes the gap it leaves.
+	fn drain<R>(source: &mut BitArrayVec<A, O>, range: R) -> Self
+	where R: RangeBounds<usize> {
+		let len = source.len();
+		let Range { start, end } = range.normalize(None, len);
+		assert!(
+			end <= len,
+			"drains cannot extend past the length of their source bit-vector",
+		);
+		let mut bits = BitArrayVec::new();
+		bits.extend_from_bitslice(&source[start .. end]);
+		source[start ..].shift_start(end - start);
+		source.truncate(len - (end - start));
+		Self {
+			range: 0 .. bits.len(),
+			bits,
+			start,
+		}
+	}
+
+	/// Views the bits that have not yet been yielded.
+	fn as_bitslice(&self) -> &BitSlice<A::Store, O> {
+		&self.bits[self.range.clone()]
+	}
+}
+
+impl<A, O> Iterator for Inline<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	type Item = bool;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.range.next().map(|idx| self.bits[idx])
+	}
+}
+
+impl<A, O> DoubleEndedIterator for Inline<A, O>
+where
+	A: BitViewSized,
+	O: BitOrder,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.range.next_back().map(|idx| self.bits[idx])
+	}
+}
@@ -0,0 +1,136 @@
+//! Unit tests for small bit-vectors.
+
+#![cfg(test)]
+
+use alloc::format;
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	fmt::Debug,
+	hash::Hash,
+};
+
+use static_assertions::*;
+
+use crate::prelude::*;
+
+#[test]
+fn core_impl() {
+	assert_impl_all!(
+		SmallBitVec: AsMut<BitSlice>,
+		AsRef<BitSlice>,
+		Borrow<BitSlice>,
+		BorrowMut<BitSlice>,
+		Clone,
+		Debug,
+		Default,
+		Eq,
+		Extend<bool>,
+		From<BitVec>,
+		Hash,
+		Ord,
+		Send,
+		Sync,
+	);
+	assert_impl_all!(BitVec: From<SmallBitVec>);
+}
+
+#[test]
+fn spill() {
+	let mut sbv = SmallBitVec::<[u8; 2], Msb0>::new();
+	assert_eq!(sbv.inline_capacity(), 16);
+	for idx in 0 .. 16 {
+		sbv.push(idx % 2 == 0);
+	}
+	assert!(!sbv.spilled());
+	assert_eq!(sbv.capacity(), 16);
+
+	sbv.push(true);
+	assert!(sbv.spilled());
+	assert!(sbv.capacity() >= 17);
+	assert_eq!(sbv.count_ones(), 9);
+	assert_eq!(sbv.pop(), Some(true));
+
+	sbv.shrink_to_fit();
+	assert!(!sbv.spilled());
+	assert_eq!(sbv, bits![u8, Msb0; 1, 0].repeat(8));
+
+	sbv.insert(16, false);
+	assert!(sbv.spilled());
+	assert_eq!(sbv.len(), 17);
+	sbv.clear();
+	assert!(sbv.is_empty());
+	assert!(sbv.spilled());
+}
+
+#[test]
+fn edit() {
+	for mut sbv in [
+		SmallBitVec::<u16, Lsb0>::from_bitslice(bits![0, 1, 1, 0, 1]),
+		SmallBitVec::from(bitvec![u16, Lsb0; 0, 1, 1, 0, 1]),
+	] {
+		let spilled = sbv.spilled();
+		assert!(sbv.remove(1));
+		assert!(!sbv.swap_remove(0));
+		assert_eq!(sbv, bits![1, 1, 0]);
+
+		sbv.retain(|idx, _| idx != 1);
+		assert_eq!(sbv, bits![1, 0]);
+
+		sbv.resize(6, true);
+		assert_eq!(sbv, bits![1, 0, 1, 1, 1, 1]);
+		let tail = sbv.split_off(4);
+		assert_eq!(sbv, bits![1, 0, 1, 1]);
+		assert_eq!(tail, bits![1, 1]);
+		assert_eq!(sbv.spilled(), spilled);
+	}
+}
+
+#[test]
+fn drain_splice() {
+	for mut sbv in [
+		SmallBitVec::<u8, Lsb0>::from_bitslice(bits![0, 1, 0, 0, 1, 1]),
+		SmallBitVec::from(bitvec![u8, Lsb0; 0, 1, 0, 0, 1, 1]),
+	] {
+		let mut drain = sbv.drain(1 .. 4);
+		assert_eq!(drain.len(), 3);
+		assert_eq!(drain.as_bitslice(), bits![1, 0, 0]);
+		assert_eq!(drain.next_back(), Some(false));
+		assert_eq!(drain.next(), Some(true));
+		drop(drain);
+		assert_eq!(sbv, bits![0, 1, 1]);
+
+		let removed = sbv.splice(1 .., [false, true].iter().copied());
+		assert_eq!(removed.collect::<BitVec>(), bits![1, 1]);
+		assert_eq!(sbv, bits![0, 0, 1]);
+
+		let removed = sbv.splice(.. 1, bits![static 1; 7].iter().by_vals());
+		assert_eq!(removed.len(), 1);
+		drop(removed);
+		assert_eq!(sbv, bits![1, 1, 1, 1, 1, 1, 1, 0, 1]);
+		assert!(sbv.spilled());
+	}
+}
+
+#[test]
+#[should_panic = "drains cannot extend past the length"]
+fn drain_oob() {
+	let mut sbv = SmallBitVec::<u8, Lsb0>::from_bitslice(bits![0; 3]);
+	sbv.drain(2 .. 5);
+}
+
+#[test]
+fn convert() {
+	let sbv = (0 .. 10).map(|n| n % 3 == 0).collect::<SmallBitVec>();
+	assert!(!sbv.spilled());
+	let bv: BitVec = sbv.clone().into();
+	assert_eq!(bv, sbv);
+	assert_eq!(SmallBitVec::<[usize; 2], Lsb0>::from(bv).into_bitvec(), sbv);
+
+	let sbv = SmallBitVec::from(bitarr![u8, Msb0; 1, 0, 1]);
+	assert_eq!(sbv.len(), 8);
+	assert_eq!(format!("{}", sbv), "[1, 0, 1, 0, 0, 0, 0, 0]");
+	assert!(format!("{:?}", sbv).starts_with("SmallBitVec { spilled: false"));
+}
@@ -79,3 +79,20 @@ fn misc() {
 
 	assert_eq!(!bitvec![0, 1], bits![1, 0]);
 }
+
+#[test]
+fn shifts() {
+	let mut bv = bitvec![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	bv <<= 2;
+	assert_eq!(bv, bits![1, 1, 0, 0, 1, 0, 1, 0, 0]);
+	let bv = bv >> 3;
+	assert_eq!(bv, bits![0, 0, 0, 1, 1, 0, 0, 1, 0]);
+
+	let mut bv = bitvec![u16, Lsb0; 1, 0, 1, 1, 0, 0, 1, 0, 1];
+	bv <<= 2;
+	assert_eq!(bv, bits![0, 0, 1, 0, 1, 1, 0, 0, 1]);
+	let mut bv = bv >> 4;
+	assert_eq!(bv, bits![1, 1, 0, 0, 1, 0, 0, 0, 0]);
+	bv <<= 9;
+	assert!(bv.not_any());
+}
@@ -1,3 +1,19 @@
+use alloc::{
+	collections::TryReserveError,
+	vec::Vec,
+};
+use core::{
+	alloc::Layout,
+	cell::Cell,
+	ptr::NonNull,
+};
+
+use allocator_api2::alloc::{
+	AllocError,
+	Allocator,
+	Global,
+};
+
 use crate::prelude::*;
 
 #[test]
@@ -67,3 +83,107 @@ fn misc() {
 	bv.extend_from_within(2 .. 4);
 	assert_eq!(bv, bits![0, 0, 1, 1, 0, 0, 1, 1]);
 }
+
+#[test]
+fn fallible() {
+	let mut bv = BitVec::<u8, Msb0>::try_with_capacity(20).unwrap();
+	assert!(bv.capacity() >= 20);
+	bv.try_extend_from_bitslice(bits![0, 1, 1]).unwrap();
+	bv.try_push(true).unwrap();
+	assert_eq!(bv, bits![0, 1, 1, 1]);
+
+	bv.try_reserve(100).unwrap();
+	assert!(bv.capacity() >= 104);
+	bv.try_reserve_exact(200).unwrap();
+	assert!(bv.capacity() >= 204);
+
+	let max = BitSlice::<u8, Msb0>::MAX_BITS;
+	assert!(BitVec::<u8, Msb0>::try_with_capacity(max + 1).is_err());
+	assert!(BitVec::<u8, Msb0>::try_repeat(true, max + 1).is_err());
+	assert!(bv.try_reserve(max).is_err());
+	assert!(bv.try_reserve_exact(usize::MAX).is_err());
+	//  The errors are the standard library’s, as `Vec::try_reserve` reports.
+	let err: TryReserveError = bv.try_reserve(max).unwrap_err();
+	assert_eq!(err, Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err());
+	assert_eq!(bv, bits![0, 1, 1, 1]);
+
+	let bv = BitVec::<u16, Lsb0>::try_repeat(true, 40).unwrap();
+	assert_eq!(bv.count_ones(), 40);
+	let bb = BitBox::try_from_bitslice(&bv[3 ..]).unwrap();
+	assert_eq!(bb, bits![1; 37]);
+}
+
+#[test]
+fn replace_all() {
+	let data = [0b1011_0110_1101_1011u16, 0x5AC3, 0x0FF0, 0xB6DB];
+	let base = &data.view_bits::<Lsb0>()[3 ..];
+	for from in [bits![0], bits![1, 1], bits![1, 0, 1], bits![0; 4], bits![]] {
+		for to in [bits![], bits![1], bits![0, 0], bits![1, 0, 1, 0, 1, 0, 1]] {
+			let mut expected = BitVec::<u8, Msb0>::new();
+			let mut read = 0;
+			let matches = base.match_indices(from).collect::<Vec<_>>();
+			for &at in &matches {
+				expected.extend_from_bitslice(&base[read .. at]);
+				expected.extend_from_bitslice(to);
+				read = at + from.len();
+			}
+			expected.extend_from_bitslice(&base[read ..]);
+
+			let mut bv = base.iter().by_vals().collect::<BitVec<u8, Msb0>>();
+			assert_eq!(bv.replace_all(from, to), matches.len());
+			assert_eq!(bv, expected, "{} -> {}", from, to);
+		}
+	}
+}
+
+/// Forwards to the global allocator, counting the live allocations.
+#[derive(Default)]
+struct Counting {
+	live: Cell<isize>,
+}
+
+unsafe impl Allocator for Counting {
+	fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+		self.live.set(self.live.get() + 1);
+		Global.allocate(layout)
+	}
+
+	unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+		self.live.set(self.live.get() - 1);
+		Global.deallocate(ptr, layout)
+	}
+}
+
+#[test]
+fn allocator() {
+	let alloc = Counting::default();
+
+	let mut bv = BitVec::<u8, Msb0, _>::with_capacity_in(10, &alloc);
+	assert_eq!(alloc.live.get(), 1);
+	bv.extend_from_bitslice(bits![0, 1, 0, 0, 1]);
+	bv.resize(40, true);
+	assert_eq!(bv.count_ones(), 37);
+	assert_eq!(alloc.live.get(), 1);
+
+	let mut bv = bv.into_boxed_bitslice().into_bitvec();
+	assert_eq!(alloc.live.get(), 1);
+	bv.drain(.. 5);
+	bv.splice(.. 3, bits![0; 3].iter().by_vals());
+	assert_eq!(bv.count_ones(), 32);
+
+	let (bitptr, len, capa, alloc_ref) = bv.into_raw_parts_with_alloc();
+	let bv = unsafe { BitVec::from_raw_parts_in(bitptr, len, capa, alloc_ref) };
+	let clone = bv.clone();
+	assert_eq!(alloc.live.get(), 2);
+	assert_eq!(bv, clone);
+	drop((bv, clone));
+	assert_eq!(alloc.live.get(), 0);
+
+	let bb = BitBox::from_bitslice_in(&bits![u16, Lsb0; 1; 20][3 ..], &alloc);
+	assert_eq!(alloc.live.get(), 1);
+	let (ptr, alloc_ref) = BitBox::into_raw_with_allocator(bb);
+	let bb = unsafe { BitBox::from_raw_in(ptr, alloc_ref) };
+	assert_eq!(bb.iter().collect::<BitVec>(), bits![1; 17]);
+	drop(bb);
+	assert_eq!(alloc.live.get(), 0);
+}
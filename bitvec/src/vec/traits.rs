@@ -25,23 +25,30 @@ use core::{
 		Hasher,
 	},
 	marker::Unpin,
+	str::FromStr,
 };
 
+use allocator_api2::alloc::Allocator;
+
 use super::BitVec;
 use crate::{
 	array::BitArray,
 	boxed::BitBox,
 	order::BitOrder,
-	slice::BitSlice,
+	slice::{
+		BitSlice,
+		ParseBitsError,
+	},
 	store::BitStore,
 	view::BitViewSized,
 };
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Borrow<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, A> Borrow<BitSlice<T, O>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn borrow(&self) -> &BitSlice<T, O> {
@@ -50,10 +57,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> BorrowMut<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, A> BorrowMut<BitSlice<T, O>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn borrow_mut(&mut self) -> &mut BitSlice<T, O> {
@@ -62,29 +70,32 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Clone for BitVec<T, O>
+impl<T, O, A> Clone for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Clone,
 {
 	#[inline]
 	fn clone(&self) -> Self {
-		Self::from_bitslice(self.as_bitslice())
+		Self::from_bitslice_in(self.as_bitslice(), self.alloc.clone())
 	}
 }
 
-impl<T, O> Eq for BitVec<T, O>
+impl<T, O, A> Eq for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Ord for BitVec<T, O>
+impl<T, O, A> Ord for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn cmp(&self, other: &Self) -> cmp::Ordering {
@@ -93,52 +104,56 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for BitSlice<T1, O1>
+impl<T1, T2, O1, O2, A> PartialEq<BitVec<T2, O2, A>> for BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &BitVec<T2, O2, A>) -> bool {
 		self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for &BitSlice<T1, O1>
+impl<T1, T2, O1, O2, A> PartialEq<BitVec<T2, O2, A>> for &BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &BitVec<T2, O2, A>) -> bool {
 		*self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialEq<BitVec<T2, O2>> for &mut BitSlice<T1, O1>
+impl<T1, T2, O1, O2, A> PartialEq<BitVec<T2, O2, A>> for &mut BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn eq(&self, other: &BitVec<T2, O2>) -> bool {
+	fn eq(&self, other: &BitVec<T2, O2, A>) -> bool {
 		**self == other.as_bitslice()
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialEq<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> PartialEq<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	Rhs: ?Sized + PartialEq<BitSlice<T, O>>,
 {
 	#[inline]
@@ -148,52 +163,58 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for BitSlice<T1, O1>
+impl<T1, T2, O1, O2, A> PartialOrd<BitVec<T2, O2, A>> for BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitVec<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for &'a BitSlice<T1, O1>
+impl<'a, T1, T2, O1, O2, A> PartialOrd<BitVec<T2, O2, A>>
+	for &'a BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitVec<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T1, T2, O1, O2> PartialOrd<BitVec<T2, O2>> for &'a mut BitSlice<T1, O1>
+impl<'a, T1, T2, O1, O2, A> PartialOrd<BitVec<T2, O2, A>>
+	for &'a mut BitSlice<T1, O1>
 where
 	T1: BitStore,
 	T2: BitStore,
 	O1: BitOrder,
 	O2: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn partial_cmp(&self, other: &BitVec<T2, O2>) -> Option<cmp::Ordering> {
+	fn partial_cmp(&self, other: &BitVec<T2, O2, A>) -> Option<cmp::Ordering> {
 		self.partial_cmp(other.as_bitslice())
 	}
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O, Rhs> PartialOrd<Rhs> for BitVec<T, O>
+impl<T, O, A, Rhs> PartialOrd<Rhs> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 	Rhs: ?Sized + PartialOrd<BitSlice<T, O>>,
 {
 	#[inline]
@@ -203,10 +224,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, A> AsRef<BitSlice<T, O>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_ref(&self) -> &BitSlice<T, O> {
@@ -215,10 +237,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsMut<BitSlice<T, O>> for BitVec<T, O>
+impl<T, O, A> AsMut<BitSlice<T, O>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_mut(&mut self) -> &mut BitSlice<T, O> {
@@ -227,10 +250,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsRef<BitVec<T, O>> for BitVec<T, O>
+impl<T, O, A> AsRef<BitVec<T, O, A>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_ref(&self) -> &Self {
@@ -239,10 +263,11 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> AsMut<BitVec<T, O>> for BitVec<T, O>
+impl<T, O, A> AsMut<BitVec<T, O, A>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn as_mut(&mut self) -> &mut Self {
@@ -287,13 +312,14 @@ where
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> From<BitBox<T, O>> for BitVec<T, O>
+impl<T, O, A> From<BitBox<T, O, A>> for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
-	fn from(boxed: BitBox<T, O>) -> Self {
+	fn from(boxed: BitBox<T, O, A>) -> Self {
 		boxed.into_bitvec()
 	}
 }
@@ -336,6 +362,39 @@ where
 	}
 }
 
+/// Parses a bit-vector from text.
+///
+/// Each numeral may select binary, octal, or hexadecimal with a `0b`, `0o`, or
+/// `0x` prefix, and is binary if it has none. This accepts the output of this
+/// type’s `Debug`, `Display`, and `Binary` implementations. See
+/// [`ParseBitsError`] for a full description of the accepted text.
+///
+/// ## Examples
+///
+/// ```rust
+/// use bitvec::prelude::*;
+///
+/// let bv = bitvec![u16, Msb0; 1, 0, 1, 1, 0];
+/// let text = format!("{:?}", bv);
+/// assert_eq!(text.parse::<BitVec<u16, Msb0>>().unwrap(), bv);
+/// assert_eq!(bv.to_string().parse::<BitVec<u16, Msb0>>().unwrap(), bv);
+///
+/// let bv: BitVec = "0b1011_0".parse().unwrap();
+/// assert_eq!(bv, bits![1, 0, 1, 1, 0]);
+/// ```
+impl<T, O> FromStr for BitVec<T, O>
+where
+	T: BitStore,
+	O: BitOrder,
+{
+	type Err = ParseBitsError;
+
+	#[inline]
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		Self::parse_text(text, None)
+	}
+}
+
 #[cfg(not(tarpaulin_include))]
 impl<T, O> Default for BitVec<T, O>
 where
@@ -348,10 +407,11 @@ where
 	}
 }
 
-impl<T, O> Debug for BitVec<T, O>
+impl<T, O, A> Debug for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
 		self.as_bitspan().render(fmt, "Vec", &[(
@@ -374,10 +434,11 @@ easy_fmt! {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<T, O> Hash for BitVec<T, O>
+impl<T, O, A> Hash for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator,
 {
 	#[inline]
 	fn hash<H>(&self, state: &mut H)
@@ -386,23 +447,26 @@ where
 	}
 }
 
-unsafe impl<T, O> Send for BitVec<T, O>
+unsafe impl<T, O, A> Send for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Send,
 {
 }
 
-unsafe impl<T, O> Sync for BitVec<T, O>
+unsafe impl<T, O, A> Sync for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Sync,
 {
 }
 
-impl<T, O> Unpin for BitVec<T, O>
+impl<T, O, A> Unpin for BitVec<T, O, A>
 where
 	T: BitStore,
 	O: BitOrder,
+	A: Allocator + Unpin,
 {
 }